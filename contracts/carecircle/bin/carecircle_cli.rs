@@ -0,0 +1,141 @@
+//! Command-line client for CareCircle on a live Casper network.
+//!
+//! Deploys the contract and calls its main entry points and views through
+//! Odra's livenet backend, so operators don't assemble `casper-client`
+//! arguments by hand. The connection comes from the usual Odra variables,
+//! read from the environment or a `.env` file:
+//!
+//! ```sh
+//! ODRA_CASPER_LIVENET_NODE_ADDRESS=http://localhost:7777
+//! ODRA_CASPER_LIVENET_CHAIN_NAME=casper-test
+//! ODRA_CASPER_LIVENET_SECRET_KEY_PATH=keys/secret_key.pem
+//! ```
+//!
+//! The secret key's account signs every call. Deploying needs the built
+//! contract at `wasm/CareCircle.wasm`:
+//!
+//! ```sh
+//! cargo odra build
+//! cargo run --features livenet --bin carecircle-cli -- deploy
+//! cargo run --features livenet --bin carecircle-cli -- hash-... create-circle "Mom's Care"
+//! ```
+//!
+//! Reverts print the error code; `MESSAGES.md` lists what each code means.
+
+use std::process::exit;
+use std::str::FromStr;
+
+use carecircle::CareCircleHostRef;
+use odra::host::{Deployer, HostEnv, HostRef, HostRefLoader, NoArgs};
+use odra::OdraError;
+
+/// Default payment for installing the contract, in motes
+const DEPLOY_GAS: u64 = 600_000_000_000;
+/// Default payment for an entry point call, in motes
+const CALL_GAS: u64 = 10_000_000_000;
+/// Overrides either default
+const GAS_VAR: &str = "CARECIRCLE_GAS";
+/// Page size for list views
+const PAGE: u64 = 50;
+
+const USAGE: &str = "usage:
+  carecircle-cli deploy
+  carecircle-cli <contract> create-circle <name>
+  carecircle-cli <contract> add-member <circle_id> <account>
+  carecircle-cli <contract> create-task <circle_id> <assignee> <priority> <title>
+  carecircle-cli <contract> complete-task <task_id>
+  carecircle-cli <contract> circle <circle_id>
+  carecircle-cli <contract> members <circle_id>
+  carecircle-cli <contract> open-tasks <circle_id>
+  carecircle-cli <contract> task <task_id>
+  carecircle-cli <contract> stats
+
+<contract> is a contract hash (hash-...) and <account> an account hash
+(account-hash-...). Payments default to 600 CSPR for deploy and 10 CSPR
+per call; set CARECIRCLE_GAS (motes) to change them.";
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+
+    match args.as_slice() {
+        ["deploy"] => {
+            let env = odra_casper_livenet_env::env();
+            env.set_gas(gas(DEPLOY_GAS));
+            let contract = CareCircleHostRef::deploy(&env, NoArgs);
+            println!("{}", contract.address().to_string());
+        }
+        [contract, command, rest @ ..] => {
+            let env = odra_casper_livenet_env::env();
+            let contract = CareCircleHostRef::load(&env, parse(contract, "contract hash"));
+            run(&env, contract, command, rest);
+        }
+        _ => usage(),
+    }
+}
+
+/// Run one command against a deployed contract
+fn run(env: &HostEnv, mut contract: CareCircleHostRef, command: &str, args: &[&str]) {
+    match (command, args) {
+        ("create-circle", [name]) => {
+            env.set_gas(gas(CALL_GAS));
+            let circle_id = check(contract.try_create_circle(name.to_string()));
+            println!("{circle_id}");
+        }
+        ("add-member", [circle_id, account]) => {
+            env.set_gas(gas(CALL_GAS));
+            check(contract.try_add_member(parse(circle_id, "circle id"), parse(account, "account")));
+        }
+        ("create-task", [circle_id, assignee, priority, title]) => {
+            env.set_gas(gas(CALL_GAS));
+            let task_id = check(contract.try_create_task(
+                parse(circle_id, "circle id"),
+                title.to_string(),
+                parse(assignee, "assignee"),
+                parse(priority, "priority"),
+            ));
+            println!("{task_id}");
+        }
+        ("complete-task", [task_id]) => {
+            env.set_gas(gas(CALL_GAS));
+            check(contract.try_complete_task(parse(task_id, "task id")));
+        }
+        ("circle", [circle_id]) => println!("{:#?}", contract.get_circle(parse(circle_id, "circle id"))),
+        ("members", [circle_id]) => println!("{:#?}", contract.get_members(parse(circle_id, "circle id"), 0, PAGE)),
+        ("open-tasks", [circle_id]) => println!("{:#?}", contract.get_open_tasks(parse(circle_id, "circle id"), PAGE)),
+        ("task", [task_id]) => println!("{:#?}", contract.get_task(parse(task_id, "task id"))),
+        ("stats", []) => {
+            let (circles, tasks, completions) = contract.get_stats();
+            println!("circles {circles}\ntasks {tasks}\ncompletions {completions}");
+        }
+        _ => usage(),
+    }
+}
+
+/// The payment from `CARECIRCLE_GAS`, or `default`
+fn gas(default: u64) -> u64 {
+    match std::env::var(GAS_VAR) {
+        Ok(value) => parse(&value, GAS_VAR),
+        Err(_) => default,
+    }
+}
+
+fn parse<T: FromStr>(value: &str, what: &str) -> T {
+    value.parse().unwrap_or_else(|_| {
+        eprintln!("invalid {what}: {value}");
+        exit(2);
+    })
+}
+
+/// Unwrap a call result, exiting with the revert's code on failure
+fn check<T>(result: Result<T, OdraError>) -> T {
+    result.unwrap_or_else(|error| {
+        eprintln!("call reverted with code {}: {error:?}", error.code());
+        exit(1);
+    })
+}
+
+fn usage() -> ! {
+    eprintln!("{USAGE}");
+    exit(2);
+}
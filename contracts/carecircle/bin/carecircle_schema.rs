@@ -0,0 +1,81 @@
+//! Exports the contract's machine-readable schema.
+//!
+//! Writes a Casper Contract Schema JSON document for TypeScript and mobile
+//! clients to generate bindings from. It holds every entry point with its
+//! argument and return types, every custom type (including each event's
+//! field layout), the event list and all error codes:
+//!
+//! ```sh
+//! cargo run --bin carecircle-schema                  # resources/carecircle_schema.json
+//! cargo run --bin carecircle-schema -- schema.json   # custom path
+//! ```
+//!
+//! Odra derives the schema from the module itself, so it only knows the
+//! `Error` enum's variants. Retired codes, which have none, are merged in
+//! from the error table of `MESSAGES.md`, which keeps the schema and the
+//! message catalog listing the same codes.
+
+use std::fs;
+use std::path::Path;
+
+use carecircle::CareCircle;
+use odra::schema::casper_contract_schema::UserError;
+
+const DEFAULT_PATH: &str = "resources/carecircle_schema.json";
+const MESSAGES: &str = include_str!("../MESSAGES.md");
+/// The generated schema functions for a module this size overflow the
+/// default main-thread stack in debug builds
+const STACK_SIZE: usize = 64 * 1024 * 1024;
+
+fn main() {
+    let path = std::env::args().nth(1).unwrap_or_else(|| DEFAULT_PATH.to_string());
+    std::thread::Builder::new()
+        .stack_size(STACK_SIZE)
+        .spawn(move || export(&path))
+        .expect("export thread starts")
+        .join()
+        .expect("schema export succeeds");
+}
+
+/// Build the schema and write it to `path`
+fn export(path: &str) {
+    let authors = env!("CARGO_PKG_AUTHORS").split(':').map(|author| author.trim().to_string()).collect();
+    let mut schema = odra::schema::schema::<CareCircle>(
+        "CareCircle",
+        "CareCircle",
+        env!("CARGO_PKG_VERSION"),
+        authors,
+        env!("CARGO_PKG_REPOSITORY"),
+        env!("CARGO_PKG_HOMEPAGE"),
+    );
+
+    for (code, key, english) in catalog_errors() {
+        if !schema.errors.iter().any(|error| error.discriminant == code) {
+            schema.errors.push(UserError::new(key, english, code));
+        }
+    }
+    schema.errors.sort_by_key(|error| error.discriminant);
+
+    let json = schema.as_json().expect("schema serializes to JSON");
+    if let Some(dir) = Path::new(path).parent().filter(|dir| !dir.as_os_str().is_empty()) {
+        fs::create_dir_all(dir).expect("schema directory is writable");
+    }
+    fs::write(path, json + "\n").expect("schema file is writable");
+    println!("{path}");
+}
+
+/// `(code, key, English)` rows of the catalog's error table
+fn catalog_errors() -> impl Iterator<Item = (u16, &'static str, &'static str)> {
+    MESSAGES
+        .split("## Errors")
+        .nth(1)
+        .and_then(|section| section.split("## Events").next())
+        .expect("MESSAGES.md has an Errors section")
+        .lines()
+        .filter_map(|line| {
+            let mut cells = line.trim().trim_matches('|').split('|').map(str::trim);
+            let code = cells.next()?.parse().ok()?;
+            let key = cells.next()?.trim_matches('`');
+            Some((code, key, cells.next()?))
+        })
+}
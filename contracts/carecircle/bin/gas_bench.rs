@@ -0,0 +1,141 @@
+//! Gas benchmark for the CareCircle hot paths.
+//!
+//! Measures each entry point against circles already holding 10, 100 and
+//! 1000 tasks (or the sizes passed as arguments); keepers get that many open
+//! tasks and are measured resuming from a stored cursor. Prints one
+//! `operation,circle_tasks,gas` line per measurement, so two runs can be
+//! diffed directly.
+//!
+//! Real gas figures need the Casper VM backend and a built contract:
+//!
+//! ```sh
+//! cargo odra build
+//! ODRA_BACKEND=casper cargo run --release --features bench --bin gas_bench -- 10 100
+//! ```
+//!
+//! The default OdraVM backend runs the same calls but reports zero gas.
+
+use carecircle::{CareCircleHostRef, CircleSettings};
+use odra::host::{Deployer, HostEnv, HostRef, NoArgs};
+use odra::Address;
+
+const DEFAULT_SIZES: [u64; 3] = [10, 100, 1000];
+const OPEN_TASK_COUNT: u64 = 10;
+const BATCH_SIZE: u64 = 10;
+
+struct Bench {
+    env: HostEnv,
+    contract: CareCircleHostRef,
+    owner: Address,
+    member: Address,
+    circle_id: u64,
+}
+
+impl Bench {
+    /// Deploy a fresh contract with one circle of two members
+    fn new() -> Self {
+        let env = odra_test::env();
+        let mut contract = CareCircleHostRef::deploy(&env, NoArgs);
+        let owner = env.get_account(0);
+        let member = env.get_account(1);
+        env.set_caller(owner);
+        let circle_id = contract.create_circle("Bench".into());
+        contract.add_member(circle_id, member);
+        Self { env, contract, owner, member, circle_id }
+    }
+
+    /// Gas of the contract's most recent call
+    fn last_gas(&self) -> u64 {
+        self.env.last_call_result(*self.contract.address()).callee_contract_gas_used()
+    }
+
+    fn report(&self, operation: &str, circle_tasks: u64) {
+        println!("{},{},{}", operation, circle_tasks, self.last_gas());
+    }
+
+    /// Fill the circle with completed tasks plus a few open ones
+    fn seed(&mut self, circle_tasks: u64) {
+        for i in 0..circle_tasks {
+            let task_id = self.contract.create_task(self.circle_id, "seed".into(), self.owner, (i % 4) as u8);
+            if i >= OPEN_TASK_COUNT {
+                self.contract.complete_task(task_id);
+            }
+        }
+    }
+
+    /// Add open tasks that are old enough to escalate and due within the hour
+    fn seed_open(&mut self, count: u64) {
+        let settings = CircleSettings { priority_aging_secs: 60, ..Default::default() };
+        self.contract.update_settings(self.circle_id, settings);
+        let task_ids: Vec<u64> = (0..count)
+            .map(|i| self.contract.create_task(self.circle_id, "open".into(), self.owner, (i % 3) as u8))
+            .collect();
+        self.env.advance_block_time(120_000);
+        let due_at = self.env.block_time() + 1_800_000;
+        for task_id in task_ids {
+            self.contract.set_due_date(task_id, due_at);
+        }
+    }
+}
+
+fn run(circle_tasks: u64) {
+    let mut bench = Bench::new();
+    let circle_id = bench.circle_id;
+
+    bench.contract.create_circle("Other".into());
+    bench.report("create_circle", circle_tasks);
+    bench.contract.add_member(circle_id, bench.env.get_account(2));
+    bench.report("add_member", circle_tasks);
+    let relatives = (3..13).map(|index| bench.env.get_account(index)).collect();
+    bench.contract.add_members(circle_id, relatives);
+    bench.report("add_members_x10", circle_tasks);
+
+    bench.seed(circle_tasks);
+
+    let task_id = bench.contract.create_task(circle_id, "Pick up prescription".into(), bench.member, 2);
+    bench.report("create_task", circle_tasks);
+
+    bench.env.set_caller(bench.member);
+    bench.contract.complete_task(task_id);
+    bench.report("complete_task", circle_tasks);
+    bench.env.set_caller(bench.owner);
+
+    // Batch variants: consecutive calls, reported as a total
+    let mut created = Vec::new();
+    let mut total = 0;
+    for _ in 0..BATCH_SIZE {
+        created.push(bench.contract.create_task(circle_id, "Batch".into(), bench.owner, 1));
+        total += bench.last_gas();
+    }
+    println!("create_task_x{},{},{}", BATCH_SIZE, circle_tasks, total);
+
+    let mut total = 0;
+    for task_id in created {
+        bench.contract.complete_task(task_id);
+        total += bench.last_gas();
+    }
+    println!("complete_task_x{},{},{}", BATCH_SIZE, circle_tasks, total);
+
+    // Keepers page through open tasks from a stored cursor: the first call
+    // moves the cursor into the list, the second is measured from there
+    bench.seed_open(circle_tasks);
+    bench.contract.escalate_priorities(circle_id, BATCH_SIZE);
+    bench.contract.escalate_priorities(circle_id, BATCH_SIZE);
+    bench.report("escalate_priorities", circle_tasks);
+    bench.contract.emit_due_reminders(circle_id, 3600, BATCH_SIZE);
+    bench.contract.emit_due_reminders(circle_id, 3600, BATCH_SIZE);
+    bench.report("emit_due_reminders", circle_tasks);
+}
+
+fn main() {
+    let sizes: Vec<u64> = std::env::args()
+        .skip(1)
+        .map(|arg| arg.parse().expect("circle sizes must be integers"))
+        .collect();
+    let sizes = if sizes.is_empty() { DEFAULT_SIZES.to_vec() } else { sizes };
+
+    println!("operation,circle_tasks,gas");
+    for circle_tasks in sizes {
+        run(circle_tasks);
+    }
+}
@@ -0,0 +1,134 @@
+//! Typed client for Rust services calling a deployed contract.
+//!
+//! Compiled with the `client` feature and never into the wasm build. The
+//! contract's own types (`Circle`, `Task`, `CircleSettings`, the events, ...)
+//! are the ones exported by this crate, so a backend depending on
+//! `carecircle` with this feature shares them instead of redefining them.
+//! The client works on any Odra host env: `odra_casper_livenet_env::env()`
+//! for a live network, `odra_test::env()` in tests.
+//!
+//! ```ignore
+//! let env = odra_casper_livenet_env::env();
+//! let mut client = CareCircleClient::load(&env, contract_hash.parse()?);
+//! let circle_id = client.contract_mut().try_create_circle("Mom's Care".into())?;
+//! let created: Vec<(u32, CircleCreated)> = client.events(0);
+//! ```
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use odra::casper_event_standard::EventInstance;
+use odra::casper_types::bytesrepr::FromBytes;
+use odra::host::{Deployer, HostEnv, HostRef, HostRefLoader, NoArgs};
+use odra::Address;
+use odra::EventError;
+
+use crate::indexer::{CareCircleEvent, Snapshot};
+use crate::CareCircleHostRef;
+
+/// Prefix the event standard puts before every event name
+const EVENT_PREFIX: &str = "event_";
+
+/// A deployed CareCircle contract and the env used to reach it
+pub struct CareCircleClient {
+    contract: CareCircleHostRef,
+}
+
+impl CareCircleClient {
+    /// Attach to the contract at `address`. Only live networks support this;
+    /// OdraVM knows just the contracts it deployed, so wrap those with `From`.
+    pub fn load(env: &HostEnv, address: Address) -> Self {
+        Self { contract: CareCircleHostRef::load(env, address) }
+    }
+
+    /// Install a new contract; on a live network this needs `wasm/CareCircle.wasm`
+    pub fn deploy(env: &HostEnv) -> Self {
+        Self { contract: CareCircleHostRef::deploy(env, NoArgs) }
+    }
+
+    pub fn address(&self) -> Address {
+        *self.contract.address()
+    }
+
+    pub fn env(&self) -> &HostEnv {
+        self.contract.env()
+    }
+
+    /// Typed views and entry points, called as the env's current caller
+    pub fn contract(&self) -> &CareCircleHostRef {
+        &self.contract
+    }
+
+    pub fn contract_mut(&mut self) -> &mut CareCircleHostRef {
+        &mut self.contract
+    }
+
+    /// Typed entry points, signed by `caller`
+    pub fn as_caller(&mut self, caller: Address) -> &mut CareCircleHostRef {
+        self.contract.env().set_caller(caller);
+        &mut self.contract
+    }
+
+    /// Number of events the contract has emitted
+    pub fn event_count(&self) -> u32 {
+        self.env().events_count(&self.address())
+    }
+
+    /// Name of the event at `index`, counted from the contract's first event
+    pub fn event_name(&self, index: u32) -> Result<String, EventError> {
+        event_name(&self.env().get_event_bytes(&self.address(), index)?)
+    }
+
+    /// The event at `index` decoded as `T`
+    pub fn event<T: FromBytes + EventInstance>(&self, index: u32) -> Result<T, EventError> {
+        decode_event(&self.env().get_event_bytes(&self.address(), index)?)
+    }
+
+    /// The event at `index`, whatever its type
+    pub fn decoded_event(&self, index: u32) -> Result<CareCircleEvent, EventError> {
+        CareCircleEvent::decode(&self.env().get_event_bytes(&self.address(), index)?)
+    }
+
+    /// Every event from `index` on, in emission order
+    pub fn decoded_events(&self, index: u32) -> Result<Vec<CareCircleEvent>, EventError> {
+        (index..self.event_count()).map(|index| self.decoded_event(index)).collect()
+    }
+
+    /// Fold the events `snapshot` has not seen yet into it
+    pub fn catch_up(&self, snapshot: &mut Snapshot) -> Result<(), EventError> {
+        for event in self.decoded_events(snapshot.events_applied)? {
+            snapshot.apply(&event);
+        }
+        Ok(())
+    }
+
+    /// Every event of type `T` from `index` on, with its index
+    pub fn events<T: FromBytes + EventInstance>(&self, index: u32) -> Vec<(u32, T)> {
+        (index..self.event_count())
+            .filter_map(|index| self.event(index).ok().map(|event| (index, event)))
+            .collect()
+    }
+}
+
+impl From<CareCircleHostRef> for CareCircleClient {
+    fn from(contract: CareCircleHostRef) -> Self {
+        Self { contract }
+    }
+}
+
+/// Name of a raw event as stored by the event standard, without its prefix
+pub fn event_name(bytes: &[u8]) -> Result<String, EventError> {
+    let (name, _) = String::from_bytes(bytes).map_err(|_| EventError::CouldntExtractName)?;
+    match name.strip_prefix(EVENT_PREFIX) {
+        Some(stripped) => Ok(String::from(stripped)),
+        None => Err(EventError::UnexpectedType(name)),
+    }
+}
+
+/// Decode a raw event as `T`, failing with `UnexpectedType` if it is another event
+pub fn decode_event<T: FromBytes + EventInstance>(bytes: &[u8]) -> Result<T, EventError> {
+    let name = event_name(bytes)?;
+    if name != T::name() {
+        return Err(EventError::UnexpectedType(name));
+    }
+    T::from_bytes(bytes).map(|(event, _)| event).map_err(|_| EventError::Parsing)
+}
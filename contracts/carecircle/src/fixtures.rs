@@ -0,0 +1,151 @@
+//! Deterministic demo state for tests and local development.
+//!
+//! Only compiled with the `test-fixtures` feature and never into the wasm
+//! build. Everything here drives the public entry points of a deployed
+//! [`CareCircleHostRef`], so seeded state is exactly what real callers would
+//! produce and no storage is written behind the contract's back.
+//!
+//! ```ignore
+//! let env = odra_test::env();
+//! let (mut contract, circles) = fixtures::deploy_with_demo_data(&env);
+//! ```
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use odra::host::{Deployer, HostEnv, NoArgs};
+use odra::Address;
+
+use crate::{CareCircleHostRef, CircleSettings};
+
+/// A task to create while building a circle
+#[derive(Clone, Debug)]
+pub struct TaskFixture {
+    pub title: String,
+    pub assigned_to: Address,
+    pub priority: u8,
+    /// Completed by the assignee right after creation
+    pub completed: bool,
+}
+
+/// Builder for a circle with members, settings and tasks
+#[derive(Clone, Debug)]
+pub struct CircleFixture {
+    name: String,
+    owner: Address,
+    members: Vec<Address>,
+    settings: Option<CircleSettings>,
+    tasks: Vec<TaskFixture>,
+}
+
+/// Ids of everything a [`CircleFixture`] created
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SeededCircle {
+    pub circle_id: u64,
+    pub owner: Address,
+    /// Members added on top of the owner, in insertion order
+    pub members: Vec<Address>,
+    /// Task ids in the order the tasks were declared
+    pub task_ids: Vec<u64>,
+}
+
+impl CircleFixture {
+    pub fn new(name: &str, owner: Address) -> Self {
+        Self {
+            name: String::from(name),
+            owner,
+            members: Vec::new(),
+            settings: None,
+            tasks: Vec::new(),
+        }
+    }
+
+    pub fn member(mut self, member: Address) -> Self {
+        self.members.push(member);
+        self
+    }
+
+    pub fn settings(mut self, settings: CircleSettings) -> Self {
+        self.settings = Some(settings);
+        self
+    }
+
+    pub fn task(mut self, title: &str, assigned_to: Address, priority: u8) -> Self {
+        self.tasks.push(TaskFixture { title: String::from(title), assigned_to, priority, completed: false });
+        self
+    }
+
+    pub fn completed_task(mut self, title: &str, assigned_to: Address, priority: u8) -> Self {
+        self.tasks.push(TaskFixture { title: String::from(title), assigned_to, priority, completed: true });
+        self
+    }
+
+    /// Create the circle, then its members, settings and tasks, in that order.
+    /// Tasks go through the workflow the settings ask for: assignees accept
+    /// tasks awaiting acceptance, and the owner approves completions awaiting
+    /// verification. Leaves the owner as the env caller.
+    pub fn build(self, env: &HostEnv, contract: &mut CareCircleHostRef) -> SeededCircle {
+        env.set_caller(self.owner);
+        let circle_id = contract.create_circle(self.name);
+        for member in &self.members {
+            contract.add_member(circle_id, *member);
+        }
+        if let Some(settings) = self.settings {
+            contract.update_settings(circle_id, settings);
+        }
+
+        let mut task_ids = Vec::new();
+        for task in self.tasks {
+            let task_id = contract.create_task(circle_id, task.title, task.assigned_to, task.priority);
+            env.set_caller(task.assigned_to);
+            if contract.is_awaiting_acceptance(task_id) {
+                contract.accept_assignment(task_id);
+            }
+            if task.completed {
+                contract.complete_task(task_id);
+                if contract.get_pending_completion(task_id).is_some() {
+                    env.set_caller(self.owner);
+                    contract.verify_completion(task_id, true);
+                }
+            }
+            env.set_caller(self.owner);
+            task_ids.push(task_id);
+        }
+
+        SeededCircle { circle_id, owner: self.owner, members: self.members, task_ids }
+    }
+}
+
+/// Seed the canonical demo circles on an already deployed contract.
+///
+/// Uses host accounts 0-4, so the result is identical on every run:
+/// - "Mom's Care": owned by account 0 with accounts 1 and 2, four tasks, one completed
+/// - "Dad's Recovery": owned by account 3 with accounts 4 and 0, two tasks, one completed
+pub fn seed_demo_data(env: &HostEnv, contract: &mut CareCircleHostRef) -> Vec<SeededCircle> {
+    let accounts: Vec<Address> = (0..5).map(|i| env.get_account(i)).collect();
+
+    let moms_care = CircleFixture::new("Mom's Care", accounts[0])
+        .member(accounts[1])
+        .member(accounts[2])
+        .completed_task("Morning medication", accounts[1], 3)
+        .task("Pharmacy pickup", accounts[2], 2)
+        .task("Doctor appointment", accounts[1], 2)
+        .task("Grocery run", accounts[0], 1)
+        .build(env, contract);
+
+    let dads_recovery = CircleFixture::new("Dad's Recovery", accounts[3])
+        .member(accounts[4])
+        .member(accounts[0])
+        .completed_task("Physio exercises", accounts[4], 2)
+        .task("Meal prep", accounts[0], 1)
+        .build(env, contract);
+
+    env.set_caller(accounts[0]);
+    alloc::vec![moms_care, dads_recovery]
+}
+
+/// Deploy a fresh contract and seed it with [`seed_demo_data`]
+pub fn deploy_with_demo_data(env: &HostEnv) -> (CareCircleHostRef, Vec<SeededCircle>) {
+    let mut contract = CareCircleHostRef::deploy(env, NoArgs);
+    let circles = seed_demo_data(env, &mut contract);
+    (contract, circles)
+}
@@ -0,0 +1,229 @@
+//! Typed decoding of the contract's events, and replay into a state snapshot.
+//!
+//! Compiled with the `client` feature. [`CareCircleEvent::decode`] turns the
+//! raw bytes of any event this contract emits, however they were fetched,
+//! into one typed enum. [`Snapshot`] folds a stream of those events into the
+//! circles, members and tasks they describe, so an indexer can bootstrap from
+//! the event history instead of paging through every view:
+//!
+//! ```ignore
+//! let mut snapshot = Snapshot::default();
+//! client.catch_up(&mut snapshot)?; // and again whenever new events may exist
+//! ```
+//!
+//! The snapshot only tracks what the events carry. Settings, profiles, health
+//! logs and other state without an event stay behind the views.
+
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::string::String;
+use alloc::vec::Vec;
+use odra::Address;
+use odra::EventError;
+
+use crate::client::{decode_event, event_name};
+
+macro_rules! care_circle_events {
+    ($($event:ident),* $(,)?) => {
+        /// Any event emitted by the contract, one variant per event type
+        #[derive(Debug, PartialEq)]
+        pub enum CareCircleEvent {
+            $($event(crate::$event),)*
+        }
+
+        impl CareCircleEvent {
+            /// Decode raw event bytes, dispatching on the event name they start with
+            pub fn decode(bytes: &[u8]) -> Result<Self, EventError> {
+                let name = event_name(bytes)?;
+                match name.as_str() {
+                    $(stringify!($event) => decode_event(bytes).map(Self::$event),)*
+                    _ => Err(EventError::UnexpectedType(name)),
+                }
+            }
+
+            /// The event's name, as listed in `MESSAGES.md`
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $(Self::$event(_) => stringify!($event),)*
+                }
+            }
+
+            /// The event's code, as listed in `MESSAGES.md`
+            pub fn code(&self) -> u32 {
+                crate::event_code(self.name())
+            }
+        }
+    };
+}
+
+// Same order as the module's `events` list, which fixes the event codes
+care_circle_events![
+    CircleCreated, MemberAdded, TaskCreated, TaskCompleted, TaskDueSoon, PriorityEscalated,
+    SlaBreached, VolunteerApplied, VolunteerAccepted, TaskLinked, CompletionSubmitted,
+    CompletionRejected, SettingsUpdated, ReceiptAppended, WeeklyDigest, AssigneeInactive,
+    TaskMoved, TaskDeferred, CompletionCosigned, CirclesMerged, SuccessionClaimed,
+    OwnershipTransferred, MemberSuspended, MemberReinstated, AgencyRoleChanged,
+    AssignmentAccepted, AssignmentDeclined, HelpRequested, HelperJoined, HelpersCredited,
+    CheckedIn, PresenceAttested, EvidenceAdded, DischargePlanStarted, EpisodeUpdated,
+    SupplyLow, TripLogged, ReimbursementUpdated, StatusUpdated,
+    ExtensionUpdated, TitleSealed, TitleRevealed, EnvelopeUpdated, KeyEpochRotated,
+    AuditorAccessChanged, RecordsPurged, MemberDataErased, ResearchOptInChanged,
+    ReferralCredited, CircleMilestone, AnnouncementPosted, FeatureToggled, HookSkipped,
+    IncidentReported, AllergyConflict,
+];
+
+/// A circle as reconstructed from events
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CircleSnapshot {
+    pub name: String,
+    pub owner: Address,
+    /// Members in join order, the owner first
+    pub members: Vec<Address>,
+    pub suspended: BTreeSet<Address>,
+    /// False once the circle has been merged into another
+    pub active: bool,
+    /// Completions counted for the circle, shared completions included
+    pub completions: u64,
+}
+
+/// A task as reconstructed from events
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TaskSnapshot {
+    pub circle_id: u64,
+    pub circle_task_seq: u64,
+    /// Empty while the title is sealed
+    pub title: String,
+    pub assigned_to: Address,
+    /// Circle the task is shared with, if any
+    pub secondary_circle_id: Option<u64>,
+    pub completed: bool,
+    pub completed_by: Option<Address>,
+    pub completed_at: u64, // 0 if not completed
+}
+
+/// Circles and tasks folded from the contract's event history
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Snapshot {
+    pub circles: BTreeMap<u64, CircleSnapshot>,
+    pub tasks: BTreeMap<u64, TaskSnapshot>,
+    /// Number of events applied; the event index to resume from
+    pub events_applied: u32,
+}
+
+impl Snapshot {
+    /// Replay events from the contract's first one
+    pub fn replay<'a>(events: impl IntoIterator<Item = &'a CareCircleEvent>) -> Self {
+        let mut snapshot = Self::default();
+        for event in events {
+            snapshot.apply(event);
+        }
+        snapshot
+    }
+
+    /// Fold the next event into the snapshot. Events must come in emission
+    /// order; those that change nothing tracked here only advance the count.
+    pub fn apply(&mut self, event: &CareCircleEvent) {
+        self.events_applied += 1;
+        match event {
+            CareCircleEvent::CircleCreated(event) => {
+                self.circles.insert(event.circle_id, CircleSnapshot {
+                    name: event.name.clone(),
+                    owner: event.owner,
+                    // The owner joins without a MemberAdded event
+                    members: alloc::vec![event.owner],
+                    suspended: BTreeSet::new(),
+                    active: true,
+                    completions: 0,
+                });
+            }
+            CareCircleEvent::MemberAdded(event) => {
+                if let Some(circle) = self.circles.get_mut(&event.circle_id) {
+                    circle.members.push(event.member);
+                }
+            }
+            CareCircleEvent::MemberSuspended(event) => {
+                if let Some(circle) = self.circles.get_mut(&event.circle_id) {
+                    circle.suspended.insert(event.member);
+                }
+            }
+            CareCircleEvent::MemberReinstated(event) => {
+                if let Some(circle) = self.circles.get_mut(&event.circle_id) {
+                    circle.suspended.remove(&event.member);
+                }
+            }
+            CareCircleEvent::OwnershipTransferred(event) => {
+                if let Some(circle) = self.circles.get_mut(&event.circle_id) {
+                    circle.owner = event.new_owner;
+                }
+            }
+            CareCircleEvent::CirclesMerged(event) => {
+                // Members and tasks arrive as their own MemberAdded and TaskMoved events
+                if let Some(circle) = self.circles.get_mut(&event.source_circle_id) {
+                    circle.active = false;
+                }
+            }
+            CareCircleEvent::TaskCreated(event) => {
+                self.tasks.insert(event.task_id, TaskSnapshot {
+                    circle_id: event.circle_id,
+                    circle_task_seq: event.circle_task_seq,
+                    title: event.title.clone(),
+                    assigned_to: event.assigned_to,
+                    secondary_circle_id: None,
+                    completed: false,
+                    completed_by: None,
+                    completed_at: 0,
+                });
+            }
+            CareCircleEvent::TitleRevealed(event) => {
+                if let Some(task) = self.tasks.get_mut(&event.task_id) {
+                    task.title = event.title.clone();
+                }
+            }
+            CareCircleEvent::TaskMoved(event) => {
+                if let Some(task) = self.tasks.get_mut(&event.task_id) {
+                    task.circle_id = event.to_circle_id;
+                    task.circle_task_seq = event.circle_task_seq;
+                }
+            }
+            CareCircleEvent::TaskLinked(event) => {
+                if let Some(task) = self.tasks.get_mut(&event.task_id) {
+                    task.secondary_circle_id = Some(event.secondary_circle_id);
+                }
+            }
+            CareCircleEvent::VolunteerAccepted(event) => self.reassign(event.task_id, event.volunteer),
+            CareCircleEvent::AssignmentDeclined(event) => self.reassign(event.task_id, event.reassigned_to),
+            CareCircleEvent::AssigneeInactive(event) => {
+                if let Some(reassigned_to) = event.reassigned_to {
+                    self.reassign(event.task_id, reassigned_to);
+                }
+            }
+            CareCircleEvent::TaskCompleted(event) => {
+                let Some(task) = self.tasks.get_mut(&event.task_id) else { return };
+                task.completed = true;
+                task.completed_by = Some(event.completed_by);
+                task.completed_at = event.timestamp;
+                let counted = [Some(task.circle_id), task.secondary_circle_id];
+                for circle_id in counted.into_iter().flatten() {
+                    if let Some(circle) = self.circles.get_mut(&circle_id) {
+                        circle.completions += 1;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Open tasks currently assigned to `member`
+    pub fn open_tasks_of(&self, member: Address) -> Vec<u64> {
+        self.tasks
+            .iter()
+            .filter(|(_, task)| !task.completed && task.assigned_to == member)
+            .map(|(task_id, _)| *task_id)
+            .collect()
+    }
+
+    fn reassign(&mut self, task_id: u64, assignee: Address) {
+        if let Some(task) = self.tasks.get_mut(&task_id) {
+            task.assigned_to = assignee;
+        }
+    }
+}
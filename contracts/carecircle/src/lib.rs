@@ -1,10 +1,21 @@
 #![no_std]
+#![recursion_limit = "2048"]
 extern crate alloc;
 
 use alloc::string::String;
 use alloc::vec::Vec;
+use core::ops::RangeInclusive;
 use odra::prelude::*;
-use odra::{Mapping, Var};
+use odra::casper_types::account::AccountHash;
+use odra::casper_types::bytesrepr::Bytes;
+use odra::{ContractRef, Mapping, SubModule, Var};
+
+#[cfg(all(feature = "client", not(target_arch = "wasm32")))]
+pub mod client;
+#[cfg(all(feature = "client", not(target_arch = "wasm32")))]
+pub mod indexer;
+#[cfg(all(feature = "test-fixtures", not(target_arch = "wasm32")))]
+pub mod fixtures;
 
 // ==================== Data Structures ====================
 
@@ -19,11 +30,22 @@ pub struct Circle {
     pub task_count: u64,
 }
 
+/// Storage form of a Circle. The member and task counts live in their
+/// indexes, and `get_circle` reads them from there.
+#[derive(Clone, Debug, OdraType)]
+struct StoredCircle {
+    id: u64,
+    name: String,
+    owner: Address,
+    created_at: u64,
+}
+
 /// Represents a caregiving task within a circle
 #[derive(Clone, Debug, OdraType)]
 pub struct Task {
     pub id: u64,
     pub circle_id: u64,
+    pub circle_task_seq: u64, // 1-based number within the circle
     pub title: String,
     pub assigned_to: Address,
     pub created_by: Address,
@@ -31,6 +53,1134 @@ pub struct Task {
     pub completed: bool,
     pub completed_at: u64, // 0 if not completed
     pub priority: u8,
+    pub due_at: u64, // 0 if no due date
+    pub estimated_minutes: u32, // 0 if not estimated
+    pub episode_id: u64, // 0 if not part of an episode
+}
+
+/// Storage form of a Task, converted to and from `Task` at the storage boundary.
+/// The id is the mapping key; times are whole seconds since the Unix epoch,
+/// which a u32 holds until 2106, with their milliseconds packed in `millis`.
+#[derive(Clone, Debug, OdraType)]
+struct StoredTask {
+    circle_id: u32,
+    circle_task_seq: u32,
+    title: String, // at most MAX_TITLE_LEN bytes
+    assigned_to: Address,
+    created_by: Address,
+    created_at: u32,
+    completed_at: u32, // 0 if not completed
+    due_at: u32,       // 0 if no due date
+    status: u8,        // priority in PRIORITY_MASK, COMPLETED_FLAG
+    estimated_minutes: u32,
+    episode_id: u32,
+    millis: u32, // millisecond parts of created_at, completed_at, due_at, MILLIS_BITS each
+}
+
+/// How `create_open_task` picks an assignee for a circle
+#[derive(Clone, Copy, Debug, PartialEq, Eq, OdraType)]
+pub enum AutoAssignMode {
+    /// Auto-assignment disabled, every task needs an explicit assignee
+    Off,
+    /// Assign to the member with the fewest open tasks
+    FewestOpen,
+    /// Assign to the next member in join order
+    RoundRobin,
+    /// Assign to the member with the fewest estimated open minutes
+    LeastWorkload,
+}
+
+/// What `finalize_unverified` does with a completion nobody verified in time
+#[derive(Clone, Copy, Debug, PartialEq, Eq, OdraType)]
+pub enum TimeoutAction {
+    /// Accept the completion as if the owner approved it
+    Confirm,
+    /// Reject the completion as if the owner disputed it
+    Dispute,
+}
+
+/// Daylight saving rule applied on top of a circle's UTC offset
+#[derive(Clone, Copy, Debug, PartialEq, Eq, OdraType)]
+pub enum DstRule {
+    /// Standard time all year
+    None,
+    /// +1h from the last Sunday of March to the last Sunday of October, 01:00 UTC
+    Eu,
+    /// +1h from the second Sunday of March to the first Sunday of November, 02:00 local
+    Us,
+}
+
+/// Length of the local-time bucket a leaderboard counts completions over
+#[derive(Clone, Copy, Debug, PartialEq, Eq, OdraType)]
+pub enum LeaderboardPeriod {
+    /// Monday-to-Sunday weeks, as in weekly reports
+    Week,
+    /// Calendar months since January 1970
+    Month,
+}
+
+/// Span of care a survey rates
+#[derive(Clone, Copy, Debug, PartialEq, Eq, OdraType)]
+pub enum SurveyPeriod {
+    /// A week bucket, as in weekly reports
+    Week,
+    /// A month bucket, as in leaderboards
+    Month,
+    /// A care episode, by its id within the circle
+    Episode,
+}
+
+/// Who may create tasks in a circle, before per-member overrides
+#[derive(Clone, Copy, Debug, PartialEq, Eq, OdraType)]
+pub enum TaskCreationPolicy {
+    /// Any member
+    Anyone,
+    /// The owner and guardians
+    MembersWithRole,
+    /// The owner alone
+    OwnerOnly,
+}
+
+/// Who may read a circle's task titles and statistics through views
+#[derive(Clone, Copy, Debug, PartialEq, Eq, OdraType)]
+pub enum Visibility {
+    /// Titles and statistics for members only
+    Private,
+    /// Titles for members only, statistics for anyone
+    MembersOnly,
+    /// Titles and statistics for anyone
+    Public,
+}
+
+/// Owner-controlled policy of a circle
+#[derive(Clone, Debug, PartialEq, Eq, OdraType)]
+pub struct CircleSettings {
+    /// Completions wait for owner verification before they count
+    pub require_verification: bool,
+    /// Members may create tasks without an explicit assignee
+    pub allow_open_tasks: bool,
+    /// How open tasks are assigned
+    pub auto_assign_mode: AutoAssignMode,
+    /// Open duration per priority escalation step, 0 = no aging
+    pub priority_aging_secs: u64,
+    /// Idle time after which assignees of critical tasks are flagged, 0 = off
+    pub inactivity_secs: u64,
+    /// Flagged critical tasks are handed to a member picked by `auto_assign_mode`
+    pub reassign_inactive: bool,
+    /// Wait before an unverified completion can be resolved by anyone, 0 = never
+    pub verification_timeout_secs: u64,
+    /// How a timed-out completion is resolved
+    pub timeout_action: TimeoutAction,
+    /// Times each task's due date may be deferred, 0 = deferring disabled
+    pub max_defers: u32,
+    /// Standard time offset from UTC in minutes, used for week buckets and local times
+    pub utc_offset_mins: i32,
+    /// Daylight saving rule on top of `utc_offset_mins`
+    pub dst_rule: DstRule,
+    /// Who may read task titles and statistics
+    pub visibility: Visibility,
+    /// Open tasks a member may hold at once unless overridden, 0 = unlimited
+    pub max_open_per_member: u64,
+    /// Time past the due date a completion still counts as on time
+    pub completion_grace_secs: u64,
+    /// Who may create tasks
+    pub task_creation: TaskCreationPolicy,
+    /// Assignees must accept tasks handed to them by someone else before completing them
+    pub require_acceptance: bool,
+    /// Mileage reimbursement per kilometre in the circle's currency minor unit, 0 = disabled
+    pub mileage_rate: u64,
+    /// Tasks must be created with a title commitment through `create_sealed_task`
+    pub sealed_titles: bool,
+    /// Days after which `purge_expired_records` clears note and evidence hashes, 0 = keep forever
+    pub retention_days: u64,
+}
+
+impl Default for CircleSettings {
+    fn default() -> Self {
+        Self {
+            require_verification: false,
+            allow_open_tasks: true,
+            auto_assign_mode: AutoAssignMode::Off,
+            priority_aging_secs: 0,
+            inactivity_secs: 0,
+            reassign_inactive: false,
+            verification_timeout_secs: 0,
+            timeout_action: TimeoutAction::Confirm,
+            max_defers: 3,
+            utc_offset_mins: 0,
+            dst_rule: DstRule::None,
+            visibility: Visibility::Public,
+            max_open_per_member: 0,
+            completion_grace_secs: 0,
+            task_creation: TaskCreationPolicy::Anyone,
+            require_acceptance: false,
+            mileage_rate: 0,
+            sealed_titles: false,
+            retention_days: 0,
+        }
+    }
+}
+
+/// A completion awaiting owner verification
+#[derive(Clone, Debug, PartialEq, Eq, OdraType)]
+pub struct PendingCompletion {
+    pub completed_by: Address,
+    pub submitted_at: u64,
+}
+
+/// A completion of a critical task collecting co-signatures
+#[derive(Clone, Debug, PartialEq, Eq, OdraType)]
+pub struct CosignRequest {
+    pub completed_by: Address,
+    pub submitted_at: u64,
+    /// Increases with every completion attempt so old signatures do not carry over
+    pub round: u32,
+    pub signatures: u32,
+}
+
+/// Media type of a task evidence item
+#[derive(Clone, Copy, Debug, PartialEq, Eq, OdraType)]
+pub enum EvidenceKind {
+    Photo,
+    Audio,
+    Video,
+    Document,
+}
+
+/// An off-chain evidence file attached to a task
+#[derive(Clone, Debug, PartialEq, Eq, OdraType)]
+pub struct EvidenceItem {
+    pub content_hash: [u8; 32],
+    pub kind: EvidenceKind,
+    pub added_by: Address,
+    pub added_at: u64,
+}
+
+/// A caregiver's arrival at a task's anchored location
+#[derive(Clone, Debug, PartialEq, Eq, OdraType)]
+pub struct CheckIn {
+    pub caregiver: Address,
+    pub checked_in_at: u64,
+    /// Circle oracle that confirmed proximity, if any
+    pub attested_by: Option<Address>,
+    pub attested_at: u64, // 0 until attested
+}
+
+/// A named stretch of care, such as a recovery, that tasks can be grouped under
+#[derive(Clone, Debug, PartialEq, Eq, OdraType)]
+pub struct CareEpisode {
+    pub id: u64, // 1-based within the circle
+    pub circle_id: u64,
+    pub name: String,
+    pub starts_at: u64,
+    pub ends_at: u64, // 0 while ongoing
+    pub created_by: Address,
+}
+
+/// Task counts of one care episode
+#[derive(Clone, Debug, Default, PartialEq, Eq, OdraType)]
+pub struct EpisodeStats {
+    pub tasks: u64,
+    pub completed: u64,
+    /// Completions after the due date plus the circle's grace window
+    pub late: u64,
+}
+
+/// A care supply a circle keeps stock of, such as gloves or oxygen
+#[derive(Clone, Debug, PartialEq, Eq, OdraType)]
+pub struct Supply {
+    pub id: u64, // 1-based within the circle
+    pub name: String,
+    pub quantity: u64,
+    /// `SupplyLow` fires when the quantity drops to or below this
+    pub low_threshold: u64,
+    pub updated_at: u64,
+}
+
+/// A journey a member drove for a task
+#[derive(Clone, Debug, PartialEq, Eq, OdraType)]
+pub struct Trip {
+    pub task_id: u64,
+    pub driver: Address,
+    pub distance_m: u64,
+    /// Hash of the off-chain trip purpose
+    pub purpose_hash: [u8; 32],
+    pub logged_at: u64,
+}
+
+/// Distance a member drove in one month of the circle's local time
+#[derive(Clone, Debug, PartialEq, Eq, OdraType)]
+pub struct MileageReport {
+    pub month: u64,
+    pub distance_m: u64,
+    /// Part of `distance_m` already turned into reimbursement requests
+    pub claimed_m: u64,
+}
+
+/// Where a mileage reimbursement request stands
+#[derive(Clone, Copy, Debug, PartialEq, Eq, OdraType)]
+pub enum ReimbursementStatus {
+    Pending,
+    Approved,
+    Rejected,
+}
+
+/// A member's claim for a month's unclaimed mileage, priced at the circle's rate
+#[derive(Clone, Debug, PartialEq, Eq, OdraType)]
+pub struct ReimbursementRequest {
+    pub id: u64, // 1-based within the circle
+    pub member: Address,
+    pub month: u64,
+    pub distance_m: u64,
+    pub rate: u64,
+    pub amount: u64,
+    pub requested_at: u64,
+    pub status: ReimbursementStatus,
+}
+
+/// Client-encrypted bytes, such as task instructions or contact details;
+/// the contract never sees the key
+#[derive(Clone, Debug, PartialEq, Eq, OdraType)]
+pub struct EncryptedEnvelope {
+    pub id: u64, // 1-based within the circle
+    pub task_id: Option<u64>,
+    pub ciphertext: Bytes,
+    /// Circle key epoch the ciphertext was encrypted under
+    pub key_epoch: u64,
+    pub stored_by: Address,
+    pub stored_at: u64,
+}
+
+/// One task of a discharge plan, due a fixed time after discharge
+#[derive(Clone, Debug, PartialEq, Eq, OdraType)]
+pub struct DischargeStep {
+    pub title: String,
+    pub priority: u8,
+    pub due_after_secs: u64,
+}
+
+/// Tasks instantiated by `start_discharge_plan`, in step order
+#[derive(Clone, Debug, PartialEq, Eq, OdraType)]
+pub struct DischargePlan {
+    pub discharge_at: u64,
+    pub started_by: Address,
+    pub started_at: u64,
+    pub task_ids: Vec<u64>,
+}
+
+/// Blueprint for a task the contract creates on a member's behalf
+#[derive(Clone, Debug, PartialEq, Eq, OdraType)]
+pub struct TaskTemplate {
+    pub title: String,
+    pub priority: u8,
+}
+
+/// One postponement of a task's due date
+#[derive(Clone, Debug, PartialEq, Eq, OdraType)]
+pub struct DeferEntry {
+    pub deferred_by: Address,
+    pub previous_due_at: u64,
+    pub new_due_at: u64,
+    pub reason: String,
+    pub deferred_at: u64,
+}
+
+/// A gated group of entry points the contract admin can switch off
+#[derive(Clone, Copy, Debug, PartialEq, Eq, OdraType)]
+pub enum Feature {
+    /// `request_reimbursement` and `resolve_reimbursement`
+    Reimbursements,
+    /// `register_extension` and `deliver_hooks`; hooks keep queueing
+    Extensions,
+    /// `set_proof_verifier` and `complete_task_with_proof`; verifiers are ignored
+    ProofVerifiers,
+    /// `merge_circles`
+    CircleMerges,
+    /// `claim_ownership_by_inactivity`
+    Succession,
+}
+
+/// Predicted outcome of a call, as returned by the `simulate_*` views
+#[derive(Clone, Debug, PartialEq, Eq, OdraType)]
+pub struct Simulation {
+    pub ok: bool,
+    /// Code of the error the call would revert with (see MESSAGES.md), 0 if it would succeed
+    pub error_code: u32,
+}
+
+/// A view expensive enough that indexers account for calls to it
+#[derive(Clone, Copy, Debug, PartialEq, Eq, OdraType)]
+pub enum HeavyView {
+    Members,
+    Activity,
+    AuditLog,
+    OpenTasks,
+    SymptomLog,
+    MealLog,
+    Trips,
+    ResearchAggregates,
+}
+
+/// A consumer of an indexer whose heavy view calls its operator reports
+#[derive(Clone, Debug, PartialEq, Eq, OdraType)]
+pub struct Reader {
+    pub id: u64, // 1-based
+    pub operator: Address,
+    /// Hash of the operator's name for the consumer, e.g. an API key id
+    pub label_hash: [u8; 32],
+    pub registered_at: u64,
+    pub active: bool,
+}
+
+/// How urgently clients should surface an announcement
+#[derive(Clone, Copy, Debug, PartialEq, Eq, OdraType)]
+pub enum AnnouncementSeverity {
+    Info,
+    Warning,
+    /// Needs action, e.g. an upcoming migration
+    Critical,
+}
+
+/// A platform-wide notice from the contract admin
+#[derive(Clone, Debug, PartialEq, Eq, OdraType)]
+pub struct Announcement {
+    pub seq: u64, // 1-based
+    pub severity: AnnouncementSeverity,
+    /// Hash of the full notice, which clients fetch from `uri`
+    pub content_hash: [u8; 32],
+    pub uri: String,
+    pub posted_at: u64,
+}
+
+/// What a circle reached when `CircleMilestone` is emitted
+#[derive(Clone, Copy, Debug, PartialEq, Eq, OdraType)]
+pub enum MilestoneKind {
+    /// Completed tasks, counting shared tasks in both circles
+    Completions,
+    Members,
+    /// Years since the circle was created
+    Anniversary,
+}
+
+/// Kind of action recorded in a circle's activity feed
+#[derive(Clone, Copy, Debug, PartialEq, Eq, OdraType)]
+pub enum ActivityKind {
+    CircleCreated,
+    MemberAdded,
+    SettingsUpdated,
+    CareRecipientSet,
+    ProfileUpdated,
+    AvailabilityUpdated,
+    TaskCreated,
+    DueDateSet,
+    EstimateSet,
+    CompletionSubmitted,
+    CompletionDelegated,
+    CompletionRejected,
+    TaskCompleted,
+    TaskLinkProposed,
+    TaskLinked,
+    SlaTargetsUpdated,
+    VolunteerListingChanged,
+    VolunteerApplied,
+    VolunteerAccepted,
+    LabelAdded,
+    LabelRemoved,
+    SurveySubmitted,
+    TaskMoved,
+    TaskDeferred,
+    CompletionCosigned,
+    AssignmentAccepted,
+    AssignmentDeclined,
+    HelpRequested,
+    HelperJoined,
+    EpisodeUpdated,
+    TaskEpisodeSet,
+    SupplyAdjusted,
+    TripLogged,
+    ReimbursementUpdated,
+    StatusUpdated,
+    TitleRevealed,
+    EnvelopeUpdated,
+    KeyEpochRotated,
+    MemberDataErased,
+    IncidentReported,
+    TaskLocationSet,
+    RestockTaskSet,
+    CheckedIn,
+    PresenceAttested,
+    EvidenceAdded,
+    SymptomLogged,
+    MealLogged,
+}
+
+/// One entry of a circle's activity feed
+#[derive(Clone, Debug, PartialEq, Eq, OdraType)]
+pub struct ActivityEntry {
+    pub actor: Address,
+    pub kind: ActivityKind,
+    /// Task id for task actions, circle id for circle actions
+    pub subject_id: u64,
+    pub timestamp: u64,
+}
+
+/// Kind of administrative action recorded in a circle's audit log
+#[derive(Clone, Copy, Debug, PartialEq, Eq, OdraType)]
+pub enum AuditAction {
+    MemberAdded,
+    SettingsUpdated,
+    CareRecipientSet,
+    JoinCodeCreated,
+    JoinCodeRevoked,
+    GuardianAppointed,
+    GuardianRenounced,
+    TaskMoved,
+    SlaTargetsUpdated,
+    VolunteerAccepted,
+    TaskLinkAccepted,
+    HealthRecordSet,
+    HealthRecordRemoved,
+    ConsentGranted,
+    ConsentRevoked,
+    MergeApproved,
+    CircleMerged,
+    CircleCloned,
+    SuccessorDesignated,
+    SuccessionClaimed,
+    SuccessionCancelled,
+    OwnershipTransferred,
+    MemberSuspended,
+    MemberReinstated,
+    AgencyGranted,
+    AgencyRevoked,
+    ExtensionRegistered,
+    ExtensionRemoved,
+    ProofVerifierSet,
+    AuditorGranted,
+    AuditorRevoked,
+    ResearchOptInChanged,
+    JoinCodeReferrerSet,
+    HookSkipped,
+    MedicationAdded,
+    MedicationStopped,
+}
+
+/// One entry of a circle's append-only audit log
+#[derive(Clone, Debug, PartialEq, Eq, OdraType)]
+pub struct AuditEntry {
+    pub seq: u64,
+    pub actor: Address,
+    pub action: AuditAction,
+    /// Address whose permissions changed, if any
+    pub target: Option<Address>,
+    /// Task id for task actions, circle id for circle actions
+    pub subject_id: u64,
+    pub timestamp: u64,
+}
+
+/// One link of a caregiver's completion receipt chain
+#[derive(Clone, Debug, PartialEq, Eq, OdraType)]
+pub struct CompletionReceipt {
+    pub task_id: u64,
+    pub circle_id: u64,
+    pub timestamp: u64,
+}
+
+/// One completion in a caregiver's cross-circle work history
+#[derive(Clone, Debug, PartialEq, Eq, OdraType)]
+pub struct WorkRecord {
+    pub circle_id: u64,
+    pub task_id: u64,
+    /// Task priority at completion; tasks carry no category
+    pub priority: u8,
+    pub completed_at: u64,
+    /// Approved by the circle owner or co-signed by other members
+    pub verified: bool,
+    /// Care recipient's survey average for the completion week in hundredths, 0 if unrated
+    pub rating: u64,
+}
+
+/// Circle stats for one finalized week bucket
+#[derive(Clone, Debug, PartialEq, Eq, OdraType)]
+pub struct WeeklySnapshot {
+    pub week: u64,
+    pub completions: u64,
+    /// Tasks due that week that were not completed by their due date
+    pub overdue: u64,
+    pub top_contributor: Option<Address>,
+    pub top_contributor_completions: u64,
+}
+
+/// A care quality survey submitted by a circle's care recipient
+#[derive(Clone, Debug, PartialEq, Eq, OdraType)]
+pub struct Survey {
+    pub submitted_by: Address,
+    pub scores: Bytes,
+    /// Hash of the free-text comment kept off-chain
+    pub comment_hash: [u8; 32],
+    pub submitted_at: u64,
+}
+
+/// Kind of a care recipient health record
+#[derive(Clone, Copy, Debug, PartialEq, Eq, OdraType)]
+pub enum HealthRecordKind {
+    Allergy,
+    Condition,
+}
+
+/// Clinical severity of an allergy or condition
+#[derive(Clone, Copy, Debug, PartialEq, Eq, OdraType)]
+pub enum Severity {
+    Mild,
+    Moderate,
+    Severe,
+}
+
+/// An allergy or condition of a circle's care recipient, keyed by substance hash
+#[derive(Clone, Debug, PartialEq, Eq, OdraType)]
+pub struct HealthRecord {
+    /// Hash of the substance or condition identifier kept off-chain
+    pub substance_hash: [u8; 32],
+    pub kind: HealthRecordKind,
+    pub severity: Severity,
+    /// Hash of the free-text notes kept off-chain
+    pub notes_hash: [u8; 32],
+    pub updated_by: Address,
+    pub updated_at: u64,
+}
+
+/// A symptom or behavior a caregiver observed in the care recipient
+#[derive(Clone, Debug, PartialEq, Eq, OdraType)]
+pub struct SymptomEntry {
+    /// Hash of the symptom identifier kept off-chain, e.g. "confusion"
+    pub symptom_hash: [u8; 32],
+    /// 0 (absent) to 10 (worst)
+    pub severity: u8,
+    /// Hash of the free-text notes kept off-chain
+    pub notes_hash: [u8; 32],
+    pub logged_by: Address,
+    pub logged_at: u64,
+}
+
+/// One local day of a symptom trend
+#[derive(Clone, Debug, PartialEq, Eq, OdraType)]
+pub struct SymptomTrendPoint {
+    /// Days since 1970-01-01 in the circle's local time
+    pub day: u64,
+    pub entries: u64,
+    /// Mean severity in hundredths, 0 without entries
+    pub average_severity: u64,
+}
+
+/// Which meal of the day a meal entry records
+#[derive(Clone, Copy, Debug, PartialEq, Eq, OdraType)]
+pub enum MealType {
+    Breakfast,
+    Lunch,
+    Dinner,
+    Snack,
+}
+
+/// A shared note on how the care recipient is doing right now
+#[derive(Clone, Debug, PartialEq, Eq, OdraType)]
+pub struct StatusUpdate {
+    /// Client-defined code, e.g. resting, at physio, having a rough day
+    pub status_code: u32,
+    pub note_hash: [u8; 32],
+    pub updated_by: Address,
+    pub updated_at: u64,
+}
+
+/// An incident involving the care recipient, such as a fall or a medication error
+#[derive(Clone, Debug, PartialEq, Eq, OdraType)]
+pub struct IncidentReport {
+    /// Client-defined code, e.g. fall, wandering, missed medication
+    pub kind_code: u32,
+    /// Hash of the incident details kept off-chain
+    pub details_hash: [u8; 32],
+    pub reported_by: Address,
+    pub reported_at: u64,
+}
+
+/// A medication the care recipient takes, identified like health record substances
+#[derive(Clone, Debug, PartialEq, Eq, OdraType)]
+pub struct Medication {
+    /// Hash of the medication identifier kept off-chain
+    pub substance_hash: [u8; 32],
+    /// Hash of the dose and schedule kept off-chain
+    pub dose_hash: [u8; 32],
+    pub added_by: Address,
+    pub added_at: u64,
+    /// When the medication was stopped, 0 while it is taken
+    pub stopped_at: u64,
+}
+
+/// A meal served to the care recipient and how much of it was eaten
+#[derive(Clone, Debug, PartialEq, Eq, OdraType)]
+pub struct MealEntry {
+    /// Meal preparation task this entry reports on
+    pub task_id: Option<u64>,
+    pub meal_type: MealType,
+    /// Share of the meal consumed, 0-100
+    pub consumed_pct: u8,
+    /// Hash of the free-text notes kept off-chain
+    pub notes_hash: [u8; 32],
+    pub logged_by: Address,
+    pub logged_at: u64,
+}
+
+/// Meals logged on one local day
+#[derive(Clone, Debug, PartialEq, Eq, OdraType)]
+pub struct MealDay {
+    /// Days since 1970-01-01 in the circle's local time
+    pub day: u64,
+    pub meals: u64,
+    /// Mean share consumed in hundredths of a percent, 0 without meals
+    pub average_consumed_pct: u64,
+}
+
+/// Category of care recipient data whose views are gated by consent
+#[derive(Clone, Copy, Debug, PartialEq, Eq, OdraType)]
+pub enum ConsentScope {
+    /// Allergies and conditions
+    HealthRecords,
+    Vitals,
+    Incidents,
+    Medications,
+    /// Symptom and behavior log
+    Symptoms,
+    /// Meal log
+    Nutrition,
+}
+
+/// Who takes over a circle if its owner goes quiet, and the state of their claim
+#[derive(Clone, Debug, PartialEq, Eq, OdraType)]
+pub struct SuccessionPlan {
+    pub successor: Address,
+    /// Owner idle time after which the successor may open a claim
+    pub inactivity_secs: u64,
+    /// Time the owner has to cancel an open claim
+    pub challenge_secs: u64,
+    /// Block time the open claim started, 0 if none
+    pub claim_started_at: u64,
+}
+
+/// A shareable invitation, stored under the hash of its code
+#[derive(Clone, Debug, PartialEq, Eq, OdraType)]
+pub struct JoinCode {
+    pub max_uses: u64,
+    pub uses: u64,
+    pub expires_at: u64, // 0 if it never expires
+    pub revoked: bool,
+}
+
+/// A member who joined through a join code carrying a referrer
+#[derive(Clone, Debug, PartialEq, Eq, OdraType)]
+pub struct Referral {
+    pub referrer: Address,
+    pub recruit: Address,
+    pub joined_at: u64,
+    /// Block time of the recruit's first verified completion
+    pub credited_at: Option<u64>,
+}
+
+/// Human-readable details a member sets about themselves in a circle
+#[derive(Clone, Debug, Default, PartialEq, Eq, OdraType)]
+pub struct MemberProfile {
+    pub display_name: String,
+    pub relationship: String, // e.g. "daughter", "home nurse"
+}
+
+/// A member address with its profile, as returned by `get_members`
+#[derive(Clone, Debug, PartialEq, Eq, OdraType)]
+pub struct MemberInfo {
+    pub address: Address,
+    pub profile: MemberProfile,
+    pub is_guardian: bool,
+    pub is_suspended: bool,
+}
+
+/// A member's open workload, as returned by `get_workload`
+#[derive(Clone, Debug, PartialEq, Eq, OdraType)]
+pub struct MemberWorkload {
+    pub member: Address,
+    pub open_tasks: u64,
+    pub open_minutes: u64,
+}
+
+/// A period during which a member is available for caregiving
+#[derive(Clone, Debug, PartialEq, Eq, OdraType)]
+pub struct AvailabilityWindow {
+    pub start: u64,
+    pub end: u64,
+}
+
+/// SLA results for one circle and week bucket
+#[derive(Clone, Debug, Default, PartialEq, Eq, OdraType)]
+pub struct SlaPeriodStats {
+    /// Completions that had an SLA target
+    pub tracked: u64,
+    /// Completions within their target
+    pub met: u64,
+    /// Completions of tasks that had a due date
+    pub due_tracked: u64,
+    /// Completions after their due date plus the grace window
+    pub late: u64,
+}
+
+/// A member's completions in a leaderboard period
+#[derive(Clone, Debug, PartialEq, Eq, OdraType)]
+pub struct LeaderboardEntry {
+    pub member: Address,
+    pub completions: u64,
+}
+
+/// Platform-wide metrics, as returned by `get_platform_stats`
+#[derive(Clone, Debug, Default, PartialEq, Eq, OdraType)]
+pub struct PlatformStats {
+    pub total_circles: u64,
+    /// Circles that were not archived by a merge
+    pub active_circles: u64,
+    pub total_tasks: u64,
+    pub total_completions: u64,
+    /// Completions in the current UTC week bucket
+    pub completions_this_week: u64,
+    /// Distinct addresses that completed at least one task
+    pub unique_caregivers: u64,
+}
+
+/// Anonymized aggregates over completions in research opt-in circles, as
+/// returned by `get_research_aggregates`
+#[derive(Clone, Debug, Default, PartialEq, Eq, OdraType)]
+pub struct ResearchAggregates {
+    pub opted_in_circles: u64,
+    /// Completions per task priority, indexed by priority
+    pub completions_by_priority: Vec<u64>,
+    /// Mean creation-to-completion time per task priority, in seconds
+    pub mean_latency_secs: Vec<u64>,
+    /// Completions taking under an hour, a day, a week, and longer
+    pub latency_histogram: Vec<u64>,
+}
+
+/// Append-only log whose hashes or notes the retention keeper or an erasure clears
+#[derive(Clone, Copy, Debug, PartialEq, Eq, OdraType)]
+pub enum RetainedLog {
+    /// Symptom notes
+    Symptoms,
+    /// Meal notes
+    Meals,
+    /// Task evidence content
+    Evidence,
+    /// Trip purposes
+    Trips,
+    /// Incident details
+    Incidents,
+    /// Help request notes, cleared for erased members but not by age
+    HelpNotes,
+}
+
+/// Keeper that walks a circle's open tasks a bounded page at a time
+#[derive(Clone, Copy, Debug, PartialEq, Eq, OdraType)]
+pub enum OpenTaskKeeper {
+    /// `emit_due_reminders`
+    DueReminders,
+    /// `escalate_priorities`
+    PriorityAging,
+    /// `flag_inactive_assignees`
+    Inactivity,
+}
+
+/// Circle event an extension contract is notified of
+#[derive(Clone, Copy, Debug, PartialEq, Eq, OdraType)]
+pub enum HookKind {
+    /// A task was completed; the subject is the task id
+    TaskCompleted,
+    /// An incident was reported; the subject is its index in the incident log
+    IncidentReported,
+}
+
+/// A queued notification waiting for delivery to a circle's extensions
+#[derive(Clone, Debug, PartialEq, Eq, OdraType)]
+pub struct HookCall {
+    pub kind: HookKind,
+    pub subject_id: u64,
+    pub queued_at: u64,
+}
+
+// ==================== External Contracts ====================
+
+/// Interface add-on contracts implement to receive circle hooks
+#[odra::external_contract]
+pub trait CareExtension {
+    fn on_care_hook(&mut self, circle_id: u64, kind: HookKind, subject_id: u64);
+}
+
+/// Interface of contracts that check completion proofs, such as ZK or oracle verifiers
+#[odra::external_contract]
+pub trait ProofVerifier {
+    fn verify(&self, task_id: u64, proof: Bytes) -> bool;
+}
+
+// ==================== Errors ====================
+
+/// Typed contract errors, one variant per code in MESSAGES.md. Codes 39, 59
+/// and 66 are unassigned, so they have no variant.
+#[odra::odra_error]
+pub enum Error {
+    /// Caller is not the circle owner
+    NotOwner = 1,
+    /// The address is already a member of the circle
+    AlreadyMember = 2,
+    /// Caller is not a member of the circle
+    NotAMember = 3,
+    /// The assignee is not a member of the circle
+    AssigneeNotAMember = 4,
+    /// The task is already completed
+    AlreadyCompleted = 5,
+    /// Caller is not assigned the task
+    NotAssignee = 6,
+    /// Caller is neither the task creator nor the circle owner
+    NotCreatorOrOwner = 7,
+    /// The circle has auto-assignment switched off
+    AutoAssignmentDisabled = 8,
+    /// More availability windows than a member may declare
+    TooManyWindows = 9,
+    /// An availability window does not end after it starts
+    InvalidWindow = 10,
+    /// More SLA targets than there are priorities
+    TooManySlaTargets = 11,
+    /// Caller is not a registered volunteer
+    NotAVolunteer = 12,
+    /// The task is not open to volunteers
+    NotOpenToVolunteers = 13,
+    /// The volunteer already applied for the task
+    AlreadyApplied = 14,
+    /// The volunteer has not applied for the task
+    NotAnApplicant = 15,
+    /// The task has the maximum number of applicants
+    TooManyApplicants = 16,
+    /// The task cannot be linked to that circle, or has no link pending
+    InvalidTaskLink = 17,
+    /// The task has a completion awaiting verification
+    CompletionPendingVerification = 18,
+    /// The circle has open tasks switched off
+    OpenTasksDisabled = 19,
+    /// The task has no completion awaiting verification
+    NoPendingCompletion = 20,
+    /// A profile field exceeds its length limit
+    ProfileFieldTooLong = 21,
+    /// The label is empty, too long or not on the task
+    InvalidLabel = 22,
+    /// The task already carries the label
+    LabelAlreadySet = 23,
+    /// The task has the maximum number of labels
+    TooManyLabels = 24,
+    /// Caller is neither the assignee nor the circle owner
+    NotAssigneeOrOwner = 25,
+    /// The week has not ended yet
+    WeekNotOver = 26,
+    /// Caller is neither the care recipient nor a guardian
+    NotCareRecipientOrGuardian = 27,
+    /// A survey score is out of range
+    InvalidSurveyScores = 28,
+    /// The survey for the period was already submitted
+    SurveyAlreadySubmitted = 29,
+    /// No join code matches
+    InvalidJoinCode = 30,
+    /// The join code has expired
+    JoinCodeExpired = 31,
+    /// The join code has been used its maximum number of times
+    JoinCodeExhausted = 32,
+    /// A join code with that hash already exists
+    JoinCodeAlreadyExists = 33,
+    /// The address is not a guardian of the circle
+    NotAGuardian = 34,
+    /// A task title exceeds its length limit
+    TitleTooLong = 35,
+    /// The priority is above the highest one
+    InvalidPriority = 36,
+    /// The circle has the maximum number of members
+    TooManyMembers = 37,
+    /// The task is shared with another circle
+    TaskIsShared = 38,
+    /// The target circle is the source circle itself
+    InvalidTargetCircle = 40,
+    /// The verification timeout has not passed yet
+    VerificationTimeoutNotReached = 41,
+    /// Caller is neither the circle owner nor a guardian
+    NotOwnerOrGuardian = 42,
+    /// The circle has the maximum number of health records
+    TooManyHealthRecords = 43,
+    /// No health record has that id
+    HealthRecordNotFound = 44,
+    /// The UTC offset is out of range
+    InvalidUtcOffset = 45,
+    /// The time of day is out of range
+    InvalidTimeOfDay = 46,
+    /// A defer reason exceeds its length limit
+    DeferReasonTooLong = 47,
+    /// The task has no due date, or the new one is not after it and now
+    InvalidDeferDate = 48,
+    /// The task has been deferred the maximum number of times
+    DeferLimitReached = 49,
+    /// The co-signer count is zero or above the maximum
+    InvalidCoSignerCount = 50,
+    /// The completion is still waiting for co-signatures
+    CoSignaturesPending = 51,
+    /// The task has no co-signature request open
+    NoCoSignaturesPending = 52,
+    /// The completer cannot co-sign their own completion
+    CannotCoSignOwnCompletion = 53,
+    /// Caller already co-signed the completion
+    AlreadyCoSigned = 54,
+    /// More onboarding tasks than a circle may hold
+    TooManyOnboardingTasks = 55,
+    /// The member has the maximum number of notification routes
+    TooManyNotificationRoutes = 56,
+    /// The notification route is already registered
+    RouteAlreadyRegistered = 57,
+    /// No notification route has that id
+    RouteNotFound = 58,
+    /// The circle has been merged into another and is archived
+    CircleArchived = 60,
+    /// The named successor is not a member of the circle
+    SuccessorNotAMember = 61,
+    /// The succession inactivity period is zero
+    InvalidSuccessionPeriods = 62,
+    /// Caller is not the circle's named successor
+    NotSuccessor = 63,
+    /// The owner has been active within the inactivity period
+    OwnerStillActive = 64,
+    /// The succession challenge window has not closed
+    ChallengeWindowOpen = 65,
+    /// The member is suspended from the circle
+    MemberSuspended = 67,
+    /// The circle owner cannot be suspended
+    CannotSuspendOwner = 68,
+    /// The assignee already holds their maximum number of open tasks
+    AssignmentLimitReached = 69,
+    /// Caller is not the contract admin
+    NotAdmin = 70,
+    /// The agency has not been verified by the contract admin
+    AgencyNotVerified = 71,
+    /// The caregiver is registered with another agency
+    CaregiverHasAgency = 72,
+    /// The caregiver is not registered with the calling agency
+    NotAgencyCaregiver = 73,
+    /// The agency has registered the maximum number of caregivers
+    TooManyAgencyCaregivers = 74,
+    /// The circle's task creation policy does not allow the caller
+    TaskCreationNotAllowed = 75,
+    /// The assignee has not accepted the task yet
+    AssignmentNotAccepted = 76,
+    /// The task is not awaiting acceptance
+    NoAcceptancePending = 77,
+    /// A decline reason or help note exceeds its length limit
+    ReasonTooLong = 78,
+    /// Nobody but the decliner can take the task
+    NoAssigneeAvailable = 79,
+    /// The assignee has not asked for help on the task
+    HelpNotRequested = 80,
+    /// The caller already helps on the task, or is its assignee
+    AlreadyHelper = 81,
+    /// The task has the maximum number of helpers
+    TooManyHelpers = 82,
+    /// The task has no anchored location
+    NoTaskLocation = 83,
+    /// The submitted commitment does not match the task's location
+    LocationMismatch = 84,
+    /// Nobody has checked in to the task
+    NoCheckIn = 85,
+    /// Caller is not a presence oracle of the circle
+    NotPresenceOracle = 86,
+    /// The task has the maximum number of evidence items
+    TooManyEvidenceItems = 87,
+    /// Symptom severity is above the 0-10 scale
+    SeverityOutOfRange = 88,
+    /// Consumed share is above 100 percent
+    InvalidConsumedPercent = 89,
+    /// The linked task belongs to another circle
+    TaskNotInCircle = 90,
+    /// Discharge steps must not be due before the step ahead of them
+    DischargeStepsOutOfOrder = 91,
+    /// The discharge template has too many steps
+    TooManyDischargeSteps = 92,
+    /// The discharge time is zero
+    InvalidDischargeTime = 93,
+    /// No such care episode in the circle
+    EpisodeNotFound = 94,
+    /// The episode still has tasks
+    EpisodeNotEmpty = 95,
+    /// The episode ends before it starts
+    InvalidEpisodeRange = 96,
+    /// No such supply in the circle
+    SupplyNotFound = 97,
+    /// Taking more of a supply than is in stock
+    InsufficientSupply = 98,
+    /// The circle has no mileage rate set
+    ReimbursementDisabled = 99,
+    /// No unclaimed mileage in the month
+    NothingToReimburse = 100,
+    /// No such reimbursement request in the circle
+    ReimbursementNotFound = 101,
+    /// The reimbursement request was already approved or rejected
+    ReimbursementResolved = 102,
+    /// The circle already has the most extensions allowed
+    TooManyExtensions = 103,
+    /// The address is not a registered extension of the circle
+    NotExtension = 104,
+    /// The address is already a registered extension of the circle
+    AlreadyExtension = 105,
+    /// The circle's verifier needs a proof, use `complete_task_with_proof`
+    ProofRequired = 106,
+    /// The circle's verifier rejected the proof
+    ProofRejected = 107,
+    /// The circle has no proof verifier configured
+    NoProofVerifier = 108,
+    /// The proof is larger than the contract accepts
+    ProofTooLarge = 109,
+    /// The circle only accepts sealed task titles
+    SealedTitleRequired = 110,
+    /// The task has no unrevealed title commitment
+    TitleNotSealed = 111,
+    /// The title and salt do not match the commitment
+    TitleMismatch = 112,
+    /// The ciphertext is larger than an envelope holds
+    CiphertextTooLarge = 113,
+    /// The circle already stores the most envelopes allowed
+    TooManyEnvelopes = 114,
+    /// No such envelope in the circle
+    EnvelopeNotFound = 115,
+    /// Auditor access must expire in the future and within the maximum grant period
+    InvalidAuditorExpiry = 116,
+    /// Announcement URI exceeds the maximum length
+    UriTooLong = 117,
+    /// No circle with this id
+    CircleNotFound = 118,
+    /// No task with this id
+    TaskNotFound = 119,
+    /// The contract admin has switched this feature off
+    FeatureDisabled = 120,
+    /// No reader with this id
+    ReaderNotFound = 121,
+    /// Caller is not the operator who registered the reader
+    NotReaderOperator = 122,
+    /// The reader was deactivated by its operator
+    ReaderInactive = 123,
+    /// The extension has been delivered every queued hook
+    NoHookQueued = 124,
+    /// Caller is neither the circle owner nor the extension
+    NotOwnerOrExtension = 125,
+    /// The source circle has more members or open tasks than one merge moves
+    MergeTooLarge = 126,
+    /// A task's circle, per-circle number, episode id or a time does not fit its storage record
+    TaskFieldOverflow = 127,
+    /// The month or care episode being rated has not ended yet
+    PeriodNotOver = 128,
+    /// No medication with this index in the circle
+    MedicationNotFound = 129,
+    /// The medication has already been stopped
+    MedicationStopped = 130,
 }
 
 // ==================== Events ====================
@@ -49,6 +1199,8 @@ pub struct MemberAdded {
     pub circle_id: u64,
     pub member: Address,
     pub added_by: Address,
+    /// Notification routes registered for the circle
+    pub routes: Vec<[u8; 32]>,
 }
 
 /// Emitted when a new task is created
@@ -56,8 +1208,11 @@ pub struct MemberAdded {
 pub struct TaskCreated {
     pub task_id: u64,
     pub circle_id: u64,
+    pub circle_task_seq: u64,
     pub title: String,
     pub assigned_to: Address,
+    /// Notification routes registered for the circle
+    pub routes: Vec<[u8; 32]>,
 }
 
 /// Emitted when a task is completed - this is the verifiable proof!
@@ -65,263 +1220,7625 @@ pub struct TaskCreated {
 pub struct TaskCompleted {
     pub task_id: u64,
     pub circle_id: u64,
+    pub assigned_to: Address,
     pub completed_by: Address,
     pub timestamp: u64,
+    /// Agency that brought the completer into the circle
+    pub agency: Option<Address>,
+    /// Evidence items attached to the task
+    pub evidence_count: u64,
+    /// Notification routes registered for the circle
+    pub routes: Vec<[u8; 32]>,
 }
 
-// ==================== Contract Module ====================
+/// Emitted with `TaskCompleted` when members helped on the task, crediting them with a share
+#[derive(OdraEvent)]
+pub struct HelpersCredited {
+    pub task_id: u64,
+    pub circle_id: u64,
+    pub helpers: Vec<Address>,
+    /// Notification routes registered for the circle
+    pub routes: Vec<[u8; 32]>,
+}
 
-#[odra::module(events = [CircleCreated, MemberAdded, TaskCreated, TaskCompleted])]
-pub struct CareCircle {
-    // Counters
-    next_circle_id: Var<u64>,
-    next_task_id: Var<u64>,
-    
-    // Circle storage
-    circles: Mapping<u64, Circle>,
-    
-    // Member storage: (circle_id, member_index) -> Address
-    // We use a count + index pattern for simplicity
-    circle_member_count: Mapping<u64, u64>,
-    circle_members: Mapping<(u64, u64), Address>,
-    
-    // Is member check: (circle_id, address) -> bool
-    is_member: Mapping<(u64, Address), bool>,
-    
-    // Task storage
-    tasks: Mapping<u64, Task>,
-    
-    // Circle task count
-    circle_task_count: Mapping<u64, u64>,
-    
-    // Stats
-    total_circles: Var<u64>,
-    total_tasks: Var<u64>,
-    total_completions: Var<u64>,
+/// Emitted when a completion is submitted for owner verification
+#[derive(OdraEvent)]
+pub struct CompletionSubmitted {
+    pub task_id: u64,
+    pub circle_id: u64,
+    pub submitted_by: Address,
+    pub timestamp: u64,
+    /// Notification routes registered for the circle
+    pub routes: Vec<[u8; 32]>,
 }
 
-#[odra::module]
-impl CareCircle {
-    /// Initialize the contract
-    #[odra(init)]
-    pub fn init(&mut self) {
-        self.next_circle_id.set(1);
-        self.next_task_id.set(1);
-        self.total_circles.set(0);
-        self.total_tasks.set(0);
-        self.total_completions.set(0);
-    }
+/// Emitted when the owner rejects a submitted completion
+#[derive(OdraEvent)]
+pub struct CompletionRejected {
+    pub task_id: u64,
+    pub circle_id: u64,
+    pub rejected_by: Address,
+    /// Notification routes registered for the circle
+    pub routes: Vec<[u8; 32]>,
+}
 
-    // ==================== Circle Management ====================
+/// Emitted when a completion is appended to a caregiver's receipt chain
+#[derive(OdraEvent)]
+pub struct ReceiptAppended {
+    pub caregiver: Address,
+    pub task_id: u64,
+    pub circle_id: u64,
+    pub timestamp: u64,
+    pub head: [u8; 32],
+    /// Notification routes registered for the circle
+    pub routes: Vec<[u8; 32]>,
+}
 
-    /// Create a new care circle
-    /// The caller becomes the owner and first member
-    pub fn create_circle(&mut self, name: String) -> u64 {
-        let env = self.env();
-        let owner = env.caller();
-        let timestamp = env.get_block_time();
-        
-        let id = self.next_circle_id.get_or_default();
-        self.next_circle_id.set(id + 1);
+/// Emitted when a circle's week bucket is finalized
+#[derive(OdraEvent)]
+pub struct WeeklyDigest {
+    pub circle_id: u64,
+    pub week: u64,
+    pub completions: u64,
+    pub overdue: u64,
+    pub top_contributor: Option<Address>,
+    /// Notification routes registered for the circle
+    pub routes: Vec<[u8; 32]>,
+}
 
-        let circle = Circle {
-            id,
-            name: name.clone(),
-            owner,
-            created_at: timestamp,
-            member_count: 1,
-            task_count: 0,
-        };
+/// Emitted by the inactivity keeper for an idle assignee of a critical task
+#[derive(OdraEvent)]
+pub struct AssigneeInactive {
+    pub task_id: u64,
+    pub circle_id: u64,
+    pub assignee: Address,
+    pub last_active_at: u64,
+    pub reassigned_to: Option<Address>,
+    /// Notification routes registered for the circle
+    pub routes: Vec<[u8; 32]>,
+}
 
-        // Store circle
-        self.circles.set(&id, circle);
-        
-        // Add owner as first member
-        self.circle_member_count.set(&id, 1);
-        self.circle_members.set(&(id, 0), owner);
-        self.is_member.set(&(id, owner), true);
-        
-        // Update stats
-        self.total_circles.set(self.total_circles.get_or_default() + 1);
+/// Emitted when a task is moved to another circle
+#[derive(OdraEvent)]
+pub struct TaskMoved {
+    pub task_id: u64,
+    pub from_circle_id: u64,
+    pub to_circle_id: u64,
+    /// The task's new number within the target circle
+    pub circle_task_seq: u64,
+    pub moved_by: Address,
+    /// Notification routes of the circle(s) involved
+    pub routes: Vec<[u8; 32]>,
+}
+
+/// Emitted when a member co-signs a critical task's completion
+#[derive(OdraEvent)]
+pub struct CompletionCosigned {
+    pub task_id: u64,
+    pub circle_id: u64,
+    pub cosigner: Address,
+    pub signatures: u32,
+    pub required: u32,
+    /// Notification routes registered for the circle
+    pub routes: Vec<[u8; 32]>,
+}
+
+/// Emitted when an assignee accepts a task awaiting acceptance
+#[derive(OdraEvent)]
+pub struct AssignmentAccepted {
+    pub task_id: u64,
+    pub circle_id: u64,
+    pub accepted_by: Address,
+    /// Notification routes registered for the circle
+    pub routes: Vec<[u8; 32]>,
+}
+
+/// Emitted when an assignee declines a task and it is handed on
+#[derive(OdraEvent)]
+pub struct AssignmentDeclined {
+    pub task_id: u64,
+    pub circle_id: u64,
+    pub declined_by: Address,
+    pub reason: String,
+    pub reassigned_to: Address,
+    /// Notification routes registered for the circle
+    pub routes: Vec<[u8; 32]>,
+}
+
+/// Emitted when an assignee asks other members for backup on a task
+#[derive(OdraEvent)]
+pub struct HelpRequested {
+    pub task_id: u64,
+    pub circle_id: u64,
+    pub requested_by: Address,
+    pub note: String,
+    /// Notification routes registered for the circle
+    pub routes: Vec<[u8; 32]>,
+}
+
+/// Emitted when a member joins a task as a helper
+#[derive(OdraEvent)]
+pub struct HelperJoined {
+    pub task_id: u64,
+    pub circle_id: u64,
+    pub helper: Address,
+    /// Notification routes registered for the circle
+    pub routes: Vec<[u8; 32]>,
+}
+
+/// Emitted when an evidence item is attached to a task
+#[derive(OdraEvent)]
+pub struct EvidenceAdded {
+    pub task_id: u64,
+    pub circle_id: u64,
+    pub content_hash: [u8; 32],
+    pub kind: EvidenceKind,
+    pub added_by: Address,
+    /// Notification routes registered for the circle
+    pub routes: Vec<[u8; 32]>,
+}
+
+/// Emitted when a caregiver checks in at a task's location
+#[derive(OdraEvent)]
+pub struct CheckedIn {
+    pub task_id: u64,
+    pub circle_id: u64,
+    pub caregiver: Address,
+    pub timestamp: u64,
+    /// Notification routes registered for the circle
+    pub routes: Vec<[u8; 32]>,
+}
+
+/// Emitted when a circle oracle attests a caregiver was near the task's location
+#[derive(OdraEvent)]
+pub struct PresenceAttested {
+    pub task_id: u64,
+    pub circle_id: u64,
+    pub caregiver: Address,
+    pub oracle: Address,
+    /// Notification routes registered for the circle
+    pub routes: Vec<[u8; 32]>,
+}
+
+/// Emitted when a task's due date is pushed back
+#[derive(OdraEvent)]
+pub struct TaskDeferred {
+    pub task_id: u64,
+    pub circle_id: u64,
+    pub deferred_by: Address,
+    pub previous_due_at: u64,
+    pub new_due_at: u64,
+    /// Notification routes registered for the circle
+    pub routes: Vec<[u8; 32]>,
+}
+
+/// Emitted when the owner changes circle settings
+#[derive(OdraEvent)]
+pub struct SettingsUpdated {
+    pub circle_id: u64,
+    pub updated_by: Address,
+    /// Notification routes registered for the circle
+    pub routes: Vec<[u8; 32]>,
+}
+
+/// Emitted by the reminder keeper for open tasks that are due soon
+#[derive(OdraEvent)]
+pub struct TaskDueSoon {
+    pub task_id: u64,
+    pub circle_id: u64,
+    pub assigned_to: Address,
+    pub due_at: u64,
+    /// Notification routes registered for the circle
+    pub routes: Vec<[u8; 32]>,
+}
+
+/// Emitted when an aging task is raised to a higher priority
+#[derive(OdraEvent)]
+pub struct PriorityEscalated {
+    pub task_id: u64,
+    pub circle_id: u64,
+    pub old_priority: u8,
+    pub new_priority: u8,
+    /// Notification routes registered for the circle
+    pub routes: Vec<[u8; 32]>,
+}
+
+/// Emitted when a task is completed later than its circle's SLA target
+#[derive(OdraEvent)]
+pub struct SlaBreached {
+    pub task_id: u64,
+    pub circle_id: u64,
+    pub priority: u8,
+    pub target_secs: u64,
+    pub latency_secs: u64,
+    /// Notification routes registered for the circle
+    pub routes: Vec<[u8; 32]>,
+}
+
+/// Emitted when a registered volunteer applies for an open task
+#[derive(OdraEvent)]
+pub struct VolunteerApplied {
+    pub task_id: u64,
+    pub circle_id: u64,
+    pub volunteer: Address,
+    /// Notification routes registered for the circle
+    pub routes: Vec<[u8; 32]>,
+}
+
+/// Emitted when the owner hands a task to a volunteer
+#[derive(OdraEvent)]
+pub struct VolunteerAccepted {
+    pub task_id: u64,
+    pub circle_id: u64,
+    pub volunteer: Address,
+    pub accepted_by: Address,
+    /// Notification routes registered for the circle
+    pub routes: Vec<[u8; 32]>,
+}
+
+/// Emitted when a task is shared with a secondary circle
+#[derive(OdraEvent)]
+pub struct TaskLinked {
+    pub task_id: u64,
+    pub circle_id: u64,
+    pub secondary_circle_id: u64,
+    pub shared_completion: bool,
+    /// Notification routes registered for the circle
+    pub routes: Vec<[u8; 32]>,
+}
+
+/// Emitted when a designated successor opens a claim on an idle owner's circle
+#[derive(OdraEvent)]
+pub struct SuccessionClaimed {
+    pub circle_id: u64,
+    pub successor: Address,
+    pub challenge_ends_at: u64,
+    /// Notification routes registered for the circle
+    pub routes: Vec<[u8; 32]>,
+}
+
+/// Emitted when the owner suspends a member
+#[derive(OdraEvent)]
+pub struct MemberSuspended {
+    pub circle_id: u64,
+    pub member: Address,
+    pub suspended_by: Address,
+    /// Notification routes registered for the circle
+    pub routes: Vec<[u8; 32]>,
+}
+
+/// Emitted when the owner lifts a member's suspension
+#[derive(OdraEvent)]
+pub struct MemberReinstated {
+    pub circle_id: u64,
+    pub member: Address,
+    pub reinstated_by: Address,
+    /// Notification routes registered for the circle
+    pub routes: Vec<[u8; 32]>,
+}
+
+/// Emitted when a circle grants, syncs or revokes an agency's role
+#[derive(OdraEvent)]
+pub struct AgencyRoleChanged {
+    pub circle_id: u64,
+    pub agency: Address,
+    pub granted: bool,
+    pub changed_by: Address,
+    /// Caregivers added on grant, or suspended on revoke or deregistration
+    pub members_affected: u64,
+    /// Notification routes registered for the circle
+    pub routes: Vec<[u8; 32]>,
+}
+
+/// Emitted when a discharge plan's tasks are created
+#[derive(OdraEvent)]
+pub struct DischargePlanStarted {
+    pub circle_id: u64,
+    pub discharge_at: u64,
+    pub started_by: Address,
+    pub task_ids: Vec<u64>,
+    /// Notification routes registered for the circle
+    pub routes: Vec<[u8; 32]>,
+}
+
+/// Emitted when a care episode is created, edited or deleted
+#[derive(OdraEvent)]
+pub struct EpisodeUpdated {
+    pub circle_id: u64,
+    pub episode_id: u64,
+    pub name: String,
+    pub starts_at: u64,
+    pub ends_at: u64,
+    pub deleted: bool,
+    pub updated_by: Address,
+    /// Notification routes registered for the circle
+    pub routes: Vec<[u8; 32]>,
+}
+
+/// Emitted when a supply's stock drops to or below its low threshold
+#[derive(OdraEvent)]
+pub struct SupplyLow {
+    pub circle_id: u64,
+    pub supply_id: u64,
+    pub name: String,
+    pub quantity: u64,
+    pub low_threshold: u64,
+    /// Notification routes registered for the circle
+    pub routes: Vec<[u8; 32]>,
+}
+
+/// Emitted when a member logs a trip
+#[derive(OdraEvent)]
+pub struct TripLogged {
+    pub circle_id: u64,
+    pub task_id: u64,
+    pub driver: Address,
+    pub distance_m: u64,
+    /// Notification routes registered for the circle
+    pub routes: Vec<[u8; 32]>,
+}
+
+/// Emitted when a reimbursement request is filed, approved or rejected
+#[derive(OdraEvent)]
+pub struct ReimbursementUpdated {
+    pub circle_id: u64,
+    pub request_id: u64,
+    pub member: Address,
+    pub amount: u64,
+    pub status: ReimbursementStatus,
+    /// Notification routes registered for the circle
+    pub routes: Vec<[u8; 32]>,
+}
+
+/// Emitted when a caregiver posts a status update; the status itself is read by members through views
+#[derive(OdraEvent)]
+pub struct StatusUpdated {
+    pub circle_id: u64,
+    pub updated_by: Address,
+    /// Notification routes registered for the circle
+    pub routes: Vec<[u8; 32]>,
+}
+
+/// Emitted when a member reports an incident; the details are read through consent-gated views
+#[derive(OdraEvent)]
+pub struct IncidentReported {
+    pub circle_id: u64,
+    pub incident_id: u64,
+    pub kind_code: u32,
+    pub reported_by: Address,
+    /// Notification routes registered for the circle
+    pub routes: Vec<[u8; 32]>,
+}
+
+/// Emitted when a medication added to a circle matches one of the care recipient's recorded allergies
+#[derive(OdraEvent)]
+pub struct AllergyConflict {
+    pub circle_id: u64,
+    pub medication_id: u64,
+    pub substance_hash: [u8; 32],
+    /// Severity of the recorded allergy
+    pub severity: Severity,
+    pub added_by: Address,
+    /// Notification routes registered for the circle
+    pub routes: Vec<[u8; 32]>,
+}
+
+/// Emitted when an extension contract is registered with or removed from a circle
+#[derive(OdraEvent)]
+pub struct ExtensionUpdated {
+    pub circle_id: u64,
+    pub extension: Address,
+    pub registered: bool,
+    /// Index of the next queued hook the extension is delivered
+    pub next_hook: u64,
+    pub updated_by: Address,
+    /// Notification routes registered for the circle
+    pub routes: Vec<[u8; 32]>,
+}
+
+/// Emitted when a queued hook is skipped without being delivered to an extension
+#[derive(OdraEvent)]
+pub struct HookSkipped {
+    pub circle_id: u64,
+    pub extension: Address,
+    pub index: u64,
+    pub skipped_by: Address,
+    /// Notification routes registered for the circle
+    pub routes: Vec<[u8; 32]>,
+}
+
+/// Emitted with `TaskCreated` for a task whose title is only a commitment
+#[derive(OdraEvent)]
+pub struct TitleSealed {
+    pub task_id: u64,
+    pub circle_id: u64,
+    /// blake2b_256(title || salt)
+    pub commitment: [u8; 32],
+    /// Notification routes registered for the circle
+    pub routes: Vec<[u8; 32]>,
+}
+
+/// Emitted when a sealed title is revealed
+#[derive(OdraEvent)]
+pub struct TitleRevealed {
+    pub task_id: u64,
+    pub circle_id: u64,
+    pub title: String,
+    pub revealed_by: Address,
+    /// Notification routes registered for the circle
+    pub routes: Vec<[u8; 32]>,
+}
+
+/// Emitted when an encrypted envelope is stored, replaced or deleted
+#[derive(OdraEvent)]
+pub struct EnvelopeUpdated {
+    pub circle_id: u64,
+    pub envelope_id: u64,
+    pub key_epoch: u64,
+    pub deleted: bool,
+    pub updated_by: Address,
+    /// Notification routes registered for the circle
+    pub routes: Vec<[u8; 32]>,
+}
+
+/// Emitted when a circle moves to a new encryption key epoch
+#[derive(OdraEvent)]
+pub struct KeyEpochRotated {
+    pub circle_id: u64,
+    pub key_epoch: u64,
+    pub rotated_by: Address,
+    /// Notification routes registered for the circle
+    pub routes: Vec<[u8; 32]>,
+}
+
+/// Emitted when an auditor's read access to a scope is granted or revoked
+#[derive(OdraEvent)]
+pub struct AuditorAccessChanged {
+    pub circle_id: u64,
+    pub auditor: Address,
+    pub scope: ConsentScope,
+    /// Block time the access ends, 0 when revoked
+    pub expires_at: u64,
+    pub changed_by: Address,
+    /// Notification routes registered for the circle
+    pub routes: Vec<[u8; 32]>,
+}
+
+/// Emitted when the contract admin posts an announcement
+#[derive(OdraEvent)]
+pub struct AnnouncementPosted {
+    pub seq: u64,
+    pub severity: AnnouncementSeverity,
+    pub content_hash: [u8; 32],
+    pub uri: String,
+}
+
+/// Emitted when the contract admin switches a feature on or off
+#[derive(OdraEvent)]
+pub struct FeatureToggled {
+    pub feature: Feature,
+    pub enabled: bool,
+}
+
+/// Emitted once when a circle reaches a completion, member or anniversary milestone
+#[derive(OdraEvent)]
+pub struct CircleMilestone {
+    pub circle_id: u64,
+    pub kind: MilestoneKind,
+    /// Completions, members or years reached
+    pub value: u64,
+    /// Notification routes registered for the circle
+    pub routes: Vec<[u8; 32]>,
+}
+
+/// Emitted when a referred member's first verified completion credits their referrer
+#[derive(OdraEvent)]
+pub struct ReferralCredited {
+    pub circle_id: u64,
+    pub referrer: Address,
+    pub recruit: Address,
+    /// Referrer's referral points in the circle after this credit
+    pub points: u64,
+    /// Notification routes registered for the circle
+    pub routes: Vec<[u8; 32]>,
+}
+
+/// Emitted when a circle joins or leaves the anonymized research aggregates
+#[derive(OdraEvent)]
+pub struct ResearchOptInChanged {
+    pub circle_id: u64,
+    pub opted_in: bool,
+    pub changed_by: Address,
+    /// Notification routes registered for the circle
+    pub routes: Vec<[u8; 32]>,
+}
+
+/// Emitted when a member's personal data is erased from a circle
+#[derive(OdraEvent)]
+pub struct MemberDataErased {
+    pub circle_id: u64,
+    /// Stand-in shown for the member in views from now on
+    pub pseudonym: Address,
+    /// Notification routes registered for the circle
+    pub routes: Vec<[u8; 32]>,
+}
+
+/// Emitted when the retention keeper clears expired hashes
+#[derive(OdraEvent)]
+pub struct RecordsPurged {
+    pub circle_id: u64,
+    /// Entries logged before this block time are eligible
+    pub cutoff: u64,
+    pub purged: u64,
+    /// Notification routes registered for the circle
+    pub routes: Vec<[u8; 32]>,
+}
+
+/// Emitted when a circle changes owner
+#[derive(OdraEvent)]
+pub struct OwnershipTransferred {
+    pub circle_id: u64,
+    pub previous_owner: Address,
+    pub new_owner: Address,
+    /// Notification routes registered for the circle
+    pub routes: Vec<[u8; 32]>,
+}
+
+/// Emitted when a circle is merged into another and archived
+#[derive(OdraEvent)]
+pub struct CirclesMerged {
+    pub source_circle_id: u64,
+    pub target_circle_id: u64,
+    pub members_added: u64,
+    pub tasks_moved: u64,
+    /// Open tasks that stayed in the archived source: shared, or awaiting approval or co-signatures
+    pub tasks_left: Vec<u64>,
+    /// Notification routes of both circles
+    pub routes: Vec<[u8; 32]>,
+}
+
+// ==================== Constants ====================
+
+/// Block time is reported in milliseconds
+const MILLIS_PER_SECOND: u64 = 1_000;
+
+const MILLIS_PER_MINUTE: u64 = 60 * MILLIS_PER_SECOND;
+const MILLIS_PER_HOUR: u64 = 60 * MILLIS_PER_MINUTE;
+const MILLIS_PER_DAY: u64 = 24 * MILLIS_PER_HOUR;
+
+/// Length of the week bucket used for periodic reporting
+const MILLIS_PER_WEEK: u64 = 7 * MILLIS_PER_DAY;
+
+/// The Unix epoch fell on a Thursday; week buckets start on the Monday 3 days earlier
+const EPOCH_AFTER_MONDAY: u64 = 3 * MILLIS_PER_DAY;
+
+/// Largest UTC offset in use (UTC+14, Line Islands) in minutes
+const MAX_UTC_OFFSET_MINS: i32 = 14 * 60;
+
+/// Basis points in 100%
+const BPS_DENOMINATOR: u64 = 10_000;
+
+/// Version of MESSAGES.md, the catalog of error and event codes; bumped
+/// whenever a code is added or its meaning changes
+const MESSAGE_CATALOG_VERSION: u32 = 8;
+
+/// Code of the first event in MESSAGES.md; the others follow in list order
+const FIRST_EVENT_CODE: u32 = 1001;
+
+/// Every event name, in the order of the module's `events` list, which fixes
+/// each event's code
+const EVENT_NAMES: [&str; 55] = [
+    "CircleCreated", "MemberAdded", "TaskCreated", "TaskCompleted", "TaskDueSoon",
+    "PriorityEscalated", "SlaBreached", "VolunteerApplied", "VolunteerAccepted", "TaskLinked",
+    "CompletionSubmitted", "CompletionRejected", "SettingsUpdated", "ReceiptAppended",
+    "WeeklyDigest", "AssigneeInactive", "TaskMoved", "TaskDeferred", "CompletionCosigned",
+    "CirclesMerged", "SuccessionClaimed", "OwnershipTransferred", "MemberSuspended",
+    "MemberReinstated", "AgencyRoleChanged", "AssignmentAccepted", "AssignmentDeclined",
+    "HelpRequested", "HelperJoined", "HelpersCredited", "CheckedIn", "PresenceAttested",
+    "EvidenceAdded", "DischargePlanStarted", "EpisodeUpdated", "SupplyLow", "TripLogged",
+    "ReimbursementUpdated", "StatusUpdated", "ExtensionUpdated", "TitleSealed",
+    "TitleRevealed", "EnvelopeUpdated", "KeyEpochRotated", "AuditorAccessChanged",
+    "RecordsPurged", "MemberDataErased", "ResearchOptInChanged", "ReferralCredited",
+    "CircleMilestone", "AnnouncementPosted", "FeatureToggled", "HookSkipped",
+    "IncidentReported", "AllergyConflict",
+];
+
+/// Every heavy view, in the order `get_reader_usage` lists them
+const HEAVY_VIEWS: [HeavyView; 8] = [
+    HeavyView::Members,
+    HeavyView::Activity,
+    HeavyView::AuditLog,
+    HeavyView::OpenTasks,
+    HeavyView::SymptomLog,
+    HeavyView::MealLog,
+    HeavyView::Trips,
+    HeavyView::ResearchAggregates,
+];
+
+/// Every gated feature, in the order `get_feature_flags` lists them
+const FEATURES: [Feature; 5] = [
+    Feature::Reimbursements,
+    Feature::Extensions,
+    Feature::ProofVerifiers,
+    Feature::CircleMerges,
+    Feature::Succession,
+];
+
+/// Upper bound on open tasks examined by a single `emit_due_reminders` call
+const MAX_REMINDERS_PER_CALL: u64 = 50;
+
+/// Upper bound on open tasks examined by a single `escalate_priorities` call
+const MAX_ESCALATIONS_PER_CALL: u64 = 50;
+
+/// Upper bound on open tasks examined by a single `flag_inactive_assignees` call
+const MAX_INACTIVITY_FLAGS_PER_CALL: u64 = 50;
+
+/// Circle completion counts that emit `CircleMilestone`
+const COMPLETION_MILESTONES: [u64; 4] = [100, 500, 1_000, 5_000];
+
+/// Circle member counts that emit `CircleMilestone`
+const MEMBER_MILESTONES: [u64; 3] = [10, 25, 50];
+
+/// Length of a circle year for anniversaries, ignoring leap days
+const MILLIS_PER_YEAR: u64 = 365 * MILLIS_PER_DAY;
+
+/// Points a referrer earns when a recruit makes their first verified completion
+const REFERRAL_POINTS: u64 = 10;
+
+/// Upper bound on entries cleared by a single `purge_expired_records` call
+const MAX_PURGES_PER_CALL: u64 = 50;
+
+/// Logs `purge_expired_records` walks, in order
+const RETAINED_LOGS: [RetainedLog; 5] = [
+    RetainedLog::Symptoms,
+    RetainedLog::Meals,
+    RetainedLog::Evidence,
+    RetainedLog::Trips,
+    RetainedLog::Incidents,
+];
+
+/// Logs `purge_erased_notes` walks for an erased member's entries, in order
+const AUTHORED_LOGS: [RetainedLog; 6] = [
+    RetainedLog::Symptoms,
+    RetainedLog::Meals,
+    RetainedLog::Evidence,
+    RetainedLog::Trips,
+    RetainedLog::Incidents,
+    RetainedLog::HelpNotes,
+];
+
+/// Stand-in for a hash cleared by the retention policy or an erasure
+const PURGED_HASH: [u8; 32] = [0u8; 32];
+
+/// Longest task title accepted, in bytes
+const MAX_TITLE_LEN: usize = 128;
+
+/// Most onboarding tasks created for a member joining by code
+const MAX_ONBOARDING_TASKS: usize = 10;
+
+/// Most notification routes a circle can register
+const MAX_NOTIFICATION_ROUTES: usize = 8;
+
+/// Most caregivers an agency can ever register
+const MAX_AGENCY_CAREGIVERS: u64 = 50;
+
+/// Most co-signers a critical task can require
+const MAX_COSIGNERS: u32 = 10;
+
+/// Longest defer reason accepted, in bytes
+const MAX_DEFER_REASON_LEN: usize = 128;
+
+/// Longest assignment decline reason accepted, in bytes
+const MAX_DECLINE_REASON_LEN: usize = 128;
+
+/// Longest help request note accepted, in bytes
+const MAX_HELP_NOTE_LEN: usize = 128;
+
+/// Most helpers that can join one task
+const MAX_HELPERS: usize = 5;
+
+/// Most evidence items attached to one task
+const MAX_EVIDENCE_PER_TASK: u64 = 20;
+
+/// Most steps in a discharge plan template
+const MAX_DISCHARGE_STEPS: usize = 10;
+
+/// Highest symptom severity on the 0-10 scale
+const MAX_SYMPTOM_SEVERITY: u8 = 10;
+
+/// Most days one trend query returns
+const MAX_TREND_DAYS: u64 = 92;
+
+/// Opted-in circles needed before research aggregates are published
+const MIN_RESEARCH_CIRCLES: u64 = 5;
+
+/// Upper edges of the research latency histogram buckets, in seconds; a last
+/// bucket holds everything slower
+const RESEARCH_LATENCY_EDGES_SECS: [u64; 3] = [3_600, 86_400, 604_800];
+
+/// Most months one mileage report returns
+const MAX_REPORT_MONTHS: u64 = 24;
+
+/// Trips are logged in metres, `mileage_rate` is per kilometre
+const METRES_PER_KM: u64 = 1_000;
+
+/// StoredTask status bits
+const PRIORITY_MASK: u8 = 0b0000_0011;
+const COMPLETED_FLAG: u8 = 0b0000_0100;
+
+/// Bits per time in `StoredTask::millis`, enough for 0..=999
+const MILLIS_BITS: u32 = 10;
+const MILLIS_MASK: u32 = (1 << MILLIS_BITS) - 1;
+
+/// Highest task priority (0=low, 1=medium, 2=high, 3=urgent)
+const MAX_PRIORITY: u8 = 3;
+
+/// Upper bound on volunteer applications per task
+const MAX_APPLICANTS_PER_TASK: u64 = 20;
+
+/// Upper bounds on free-form task labels
+const MAX_LABELS_PER_TASK: usize = 5;
+const MAX_LABEL_LEN: usize = 32;
+
+/// Entries kept in each circle's rolling activity feed
+const ACTIVITY_LOG_SIZE: u64 = 100;
+
+/// Entries kept in each circle's rolling status board
+const STATUS_HISTORY_SIZE: u64 = 20;
+
+/// Most extension contracts registered with one circle
+const MAX_EXTENSIONS: usize = 5;
+
+/// Most hooks delivered to an extension by one `deliver_hooks` call
+const MAX_HOOKS_PER_CALL: u64 = 20;
+
+/// Largest completion proof forwarded to a circle's verifier, in bytes
+const MAX_PROOF_LEN: usize = 4096;
+
+/// Largest ciphertext one envelope holds, in bytes
+const MAX_CIPHERTEXT_LEN: usize = 1024;
+
+/// Upper bound on an announcement URI, in bytes
+const MAX_URI_LEN: usize = 256;
+
+/// Upper bound on announcements returned by one `get_announcements` call
+const MAX_ANNOUNCEMENTS_PER_PAGE: u64 = 20;
+
+/// Most envelopes a circle stores at once
+const MAX_ENVELOPES_PER_CIRCLE: u64 = 100;
+
+/// Longest auditor access grant, 90 days in milliseconds
+const MAX_AUDITOR_GRANT_MS: u64 = 90 * MILLIS_PER_DAY;
+
+/// Upper bounds on member profile fields, in bytes
+const MAX_DISPLAY_NAME_LEN: usize = 64;
+const MAX_RELATIONSHIP_LEN: usize = 32;
+
+/// Upper bound on addresses accepted by a single `add_members` call
+const MAX_MEMBERS_PER_BATCH: usize = 50;
+
+/// Upper bound on the source members, and separately on its open tasks,
+/// that `merge_circles` moves
+const MAX_MERGE_ITEMS: u64 = 50;
+
+/// Upper bound on availability windows a member can declare per circle
+const MAX_AVAILABILITY_WINDOWS: usize = 16;
+
+/// Survey score bounds and maximum questions per survey
+const MIN_SURVEY_SCORE: u8 = 1;
+const MAX_SURVEY_SCORE: u8 = 5;
+const MAX_SURVEY_SCORES: usize = 10;
+
+/// Upper bound on members one call examines when crediting a survey to caregivers
+const MAX_SURVEY_CREDITS_PER_CALL: u64 = 50;
+
+/// Survey averages are reported in hundredths of a point
+const SURVEY_AVERAGE_SCALE: u64 = 100;
+
+/// Fixed-point scale of the decaying score tallies behind `get_caregiver_reputation`
+const REPUTATION_WEIGHT_SCALE: u64 = 1_000_000;
+
+/// Upper bound on allergy and condition records per circle
+const MAX_HEALTH_RECORDS: u64 = 64;
+
+// ==================== Storage Modules ====================
+//
+// Odra keys every slot as blake2b(field path || key bytes), where the path
+// packs each field's 1-based position, 4 bits per nesting level. Each mapping
+// is therefore its own namespace and equal tuple keys in different mappings
+// never collide. Positions are the namespace, so fields are only ever
+// appended to a module, never reordered or removed; see "Storage Layout" in
+// the README.
+
+/// Circle membership and per-member state
+#[odra::module]
+pub struct MemberRegistry {
+    // Member storage: (circle_id, member_index) -> Address
+    // We use a count + index pattern for simplicity
+    circle_member_count: Mapping<u64, u64>,
+    circle_members: Mapping<(u64, u64), Address>,
+    
+    // Is member check: (circle_id, address) -> bool
+    is_member: Mapping<(u64, Address), bool>,
+    
+    // Open tasks currently assigned: (circle_id, address) -> count
+    open_tasks: Mapping<(u64, Address), u64>,
+    
+    // Declared availability: (circle_id, address) -> windows
+    availability: Mapping<(u64, Address), Vec<AvailabilityWindow>>,
+    
+    // Block time of the member's latest action: (circle_id, address) -> ms
+    last_active: Mapping<(u64, Address), u64>,
+    
+    // Display name and relationship: (circle_id, address) -> profile
+    profiles: Mapping<(u64, Address), MemberProfile>,
+    
+    // Legal guardians of the care recipient: (circle_id, address) -> bool
+    guardians: Mapping<(u64, Address), bool>,
+    
+    // Shareable invitations: (circle_id, H(code)) -> join code
+    join_codes: Mapping<(u64, [u8; 32]), JoinCode>,
+    
+    // Estimated minutes of open assigned tasks: (circle_id, address) -> minutes
+    open_minutes: Mapping<(u64, Address), u64>,
+    
+    // Tasks assigned to every member joining by code: circle_id -> templates
+    onboarding_tasks: Mapping<u64, Vec<TaskTemplate>>,
+    
+    // Members barred from mutating actions: (circle_id, address) -> bool
+    suspended: Mapping<(u64, Address), bool>,
+    
+    // Per-member override of max_open_per_member, 0 = unlimited: (circle_id, address) -> cap
+    open_task_caps: Mapping<(u64, Address), Option<u64>>,
+    
+    // Per-member override of task_creation: (circle_id, address) -> allowed
+    task_creator_overrides: Mapping<(u64, Address), Option<bool>>,
+}
+
+/// One-byte flags answering existence queries without reading full records
+#[odra::module]
+pub struct ExistenceFlags {
+    circle_exists: Mapping<u64, bool>,
+    circle_active: Mapping<u64, bool>,
+    task_exists: Mapping<u64, bool>,
+}
+
+/// Per-circle task index
+#[odra::module]
+pub struct TaskIndex {
+    // Circle task count, including tasks shared into the circle
+    circle_task_count: Mapping<u64, u64>,
+
+    
+    // Circle task index: (circle_id, task_index) -> task_id
+    circle_tasks: Mapping<(u64, u64), u64>,
+    
+    // Free-form labels: task_id -> labels
+    task_labels: Mapping<u64, Vec<String>>,
+    // Label index, append-only: (circle_id, label_hash, index) -> task_id
+    label_tasks: Mapping<(u64, [u8; 32], u64), u64>,
+    label_task_count: Mapping<(u64, [u8; 32]), u64>,
+    // Whether a task was ever indexed under a label in a circle:
+    // (circle_id, task_id, label_hash) -> bool
+    label_indexed: Mapping<(u64, u64, [u8; 32]), bool>,
+    
+    // Open tasks bucketed by priority (clamped to MAX_PRIORITY), swap-removed
+    // on completion: (circle_id, priority) -> count,
+    // (circle_id, priority, slot) -> task_id, task_id -> slot
+    priority_bucket_count: Mapping<(u64, u8), u64>,
+    priority_buckets: Mapping<(u64, u8, u64), u64>,
+    priority_slot: Mapping<u64, u64>,
+    
+    // Tasks created in a circle: circle_id -> last seq, (circle_id, seq) -> task_id
+    circle_task_seq: Mapping<u64, u64>,
+    task_by_seq: Mapping<(u64, u64), u64>,
+    
+    // Position in circle_tasks for swap-removal: (circle_id, task_id) -> index
+    circle_task_slot: Mapping<(u64, u64), u64>,
+}
+
+/// Global and per-circle counters
+#[odra::module]
+pub struct Stats {
+    total_circles: Var<u64>,
+    total_tasks: Var<u64>,
+    total_completions: Var<u64>,
+    
+    // Completions per circle, shared tasks count in both circles
+    circle_completions: Mapping<u64, u64>,
+    
+    weekly: SubModule<WeeklyReports>,
+    
+    active_circles: Var<u64>,
+    
+    // Platform completions per UTC week bucket: week -> completions
+    utc_week_completions: Mapping<u64, u64>,
+    
+    // Addresses that completed a task, and how many there are
+    is_caregiver: Mapping<Address, bool>,
+    unique_caregivers: Var<u64>,
+    
+    research: SubModule<ResearchPool>,
+}
+
+/// Completions of research opt-in circles, pooled without circle or member keys
+#[odra::module]
+pub struct ResearchPool {
+    // circle_id -> opted in, and how many circles are
+    opted_in: Mapping<u64, bool>,
+    opted_in_circles: Var<u64>,
+    
+    // priority -> completions and summed latency in seconds
+    completions: Mapping<u8, u64>,
+    latency_secs: Mapping<u8, u64>,
+    
+    // Latency histogram: bucket -> completions
+    latency_buckets: Mapping<u8, u64>,
+}
+
+/// Per-circle counters bucketed by week (and month) of the circle's local time,
+/// and by care episode
+#[odra::module]
+pub struct WeeklyReports {
+    // (circle_id, week) -> completions
+    completions: Mapping<(u64, u64), u64>,
+    
+    // Tasks with a due date in the week, and those completed by it
+    due: Mapping<(u64, u64), u64>,
+    due_met: Mapping<(u64, u64), u64>,
+    
+    // (circle_id, week, member) -> completions, (circle_id, week) -> leader
+    member_completions: Mapping<(u64, u64, Address), u64>,
+    top_contributor: Mapping<(u64, u64), (Address, u64)>,
+    
+    // circle_id -> next week to finalize, (circle_id, week) -> snapshot
+    next_week: Mapping<u64, u64>,
+    snapshots: Mapping<(u64, u64), WeeklySnapshot>,
+    
+    // (circle_id, month, member) -> completions
+    member_month_completions: Mapping<(u64, u64, Address), u64>,
+    
+    // Week a task's due date is counted under in `due`: task_id -> week
+    due_week: Mapping<u64, u64>,
+    
+    // Completions under the task's episode at the time: (circle_id, episode_id, member) -> completions
+    member_episode_completions: Mapping<(u64, u64, Address), u64>,
+}
+
+/// Auto-assignment, reminder and priority aging state
+#[odra::module]
+pub struct Scheduling {
+    // Auto-assignment: circle_id -> next round-robin index
+    auto_assign_cursor: Mapping<u64, u64>,
+    
+    // Due date a reminder was already emitted for: task_id -> due_at
+    reminded_due_at: Mapping<u64, u64>,
+    
+    // Next open task a keeper examines: (circle_id, keeper) -> (priority, slot)
+    keeper_cursors: Mapping<(u64, OpenTaskKeeper), (u8, u64)>,
+    
+    // Assignee activity time an inactivity flag was raised for: task_id -> ms
+    inactivity_flagged_at: Mapping<u64, u64>,
+    
+    // Priority aging, last escalation time: task_id -> timestamp
+    last_escalated_at: Mapping<u64, u64>,
+    
+    sla: SubModule<SlaTracker>,
+}
+
+/// SLA targets and compliance
+#[odra::module]
+pub struct SlaTracker {
+    // circle_id -> completion target (secs) indexed by priority, 0 = none
+    targets: Mapping<u64, Vec<u64>>,
+    // Completion latency: task_id -> secs from creation to completion
+    task_latency_secs: Mapping<u64, u64>,
+    // SLA compliance: (circle_id, week) -> stats
+    period_stats: Mapping<(u64, u64), SlaPeriodStats>,
+    
+    // Due date results: task_id -> (completed late, secs past the due date)
+    lateness: Mapping<u64, (bool, u64)>,
+    // (circle_id, completer) -> (on time, completions with a due date)
+    member_on_time: Mapping<(u64, Address), (u64, u64)>,
+}
+
+/// Task lifecycle state beyond the Task record
+#[odra::module]
+pub struct TaskWorkflow {
+    // Completions awaiting verification: task_id -> pending, None once resolved
+    pending_completions: Mapping<u64, Option<PendingCompletion>>,
+    
+    // Completion delegates: task_id -> address allowed to complete on the
+    // assignee's behalf, None once revoked
+    delegates: Mapping<u64, Option<Address>>,
+    
+    // Due date postponements: task_id -> count, (task_id, n) -> entry
+    defer_count: Mapping<u64, u32>,
+    defers: Mapping<(u64, u32), DeferEntry>,
+    
+    // Co-signed completions: task_id -> required signatures, 0 = none
+    required_cosigners: Mapping<u64, u32>,
+    
+    // Completion collecting signatures: task_id -> request, None once complete
+    cosign_requests: Mapping<u64, Option<CosignRequest>>,
+    
+    // Last co-sign round started per task and who signed in it
+    cosign_rounds: Mapping<u64, u32>,
+    cosigned: Mapping<(u64, u32, Address), bool>,
+    
+    // Completions approved by the owner or co-signed: task_id -> verified
+    verified: Mapping<u64, bool>,
+    
+    // Assignments the assignee has yet to accept: task_id -> awaiting
+    awaiting_acceptance: Mapping<u64, bool>,
+    
+    // Backup requests: task_id -> assignee's note, task_id -> members helping
+    help_requests: Mapping<u64, Option<String>>,
+    helpers: Mapping<u64, Vec<Address>>,
+    
+    presence: SubModule<PresenceProofs>,
+    
+    // Evidence files: task_id -> count, (task_id, index) -> item
+    evidence_count: Mapping<u64, u64>,
+    evidence: Mapping<(u64, u64), EvidenceItem>,
+}
+
+/// Task location commitments, check-ins and oracle attestations
+#[odra::module]
+pub struct PresenceProofs {
+    // task_id -> blake2b_256(salt || geohash), None once cleared
+    locations: Mapping<u64, Option<[u8; 32]>>,
+    
+    // Latest check-in per task: task_id -> check-in
+    check_ins: Mapping<u64, CheckIn>,
+    
+    // Addresses allowed to attest proximity: (circle_id, oracle) -> authorized
+    oracles: Mapping<(u64, Address), bool>,
+}
+
+/// Care recipients and the quality surveys they submit
+#[odra::module]
+pub struct CareFeedback {
+    // Person receiving care: circle_id -> address
+    recipients: Mapping<u64, Address>,
+    
+    // One survey per period: (circle_id, kind, week, month or episode id) -> survey
+    surveys: Mapping<(u64, SurveyPeriod, u64), Survey>,
+    
+    // Running (sum, count) of individual scores per circle and per caregiver
+    circle_scores: Mapping<u64, (u64, u64)>,
+    caregiver_scores: Mapping<Address, (u64, u64)>,
+    
+    // Allergies and conditions: (circle_id, substance_hash) -> record
+    health_records: Mapping<(u64, [u8; 32]), Option<HealthRecord>>,
+    
+    // Enumerable list of recorded substances with swap-remove slots
+    health_record_count: Mapping<u64, u64>,
+    health_record_list: Mapping<(u64, u64), [u8; 32]>,
+    health_record_slot: Mapping<(u64, [u8; 32]), u64>,
+    
+    // Read consent per scope for one address, and for every member of the circle
+    address_consents: Mapping<(u64, ConsentScope, Address), bool>,
+    member_consents: Mapping<(u64, ConsentScope), bool>,
+    
+    journal: SubModule<CareJournal>,
+    
+    // Time-limited auditor reads: (circle_id, scope, auditor) -> expiry, 0 = none
+    auditor_grants: Mapping<(u64, ConsentScope, Address), u64>,
+    
+    // Next member index to credit a survey's scores to: (circle_id, kind, period) -> index
+    survey_credit_cursors: Mapping<(u64, SurveyPeriod, u64), u64>,
+    
+    // Decaying (sum, count) tally, scaled, and when it was last decayed: caregiver -> tally
+    weighted_scores: Mapping<Address, (u64, u64, u64)>,
+}
+
+/// Day-to-day observations of the care recipient
+#[odra::module]
+pub struct CareJournal {
+    // Append-only symptom log: circle_id -> count, (circle_id, index) -> entry
+    symptom_count: Mapping<u64, u64>,
+    symptoms: Mapping<(u64, u64), SymptomEntry>,
+    
+    // Trend buckets: (circle_id, symptom_hash, local day) -> (entries, severity sum)
+    symptom_days: Mapping<(u64, [u8; 32], u64), (u64, u64)>,
+    
+    // Append-only meal log: circle_id -> count, (circle_id, index) -> entry
+    meal_count: Mapping<u64, u64>,
+    meals: Mapping<(u64, u64), MealEntry>,
+    
+    // Daily totals: (circle_id, local day) -> (meals, consumed percent sum)
+    meal_days: Mapping<(u64, u64), (u64, u64)>,
+    
+    // Status board: circle_id -> updates posted, (circle_id, seq % STATUS_HISTORY_SIZE) -> update
+    status_count: Mapping<u64, u64>,
+    statuses: Mapping<(u64, u64), StatusUpdate>,
+    
+    // Append-only incident log: circle_id -> count, (circle_id, index) -> report
+    incident_count: Mapping<u64, u64>,
+    incidents: Mapping<(u64, u64), IncidentReport>,
+    
+    // Medication list: circle_id -> count, (circle_id, index) -> medication
+    medication_count: Mapping<u64, u64>,
+    medications: Mapping<(u64, u64), Medication>,
+}
+
+/// Per-circle configuration kept outside `CircleSettings`
+#[odra::module]
+pub struct CircleConfig {
+    // Opaque topic hashes copied into every circle event: circle_id -> routes
+    notification_routes: Mapping<u64, Vec<[u8; 32]>>,
+    
+    // Merge approvals: (source_id, target_id) -> owners who approved (source, target)
+    merge_approvers: Mapping<(u64, u64), (Option<Address>, Option<Address>)>,
+    
+    // Owner succession: circle_id -> plan, None once cleared or executed
+    succession: Mapping<u64, Option<SuccessionPlan>>,
+    
+    // Hospital discharge: circle_id -> custom steps (None = defaults), latest plan
+    discharge_steps: Mapping<u64, Option<Vec<DischargeStep>>>,
+    discharge_plans: Mapping<u64, DischargePlan>,
+    
+    // Care episodes: circle_id -> count, (circle_id, episode_id) -> episode (None once deleted) and stats
+    episode_count: Mapping<u64, u64>,
+    episodes: Mapping<(u64, u64), Option<CareEpisode>>,
+    episode_stats: Mapping<(u64, u64), EpisodeStats>,
+    
+    logistics: SubModule<CareLogistics>,
+    extensions: SubModule<ExtensionHooks>,
+    
+    // External proof verifier consulted on completion: circle_id -> contract
+    verifiers: Mapping<u64, Option<Address>>,
+    
+    private: SubModule<PrivateData>,
+    retention: SubModule<RetentionState>,
+    referrals: SubModule<ReferralLedger>,
+}
+
+/// Who recruited whom, and the points referrers earned
+#[odra::module]
+pub struct ReferralLedger {
+    // (circle_id, code_hash) -> member credited for joins with the code
+    code_referrers: Mapping<(u64, [u8; 32]), Option<Address>>,
+    
+    // (circle_id, recruit) -> referral
+    recruits: Mapping<(u64, Address), Referral>,
+    
+    // Recruits of a referrer: (circle_id, referrer) -> count, (circle_id, referrer, index) -> recruit
+    recruit_count: Mapping<(u64, Address), u64>,
+    recruit_list: Mapping<(u64, Address, u64), Address>,
+    
+    // (circle_id, referrer) -> referral points
+    points: Mapping<(u64, Address), u64>,
+}
+
+/// Progress of the retention keeper through each circle's logs
+#[odra::module]
+pub struct RetentionState {
+    // Evidence in the order it was added: circle_id -> count, (circle_id, seq) -> (task_id, index)
+    evidence_log_count: Mapping<u64, u64>,
+    evidence_log: Mapping<(u64, u64), (u64, u64)>,
+    
+    // (circle_id, log) -> index of the next entry to purge
+    cursors: Mapping<(u64, RetainedLog), u64>,
+}
+
+/// Commitments and ciphertext standing in for data that should not be public
+#[odra::module]
+pub struct PrivateData {
+    // Sealed titles: task_id -> blake2b_256(title || salt), None once revealed
+    title_commitments: Mapping<u64, Option<[u8; 32]>>,
+    
+    // circle_id -> current key epoch, bumped on rotation
+    key_epochs: Mapping<u64, u64>,
+    
+    // Envelopes: circle_id -> ids issued and live, (circle_id, envelope_id) -> envelope (None once deleted)
+    envelope_count: Mapping<u64, u64>,
+    live_envelopes: Mapping<u64, u64>,
+    envelopes: Mapping<(u64, u64), Option<EncryptedEnvelope>>,
+    
+    // Erased members: circle_id -> count, (circle_id, member) -> pseudonym shown in views
+    erasure_count: Mapping<u64, u64>,
+    pseudonyms: Mapping<(u64, Address), Address>,
+    // Erased members' note purge progress: (circle_id, member, log) -> next index
+    erasure_cursors: Mapping<(u64, Address, RetainedLog), u64>,
+    
+    // Help requests in the order they were made: circle_id -> count, (circle_id, seq) -> (task_id, requested_at)
+    help_log_count: Mapping<u64, u64>,
+    help_log: Mapping<(u64, u64), (u64, u64)>,
+    // Member whose note a task's help request holds: task_id -> requester
+    help_requested_by: Mapping<u64, Address>,
+}
+
+/// Extension contracts and the hook queue they are notified from
+#[odra::module]
+pub struct ExtensionHooks {
+    // circle_id -> registered extension contracts
+    registered: Mapping<u64, Vec<Address>>,
+    
+    // Hook queue: circle_id -> count, (circle_id, index) -> call
+    hook_count: Mapping<u64, u64>,
+    hooks: Mapping<(u64, u64), HookCall>,
+    
+    // (circle_id, extension) -> index of the next hook to deliver
+    cursors: Mapping<(u64, Address), u64>,
+}
+
+/// Supplies, trips and mileage reimbursement kept by a circle
+#[odra::module]
+pub struct CareLogistics {
+    // Inventory: circle_id -> count, (circle_id, supply_id) -> supply
+    supply_count: Mapping<u64, u64>,
+    supplies: Mapping<(u64, u64), Supply>,
+    
+    // Restock tasks: task_id -> (supply_id, quantity added on completion)
+    restocks: Mapping<u64, Option<(u64, u64)>>,
+    
+    // Transport log: circle_id -> count, (circle_id, index) -> trip
+    trip_count: Mapping<u64, u64>,
+    trips: Mapping<(u64, u64), Trip>,
+    // (circle_id, driver, local month) -> (metres driven, metres claimed)
+    mileage: Mapping<(u64, Address, u64), (u64, u64)>,
+    
+    // Reimbursement: circle_id -> count, (circle_id, request_id) -> request
+    reimbursement_count: Mapping<u64, u64>,
+    reimbursements: Mapping<(u64, u64), ReimbursementRequest>,
+}
+
+/// Community volunteer registry and task board
+#[odra::module]
+pub struct VolunteerBoard {
+    // Volunteer registry: address -> registered
+    volunteers: Mapping<Address, bool>,
+    
+    // Tasks flagged for volunteers: task_id -> open
+    open_tasks: Mapping<u64, bool>,
+    // Public board, append-only: board_index -> task_id
+    board: Mapping<u64, u64>,
+    board_count: Var<u64>,
+    
+    // Applicants: (task_id, index) -> address, task_id -> count
+    applicants: Mapping<(u64, u64), Address>,
+    applicant_count: Mapping<u64, u64>,
+    has_applied: Mapping<(u64, Address), bool>,
+    
+    // Tasks ever appended to the board: task_id -> listed
+    listed: Mapping<u64, bool>,
+}
+
+/// Per-circle history kept on-chain for clients without an event indexer
+#[odra::module]
+pub struct CircleHistory {
+    // Rolling activity feed: circle_id -> entries ever appended,
+    // (circle_id, seq % ACTIVITY_LOG_SIZE) -> entry
+    activity_count: Mapping<u64, u64>,
+    activity: Mapping<(u64, u64), ActivityEntry>,
+    
+    // Append-only audit log of administrative actions: circle_id -> count,
+    // (circle_id, seq) -> entry
+    audit_count: Mapping<u64, u64>,
+    audit: Mapping<(u64, u64), AuditEntry>,
+    
+    // Completion receipt chains: caregiver -> current head, caregiver -> length
+    receipt_heads: Mapping<Address, [u8; 32]>,
+    receipt_counts: Mapping<Address, u64>,
+    
+    // Stored receipts for work history: (caregiver, index) -> receipt
+    receipts: Mapping<(Address, u64), CompletionReceipt>,
+    
+    // Circles a caregiver publishes history from: (caregiver, circle_id) -> opted in
+    public_history: Mapping<(Address, u64), bool>,
+    
+    // circle_id -> anniversaries already announced, in years
+    anniversaries: Mapping<u64, u64>,
+}
+
+/// Features that reach beyond a single circle's membership
+#[odra::module]
+pub struct Community {
+    volunteers: SubModule<VolunteerBoard>,
+    sharing: SubModule<TaskSharing>,
+    
+    // Contract admin, set at init; verifies agencies
+    admin: Var<Address>,
+    agencies: SubModule<AgencyRegistry>,
+    
+    // Admin announcements: count, seq -> announcement
+    announcement_count: Var<u64>,
+    announcements: Mapping<u64, Announcement>,
+    
+    // Features switched off by the admin; everything is on by default
+    disabled_features: Mapping<Feature, bool>,
+    
+    readers: SubModule<ReaderRegistry>,
+    
+    // Half-life of survey scores in caregiver reputation, 0 = no decay
+    reputation_half_life_secs: Var<u64>,
+}
+
+/// Indexer consumers and the heavy view calls reported against them
+#[odra::module]
+pub struct ReaderRegistry {
+    // reader count, reader_id -> reader
+    reader_count: Var<u64>,
+    readers: Mapping<u64, Reader>,
+    
+    // (reader_id, UTC day, view) -> calls reported
+    usage: Mapping<(u64, u64, HeavyView), u64>,
+}
+
+/// Admin-verified agencies and the caregiver addresses they operate
+#[odra::module]
+pub struct AgencyRegistry {
+    // agency -> verified by the contract admin
+    verified: Mapping<Address, bool>,
+    
+    // Caregivers an agency ever registered: agency -> count, (agency, index) -> caregiver
+    caregiver_count: Mapping<Address, u64>,
+    caregivers: Mapping<(Address, u64), Address>,
+    listed: Mapping<(Address, Address), bool>,
+    
+    // caregiver -> agency currently operating it, None once deregistered
+    agency_of: Mapping<Address, Option<Address>>,
+    
+    // Circles holding the agency role: (circle_id, agency) -> granted
+    granted: Mapping<(u64, Address), bool>,
+    
+    // Members brought in by an agency: (circle_id, member) -> agency
+    member_agency: Mapping<(u64, Address), Option<Address>>,
+}
+
+/// Tasks shared between two circles
+#[odra::module]
+pub struct TaskSharing {
+    // task_id -> (secondary circle, shared completion) awaiting acceptance, circle 0 = none
+    pending_links: Mapping<u64, (u64, bool)>,
+    // task_id -> accepted secondary circle
+    secondary_circle: Mapping<u64, u64>,
+    // Whether secondary circle members may complete: task_id -> allowed
+    shared_completion: Mapping<u64, bool>,
+}
+
+// ==================== Contract Module ====================
+
+#[odra::module(events = [
+    CircleCreated, MemberAdded, TaskCreated, TaskCompleted, TaskDueSoon, PriorityEscalated,
+    SlaBreached, VolunteerApplied, VolunteerAccepted, TaskLinked, CompletionSubmitted,
+    CompletionRejected, SettingsUpdated, ReceiptAppended, WeeklyDigest, AssigneeInactive,
+    TaskMoved, TaskDeferred, CompletionCosigned, CirclesMerged, SuccessionClaimed,
+    OwnershipTransferred, MemberSuspended, MemberReinstated, AgencyRoleChanged,
+    AssignmentAccepted, AssignmentDeclined, HelpRequested, HelperJoined, HelpersCredited,
+    CheckedIn, PresenceAttested, EvidenceAdded, DischargePlanStarted, EpisodeUpdated,
+    SupplyLow, TripLogged, ReimbursementUpdated, StatusUpdated,
+    ExtensionUpdated, TitleSealed, TitleRevealed, EnvelopeUpdated, KeyEpochRotated,
+    AuditorAccessChanged, RecordsPurged, MemberDataErased, ResearchOptInChanged,
+    ReferralCredited, CircleMilestone, AnnouncementPosted, FeatureToggled, HookSkipped,
+    IncidentReported, AllergyConflict,
+], errors = Error)]
+pub struct CareCircle {
+    // Counters
+    next_circle_id: Var<u64>,
+    next_task_id: Var<u64>,
+    
+    // Circle storage
+    circles: Mapping<u64, StoredCircle>,
+    settings: Mapping<u64, CircleSettings>,
+    
+    // Member storage
+    members: SubModule<MemberRegistry>,
+    
+    // Task storage, compact; use load_task/store_task
+    tasks: Mapping<u64, StoredTask>,
+    task_index: SubModule<TaskIndex>,
+    
+    // Stats
+    stats: SubModule<Stats>,
+    
+    workflow: SubModule<TaskWorkflow>,
+    scheduling: SubModule<Scheduling>,
+    community: SubModule<Community>,
+    history: SubModule<CircleHistory>,
+    feedback: SubModule<CareFeedback>,
+    flags: SubModule<ExistenceFlags>,
+    
+    // Last root field (Odra allows 15); further circle state goes into config
+    config: SubModule<CircleConfig>,
+}
+
+#[odra::module]
+impl CareCircle {
+    /// Initialize the contract
+    #[odra(init)]
+    pub fn init(&mut self) {
+        self.next_circle_id.set(1);
+        self.next_task_id.set(1);
+        self.stats.total_circles.set(0);
+        self.stats.total_tasks.set(0);
+        self.stats.total_completions.set(0);
+        self.stats.active_circles.set(0);
+        self.stats.unique_caregivers.set(0);
+        let admin = self.env().caller();
+        self.community.admin.set(admin);
+    }
+
+    // ==================== Circle Management ====================
+
+    /// Create a new care circle
+    /// The caller becomes the owner and first member
+    pub fn create_circle(&mut self, name: String) -> u64 {
+        let env = self.env();
+        let owner = env.caller();
+        let timestamp = env.get_block_time();
+        
+        let id = self.next_circle_id.get_or_default();
+        self.next_circle_id.set(id + 1);
+
+        let circle = StoredCircle {
+            id,
+            name: name.clone(),
+            owner,
+            created_at: timestamp,
+        };
+
+        // Store circle with default settings
+        self.circles.set(&id, circle);
+        self.settings.set(&id, CircleSettings::default());
+        self.flags.circle_exists.set(&id, true);
+        self.flags.circle_active.set(&id, true);
+        
+        // Add owner as first member
+        self.members.circle_member_count.set(&id, 1);
+        self.members.circle_members.set(&(id, 0), owner);
+        self.members.is_member.set(&(id, owner), true);
+        
+        // Update stats
+        self.stats.total_circles.add(1);
+        self.stats.active_circles.add(1);
+        self.log_activity(id, owner, ActivityKind::CircleCreated, id);
+
+        // Emit event
+        self.env().emit_event(CircleCreated {
+            circle_id: id,
+            name,
+            owner,
+        });
+
+        id
+    }
+
+    /// Start a new circle from an existing one's settings (source owner only)
+    ///
+    /// Optionally copies the onboarding task templates and the member roster;
+    /// tasks, roles and history stay with the source.
+    pub fn clone_circle(
+        &mut self,
+        source_id: u64,
+        new_name: String,
+        include_members: bool,
+        include_templates: bool,
+    ) -> u64 {
+        let env = self.env();
+        let caller = env.caller();
+        let source = self.require_circle(source_id);
+        
+        if caller != source.owner {
+            env.revert(Error::NotOwner);
+        }
+
+        let id = self.create_circle(new_name);
+        let settings = self.get_settings(source_id);
+        self.settings.set(&id, settings);
+        if include_templates {
+            let templates = self.get_onboarding_tasks(source_id);
+            self.members.onboarding_tasks.set(&id, templates);
+        }
+        if include_members {
+            let member_count = self.members.circle_member_count.get(&source_id).unwrap_or(0);
+            for i in 0..member_count {
+                let Some(member) = self.members.circle_members.get(&(source_id, i)) else {
+                    continue;
+                };
+                if member != caller {
+                    self.insert_member(id, member, caller);
+                }
+            }
+        }
+        self.log_audit(id, caller, AuditAction::CircleCloned, None, source_id);
+        id
+    }
+
+    /// Add a member to a circle (only owner can add)
+    pub fn add_member(&mut self, circle_id: u64, member_addr: Address) {
+        let env = self.env();
+        let caller = env.caller();
+
+        // Get circle and verify caller is owner
+        let circle = self.require_circle(circle_id);
+        
+        if caller != circle.owner {
+            env.revert(Error::NotOwner);
+        }
+        
+        self.insert_member(circle_id, member_addr, caller);
+        self.log_audit(circle_id, caller, AuditAction::MemberAdded, Some(member_addr), circle_id);
+    }
+
+    /// Add several members at once (owner only).
+    /// Returns per address whether it was added; existing members are skipped.
+    pub fn add_members(&mut self, circle_id: u64, member_addrs: Vec<Address>) -> Vec<bool> {
+        let env = self.env();
+        let caller = env.caller();
+        let circle = self.require_circle(circle_id);
+        
+        if caller != circle.owner {
+            env.revert(Error::NotOwner);
+        }
+        
+        if member_addrs.len() > MAX_MEMBERS_PER_BATCH {
+            env.revert(Error::TooManyMembers);
+        }
+
+        member_addrs
+            .into_iter()
+            .map(|member_addr| {
+                if self.members.is_member.get(&(circle_id, member_addr)).unwrap_or(false) {
+                    return false;
+                }
+                self.insert_member(circle_id, member_addr, caller);
+                self.log_audit(circle_id, caller, AuditAction::MemberAdded, Some(member_addr), circle_id);
+                true
+            })
+            .collect()
+    }
+
+    /// Flag a member as a legal guardian of the care recipient (owner only)
+    ///
+    /// The flag can only be dropped by the guardian themselves.
+    pub fn appoint_guardian(&mut self, circle_id: u64, member: Address) {
+        let env = self.env();
+        let caller = env.caller();
+        let circle = self.require_circle(circle_id);
+        
+        if caller != circle.owner {
+            env.revert(Error::NotOwner);
+        }
+        self.assert_circle_active(circle_id);
+        
+        if !self.members.is_member.get(&(circle_id, member)).unwrap_or(false) {
+            env.revert(Error::NotAMember);
+        }
+
+        self.members.guardians.set(&(circle_id, member), true);
+        self.log_audit(circle_id, caller, AuditAction::GuardianAppointed, Some(member), circle_id);
+    }
+
+    /// Give up the caller's guardian flag in a circle
+    pub fn renounce_guardian(&mut self, circle_id: u64) {
+        let env = self.env();
+        let caller = env.caller();
+        
+        if !self.members.guardians.get(&(circle_id, caller)).unwrap_or(false) {
+            env.revert(Error::NotAGuardian);
+        }
+
+        self.members.guardians.set(&(circle_id, caller), false);
+        self.log_audit(circle_id, caller, AuditAction::GuardianRenounced, Some(caller), circle_id);
+    }
+
+    /// Register the hash of a shareable join code (owner only)
+    ///
+    /// `code_hash` is the blake2b-256 hash of the code's UTF-8 bytes;
+    /// `expires_at` is a block time in milliseconds, 0 for no expiry.
+    pub fn create_join_code(&mut self, circle_id: u64, code_hash: [u8; 32], max_uses: u64, expires_at: u64) {
+        let env = self.env();
+        let caller = env.caller();
+        let circle = self.require_circle(circle_id);
+        
+        if caller != circle.owner {
+            env.revert(Error::NotOwner);
+        }
+        self.assert_circle_active(circle_id);
+        
+        if max_uses == 0 {
+            env.revert(Error::InvalidJoinCode);
+        }
+        
+        if self.members.join_codes.get(&(circle_id, code_hash)).is_some() {
+            env.revert(Error::JoinCodeAlreadyExists);
+        }
+
+        self.members.join_codes.set(&(circle_id, code_hash), JoinCode {
+            max_uses,
+            uses: 0,
+            expires_at,
+            revoked: false,
+        });
+        self.log_audit(circle_id, caller, AuditAction::JoinCodeCreated, None, circle_id);
+    }
+
+    /// Credit a member for everyone who joins with a join code, or stop
+    /// crediting anyone with `None` (owner only)
+    pub fn set_join_code_referrer(&mut self, circle_id: u64, code_hash: [u8; 32], referrer: Option<Address>) {
+        let env = self.env();
+        let caller = env.caller();
+        let circle = self.require_circle(circle_id);
+        
+        if caller != circle.owner {
+            env.revert(Error::NotOwner);
+        }
+        self.assert_circle_active(circle_id);
+        
+        if self.members.join_codes.get(&(circle_id, code_hash)).is_none() {
+            env.revert(Error::InvalidJoinCode);
+        }
+        
+        if let Some(referrer) = referrer {
+            if !self.members.is_member.get(&(circle_id, referrer)).unwrap_or(false) {
+                env.revert(Error::NotAMember);
+            }
+        }
+
+        self.config.referrals.code_referrers.set(&(circle_id, code_hash), referrer);
+        self.log_audit(circle_id, caller, AuditAction::JoinCodeReferrerSet, referrer, circle_id);
+    }
+
+    /// Stop a join code from admitting further members (owner only)
+    pub fn revoke_join_code(&mut self, circle_id: u64, code_hash: [u8; 32]) {
+        let env = self.env();
+        let caller = env.caller();
+        let circle = self.require_circle(circle_id);
+        
+        if caller != circle.owner {
+            env.revert(Error::NotOwner);
+        }
+        self.assert_circle_active(circle_id);
+        
+        let Some(mut join_code) = self.members.join_codes.get(&(circle_id, code_hash)) else {
+            env.revert(Error::InvalidJoinCode);
+        };
+
+        join_code.revoked = true;
+        self.members.join_codes.set(&(circle_id, code_hash), join_code);
+        self.log_audit(circle_id, caller, AuditAction::JoinCodeRevoked, None, circle_id);
+    }
+
+    /// Join a circle by presenting a join code's preimage
+    pub fn join_with_code(&mut self, circle_id: u64, code: String) {
+        let env = self.env();
+        let caller = env.caller();
+
+        let code_hash = env.hash(code.as_bytes());
+        let mut join_code = match self.members.join_codes.get(&(circle_id, code_hash)) {
+            Some(join_code) if !join_code.revoked => join_code,
+            _ => env.revert(Error::InvalidJoinCode),
+        };
+        
+        if join_code.expires_at != 0 && env.get_block_time() > join_code.expires_at {
+            env.revert(Error::JoinCodeExpired);
+        }
+        
+        if join_code.uses >= join_code.max_uses {
+            env.revert(Error::JoinCodeExhausted);
+        }
+
+        join_code.uses += 1;
+        self.members.join_codes.set(&(circle_id, code_hash), join_code);
+        self.insert_member(circle_id, caller, caller);
+
+        if let Some(referrer) = self.config.referrals.code_referrers.get(&(circle_id, code_hash)).flatten() {
+            let referrals = &mut self.config.referrals;
+            referrals.recruits.set(&(circle_id, caller), Referral {
+                referrer,
+                recruit: caller,
+                joined_at: env.get_block_time(),
+                credited_at: None,
+            });
+            let index = referrals.recruit_count.get(&(circle_id, referrer)).unwrap_or(0);
+            referrals.recruit_list.set(&(circle_id, referrer, index), caller);
+            referrals.recruit_count.set(&(circle_id, referrer), index + 1);
+        }
+
+        // Onboarding tasks are the owner's, checked as if the owner created them
+        let owner = self.require_circle(circle_id).owner;
+        for template in self.members.onboarding_tasks.get(&circle_id).unwrap_or_default() {
+            if let Err(error) = self.check_task_creation(owner, circle_id, &template.title, caller, template.priority) {
+                env.revert(error);
+            }
+            self.insert_task(circle_id, template.title, caller, owner, template.priority);
+        }
+    }
+
+    /// Replace the tasks created for every member who joins by code (owner only)
+    pub fn set_onboarding_tasks(&mut self, circle_id: u64, templates: Vec<TaskTemplate>) {
+        let env = self.env();
+        let caller = env.caller();
+        let circle = self.require_circle(circle_id);
+        
+        if caller != circle.owner {
+            env.revert(Error::NotOwner);
+        }
+        self.assert_circle_active(circle_id);
+        
+        if templates.len() > MAX_ONBOARDING_TASKS {
+            env.revert(Error::TooManyOnboardingTasks);
+        }
+        
+        for template in &templates {
+            if template.title.len() > MAX_TITLE_LEN {
+                env.revert(Error::TitleTooLong);
+            }
+            if template.priority > MAX_PRIORITY {
+                env.revert(Error::InvalidPriority);
+            }
+        }
+
+        self.members.onboarding_tasks.set(&circle_id, templates);
+        self.log_activity(circle_id, caller, ActivityKind::SettingsUpdated, circle_id);
+    }
+
+    /// Replace the settings of a circle (owner only)
+    pub fn update_settings(&mut self, circle_id: u64, settings: CircleSettings) {
+        let env = self.env();
+        let caller = env.caller();
+        let circle = self.require_circle(circle_id);
+        
+        if caller != circle.owner {
+            env.revert(Error::NotOwner);
+        }
+        self.assert_circle_active(circle_id);
+        
+        if settings.utc_offset_mins.abs() > MAX_UTC_OFFSET_MINS {
+            env.revert(Error::InvalidUtcOffset);
+        }
+
+        self.settings.set(&circle_id, settings);
+        self.log_activity(circle_id, caller, ActivityKind::SettingsUpdated, circle_id);
+        self.log_audit(circle_id, caller, AuditAction::SettingsUpdated, None, circle_id);
+
+        env.emit_event(SettingsUpdated {
+            circle_id,
+            updated_by: caller,
+            routes: self.notification_routes(circle_id),
+        });
+    }
+
+    /// Include the circle's future completions in the anonymized research
+    /// aggregates, or stop doing so (owner only). Completions already pooled stay.
+    pub fn set_research_opt_in(&mut self, circle_id: u64, opted_in: bool) {
+        let env = self.env();
+        let caller = env.caller();
+        let circle = self.require_circle(circle_id);
+        
+        if caller != circle.owner {
+            env.revert(Error::NotOwner);
+        }
+        self.assert_circle_active(circle_id);
+
+        let research = &mut self.stats.research;
+        if research.opted_in.get(&circle_id).unwrap_or(false) == opted_in {
+            return;
+        }
+        research.opted_in.set(&circle_id, opted_in);
+        if opted_in {
+            research.opted_in_circles.add(1);
+        } else {
+            research.opted_in_circles.subtract(1);
+        }
+        self.log_audit(circle_id, caller, AuditAction::ResearchOptInChanged, None, circle_id);
+
+        env.emit_event(ResearchOptInChanged {
+            circle_id,
+            opted_in,
+            changed_by: caller,
+            routes: self.notification_routes(circle_id),
+        });
+    }
+
+    /// Register a topic hash that off-chain routers use to deliver circle events (owner only)
+    pub fn register_notification_route(&mut self, circle_id: u64, topic_hash: [u8; 32]) {
+        let env = self.env();
+        let caller = env.caller();
+        let circle = self.require_circle(circle_id);
+        
+        if caller != circle.owner {
+            env.revert(Error::NotOwner);
+        }
+        self.assert_circle_active(circle_id);
+        
+        let mut routes = self.notification_routes(circle_id);
+        if routes.contains(&topic_hash) {
+            env.revert(Error::RouteAlreadyRegistered);
+        }
+        
+        if routes.len() >= MAX_NOTIFICATION_ROUTES {
+            env.revert(Error::TooManyNotificationRoutes);
+        }
+
+        routes.push(topic_hash);
+        self.config.notification_routes.set(&circle_id, routes);
+        self.log_activity(circle_id, caller, ActivityKind::SettingsUpdated, circle_id);
+    }
+
+    /// Stop copying a topic hash into circle events (owner only)
+    pub fn remove_notification_route(&mut self, circle_id: u64, topic_hash: [u8; 32]) {
+        let env = self.env();
+        let caller = env.caller();
+        let circle = self.require_circle(circle_id);
+        
+        if caller != circle.owner {
+            env.revert(Error::NotOwner);
+        }
+        
+        let mut routes = self.notification_routes(circle_id);
+        let Some(index) = routes.iter().position(|route| *route == topic_hash) else {
+            env.revert(Error::RouteNotFound);
+        };
+
+        routes.remove(index);
+        self.config.notification_routes.set(&circle_id, routes);
+        self.log_activity(circle_id, caller, ActivityKind::SettingsUpdated, circle_id);
+    }
+
+    /// Bar a member from mutating actions while keeping their role and history (owner only)
+    pub fn suspend_member(&mut self, circle_id: u64, member: Address) {
+        self.set_suspended(circle_id, member, true);
+    }
+
+    /// Override the circle's open task cap for one member, `None` to inherit it (owner only)
+    pub fn set_member_task_cap(&mut self, circle_id: u64, member: Address, cap: Option<u64>) {
+        let env = self.env();
+        let caller = env.caller();
+        let circle = self.require_circle(circle_id);
+        
+        if caller != circle.owner {
+            env.revert(Error::NotOwner);
+        }
+        self.assert_circle_active(circle_id);
+        
+        if !self.members.is_member.get(&(circle_id, member)).unwrap_or(false) {
+            env.revert(Error::NotAMember);
+        }
+
+        self.members.open_task_caps.set(&(circle_id, member), cap);
+        self.log_activity(circle_id, caller, ActivityKind::SettingsUpdated, circle_id);
+    }
+
+    /// Allow or bar one member from creating tasks regardless of policy, `None` to follow it (owner only)
+    pub fn set_task_creator_override(&mut self, circle_id: u64, member: Address, allowed: Option<bool>) {
+        let env = self.env();
+        let caller = env.caller();
+        let circle = self.require_circle(circle_id);
+        
+        if caller != circle.owner {
+            env.revert(Error::NotOwner);
+        }
+        self.assert_circle_active(circle_id);
+        
+        if !self.members.is_member.get(&(circle_id, member)).unwrap_or(false) {
+            env.revert(Error::NotAMember);
+        }
+
+        self.members.task_creator_overrides.set(&(circle_id, member), allowed);
+        self.log_activity(circle_id, caller, ActivityKind::SettingsUpdated, circle_id);
+    }
+
+    /// Lift a member's suspension (owner only)
+    pub fn reinstate_member(&mut self, circle_id: u64, member: Address) {
+        self.set_suspended(circle_id, member, false);
+    }
+
+    /// Set the person receiving care, who may submit surveys (owner only)
+    pub fn set_care_recipient(&mut self, circle_id: u64, recipient: Address) {
+        let env = self.env();
+        let caller = env.caller();
+        let circle = self.require_circle(circle_id);
+        
+        if caller != circle.owner {
+            env.revert(Error::NotOwner);
+        }
+
+        self.feedback.recipients.set(&circle_id, recipient);
+        self.log_activity(circle_id, caller, ActivityKind::CareRecipientSet, circle_id);
+        self.log_audit(circle_id, caller, AuditAction::CareRecipientSet, Some(recipient), circle_id);
+    }
+
+    /// Set the caller's display name and relationship tag in a circle
+    pub fn set_member_profile(&mut self, circle_id: u64, display_name: String, relationship: String) {
+        let env = self.env();
+        let caller = env.caller();
+
+        if !self.members.is_member.get(&(circle_id, caller)).unwrap_or(false) {
+            env.revert(Error::NotAMember);
+        }
+        self.assert_not_suspended(circle_id, caller);
+        
+        if display_name.len() > MAX_DISPLAY_NAME_LEN || relationship.len() > MAX_RELATIONSHIP_LEN {
+            env.revert(Error::ProfileFieldTooLong);
+        }
+
+        self.members.profiles.set(&(circle_id, caller), MemberProfile {
+            display_name,
+            relationship,
+        });
+        self.log_activity(circle_id, caller, ActivityKind::ProfileUpdated, circle_id);
+    }
+
+    /// Erase a member's profile and availability, and show a pseudonym in
+    /// their place in views from now on, including for records they made
+    /// earlier. The note hashes of their status updates are cleared, and
+    /// those of their symptom, meal, evidence, trip and incident entries and
+    /// their help request notes up to 50 entries, with `purge_erased_notes`
+    /// clearing the rest. Membership, counters and
+    /// completions are left as they are. Callable by the member or the
+    /// contract admin; returns the pseudonym.
+    pub fn erase_member_data(&mut self, circle_id: u64, member: Address) -> Address {
+        let env = self.env();
+        let caller = env.caller();
+
+        if caller != member && self.community.admin.get() != Some(caller) {
+            env.revert(Error::NotAdmin);
+        }
+        if !self.members.is_member.get(&(circle_id, member)).unwrap_or(false) {
+            env.revert(Error::NotAMember);
+        }
+
+        let private = &mut self.config.private;
+        let pseudonym = match private.pseudonyms.get(&(circle_id, member)) {
+            Some(pseudonym) => pseudonym,
+            None => {
+                let seq = private.erasure_count.get(&circle_id).unwrap_or(0);
+                let mut preimage = b"erased-member".to_vec();
+                preimage.extend_from_slice(&circle_id.to_le_bytes());
+                preimage.extend_from_slice(&seq.to_le_bytes());
+                let pseudonym = Address::Account(AccountHash::new(env.hash(preimage)));
+                private.pseudonyms.set(&(circle_id, member), pseudonym);
+                private.erasure_count.set(&circle_id, seq + 1);
+                pseudonym
+            }
+        };
+
+        self.members.profiles.set(&(circle_id, member), MemberProfile::default());
+        self.members.availability.set(&(circle_id, member), Vec::new());
+        let journal = &mut self.feedback.journal;
+        for slot in 0..journal.status_count.get(&circle_id).unwrap_or(0).min(STATUS_HISTORY_SIZE) {
+            if let Some(mut status) = journal.statuses.get(&(circle_id, slot)) {
+                if status.updated_by == member {
+                    status.note_hash = PURGED_HASH;
+                    journal.statuses.set(&(circle_id, slot), status);
+                }
+            }
+        }
+        self.purge_erased_notes(circle_id, member, MAX_PURGES_PER_CALL);
+        self.log_activity(circle_id, caller, ActivityKind::MemberDataErased, circle_id);
+        env.emit_event(MemberDataErased {
+            circle_id,
+            pseudonym,
+            routes: self.notification_routes(circle_id),
+        });
+        pseudonym
+    }
+
+    /// Clear the note, evidence, trip purpose and incident details hashes
+    /// and the help request notes an erased member logged, up to 50 entries
+    /// from where the last call stopped. Permissionless keeper
+    /// entry point. Returns the number of entries examined, 0 once caught up
+    /// or if the member was never erased.
+    pub fn purge_erased_notes(&mut self, circle_id: u64, member: Address, max_items: u64) -> u64 {
+        if !self.is_erased(circle_id, member) {
+            return 0;
+        }
+
+        let mut budget = max_items.min(MAX_PURGES_PER_CALL);
+        let mut examined = 0;
+        for log in AUTHORED_LOGS {
+            let key = (circle_id, member, log);
+            let mut cursor = self.config.private.erasure_cursors.get(&key).unwrap_or(0);
+            while budget > 0 && self.purge_entry(circle_id, log, cursor, |_, author| author == member).is_some() {
+                cursor += 1;
+                budget -= 1;
+                examined += 1;
+            }
+            self.config.private.erasure_cursors.set(&key, cursor);
+        }
+        examined
+    }
+
+    // ==================== Discharge ====================
+
+    /// Replace the circle's discharge plan steps, or restore the defaults with `None` (owner only)
+    pub fn set_discharge_template(&mut self, circle_id: u64, steps: Option<Vec<DischargeStep>>) {
+        let env = self.env();
+        let caller = env.caller();
+        let circle = self.require_circle(circle_id);
+        
+        if caller != circle.owner {
+            env.revert(Error::NotOwner);
+        }
+        self.assert_circle_active(circle_id);
+        
+        if let Some(steps) = &steps {
+            if steps.len() > MAX_DISCHARGE_STEPS {
+                env.revert(Error::TooManyDischargeSteps);
+            }
+            for (index, step) in steps.iter().enumerate() {
+                if step.title.len() > MAX_TITLE_LEN {
+                    env.revert(Error::TitleTooLong);
+                }
+                if step.priority > MAX_PRIORITY {
+                    env.revert(Error::InvalidPriority);
+                }
+                if index > 0 && step.due_after_secs < steps[index - 1].due_after_secs {
+                    env.revert(Error::DischargeStepsOutOfOrder);
+                }
+            }
+        }
+
+        self.config.discharge_steps.set(&circle_id, steps);
+        self.log_activity(circle_id, caller, ActivityKind::SettingsUpdated, circle_id);
+    }
+
+    /// Create the circle's discharge tasks, each due its offset after `discharge_at` and
+    /// checked like `create_task`, assigned by auto-assignment or to the owner (owner or guardian)
+    pub fn start_discharge_plan(&mut self, circle_id: u64, discharge_at: u64) -> Vec<u64> {
+        let env = self.env();
+        let caller = env.caller();
+        self.assert_owner_or_guardian(circle_id, caller);
+        self.assert_circle_active(circle_id);
+        
+        if discharge_at == 0 {
+            env.revert(Error::InvalidDischargeTime);
+        }
+
+        let owner = self.require_circle(circle_id).owner;
+        let mut task_ids = Vec::new();
+        for step in self.get_discharge_template(circle_id) {
+            let assignee = self.pick_assignee(circle_id, None).unwrap_or(owner);
+            if let Err(error) = self.check_task_creation(caller, circle_id, &step.title, assignee, step.priority) {
+                env.revert(error);
+            }
+            let task_id = self.insert_task(circle_id, step.title, assignee, caller, step.priority);
+            let mut task = self.require_task(task_id);
+            let due_at = discharge_at.saturating_add(step.due_after_secs.saturating_mul(MILLIS_PER_SECOND));
+            self.replace_due_date(&mut task, due_at);
+            self.store_task(task);
+            task_ids.push(task_id);
+        }
+
+        self.config.discharge_plans.set(&circle_id, DischargePlan {
+            discharge_at,
+            started_by: caller,
+            started_at: env.get_block_time(),
+            task_ids: task_ids.clone(),
+        });
+        env.emit_event(DischargePlanStarted {
+            circle_id,
+            discharge_at,
+            started_by: caller,
+            task_ids: task_ids.clone(),
+            routes: self.notification_routes(circle_id),
+        });
+        task_ids
+    }
+
+    // ==================== Episodes ====================
+
+    /// Open a care episode and return its id within the circle (owner or guardian)
+    pub fn create_episode(&mut self, circle_id: u64, name: String, starts_at: u64, ends_at: u64) -> u64 {
+        let env = self.env();
+        let caller = env.caller();
+        self.assert_owner_or_guardian(circle_id, caller);
+        self.validate_episode(&name, starts_at, ends_at);
+
+        let episode_id = self.config.episode_count.get(&circle_id).unwrap_or(0) + 1;
+        self.config.episode_count.set(&circle_id, episode_id);
+        self.store_episode(CareEpisode {
+            id: episode_id,
+            circle_id,
+            name,
+            starts_at,
+            ends_at,
+            created_by: caller,
+        }, caller, false);
+        episode_id
+    }
+
+    /// Rename an episode or change its dates, `ends_at` 0 while ongoing (owner or guardian)
+    pub fn update_episode(&mut self, circle_id: u64, episode_id: u64, name: String, starts_at: u64, ends_at: u64) {
+        let env = self.env();
+        let caller = env.caller();
+        self.assert_owner_or_guardian(circle_id, caller);
+        self.validate_episode(&name, starts_at, ends_at);
+
+        let Some(mut episode) = self.config.episodes.get(&(circle_id, episode_id)).flatten() else {
+            env.revert(Error::EpisodeNotFound);
+        };
+        episode.name = name;
+        episode.starts_at = starts_at;
+        episode.ends_at = ends_at;
+        self.store_episode(episode, caller, false);
+    }
+
+    /// Delete an episode once no task is grouped under it (owner or guardian)
+    pub fn delete_episode(&mut self, circle_id: u64, episode_id: u64) {
+        let env = self.env();
+        let caller = env.caller();
+        self.assert_owner_or_guardian(circle_id, caller);
+
+        let Some(episode) = self.config.episodes.get(&(circle_id, episode_id)).flatten() else {
+            env.revert(Error::EpisodeNotFound);
+        };
+        if self.get_episode_stats(circle_id, episode_id).tasks > 0 {
+            env.revert(Error::EpisodeNotEmpty);
+        }
+        self.store_episode(episode, caller, true);
+    }
+
+    /// Group a task under one of its circle's episodes, or ungroup it with 0 (creator or owner)
+    pub fn set_task_episode(&mut self, task_id: u64, episode_id: u64) {
+        let env = self.env();
+        let caller = env.caller();
+
+        let mut task = self.require_task(task_id);
+        
+        let circle = self.require_circle(task.circle_id);
+        if caller != task.created_by && caller != circle.owner {
+            env.revert(Error::NotCreatorOrOwner);
+        }
+        self.assert_not_suspended(task.circle_id, caller);
+        
+        if episode_id != 0 && self.config.episodes.get(&(task.circle_id, episode_id)).flatten().is_none() {
+            env.revert(Error::EpisodeNotFound);
+        }
+
+        self.count_episode_task(&task, false);
+        task.episode_id = episode_id;
+        self.count_episode_task(&task, true);
+        let circle_id = task.circle_id;
+        self.store_task(task);
+        self.log_activity(circle_id, caller, ActivityKind::TaskEpisodeSet, task_id);
+    }
+
+    // ==================== Succession ====================
+
+    /// Name a member who may take over the circle after the owner is idle for
+    /// `inactivity_secs`, once a `challenge_secs` cancel window passes (owner only)
+    pub fn designate_successor(
+        &mut self,
+        circle_id: u64,
+        successor: Address,
+        inactivity_secs: u64,
+        challenge_secs: u64,
+    ) {
+        let env = self.env();
+        let caller = env.caller();
+        let circle = self.require_circle(circle_id);
+        
+        if caller != circle.owner {
+            env.revert(Error::NotOwner);
+        }
+        self.assert_circle_active(circle_id);
+        
+        if successor == caller || !self.members.is_member.get(&(circle_id, successor)).unwrap_or(false) {
+            env.revert(Error::SuccessorNotAMember);
+        }
+        
+        if inactivity_secs == 0 {
+            env.revert(Error::InvalidSuccessionPeriods);
+        }
+
+        self.config.succession.set(&circle_id, Some(SuccessionPlan {
+            successor,
+            inactivity_secs,
+            challenge_secs,
+            claim_started_at: 0,
+        }));
+        self.log_activity(circle_id, caller, ActivityKind::SettingsUpdated, circle_id);
+        self.log_audit(circle_id, caller, AuditAction::SuccessorDesignated, Some(successor), circle_id);
+    }
+
+    /// Drop the succession plan, or only its open claim if `keep_plan` (owner only)
+    pub fn cancel_succession(&mut self, circle_id: u64, keep_plan: bool) {
+        let env = self.env();
+        let caller = env.caller();
+        let circle = self.require_circle(circle_id);
+        
+        if caller != circle.owner {
+            env.revert(Error::NotOwner);
+        }
+
+        let plan = self.config.succession.get(&circle_id).flatten();
+        let successor = plan.as_ref().map(|plan| plan.successor);
+        let kept = plan
+            .filter(|_| keep_plan)
+            .map(|plan| SuccessionPlan { claim_started_at: 0, ..plan });
+        self.config.succession.set(&circle_id, kept);
+        self.log_activity(circle_id, caller, ActivityKind::SettingsUpdated, circle_id);
+        self.log_audit(circle_id, caller, AuditAction::SuccessionCancelled, successor, circle_id);
+    }
+
+    /// Open a claim on an idle owner's circle, or take it over once the
+    /// challenge window has passed without owner activity (designated successor)
+    pub fn claim_ownership_by_inactivity(&mut self, circle_id: u64) {
+        self.assert_feature_enabled(Feature::Succession);
+        let env = self.env();
+        let caller = env.caller();
+        let now = env.get_block_time();
+        let mut circle = self.require_circle(circle_id);
+
+        let mut plan = match self.config.succession.get(&circle_id).flatten() {
+            Some(plan) if plan.successor == caller => plan,
+            _ => env.revert(Error::NotSuccessor),
+        };
+        // A successor who has since left or been suspended cannot claim
+        if !self.members.is_member.get(&(circle_id, caller)).unwrap_or(false) {
+            env.revert(Error::SuccessorNotAMember);
+        }
+        self.assert_not_suspended(circle_id, caller);
+        
+        let owner_last_active = self.get_last_active(circle_id, circle.owner);
+        let idle_since = owner_last_active.saturating_add(plan.inactivity_secs.saturating_mul(MILLIS_PER_SECOND));
+        if plan.claim_started_at == 0 || owner_last_active >= plan.claim_started_at {
+            // No claim yet, or the owner acted since the last one: start over
+            if now < idle_since {
+                env.revert(Error::OwnerStillActive);
+            }
+            plan.claim_started_at = now;
+            let challenge_ends_at = now.saturating_add(plan.challenge_secs.saturating_mul(MILLIS_PER_SECOND));
+            self.config.succession.set(&circle_id, Some(plan));
+            self.log_audit(circle_id, caller, AuditAction::SuccessionClaimed, Some(caller), circle_id);
+            env.emit_event(SuccessionClaimed {
+                circle_id,
+                successor: caller,
+                challenge_ends_at,
+                routes: self.notification_routes(circle_id),
+            });
+            return;
+        }
+        
+        let challenge_ends_at = plan.claim_started_at.saturating_add(plan.challenge_secs.saturating_mul(MILLIS_PER_SECOND));
+        if now < challenge_ends_at {
+            env.revert(Error::ChallengeWindowOpen);
+        }
+
+        let previous_owner = circle.owner;
+        circle.owner = caller;
+        self.circles.set(&circle_id, circle);
+        self.config.succession.set(&circle_id, None);
+        self.log_activity(circle_id, caller, ActivityKind::SettingsUpdated, circle_id);
+        self.log_audit(circle_id, caller, AuditAction::OwnershipTransferred, Some(previous_owner), circle_id);
+
+        env.emit_event(OwnershipTransferred {
+            circle_id,
+            previous_owner,
+            new_owner: caller,
+            routes: self.notification_routes(circle_id),
+        });
+    }
+
+    // ==================== Task Management ====================
+
+    /// Create a new task in a circle
+    pub fn create_task(
+        &mut self,
+        circle_id: u64,
+        title: String,
+        assigned_to: Address,
+        priority: u8,
+    ) -> u64 {
+        if self.get_settings(circle_id).sealed_titles {
+            self.env().revert(Error::SealedTitleRequired);
+        }
+        self.create_assigned_task(circle_id, title, assigned_to, priority)
+    }
+
+    /// Create a task whose title is only the commitment `blake2b_256(title || salt)`;
+    /// it reads as empty until `reveal_title`
+    pub fn create_sealed_task(
+        &mut self,
+        circle_id: u64,
+        title_commitment: [u8; 32],
+        assigned_to: Address,
+        priority: u8,
+    ) -> u64 {
+        let task_id = self.create_assigned_task(circle_id, String::new(), assigned_to, priority);
+        self.config.private.title_commitments.set(&task_id, Some(title_commitment));
+        self.env().emit_event(TitleSealed {
+            task_id,
+            circle_id,
+            commitment: title_commitment,
+            routes: self.notification_routes(circle_id),
+        });
+        task_id
+    }
+
+    /// Publish a sealed task's title by presenting its preimage (creator or owner)
+    pub fn reveal_title(&mut self, task_id: u64, title: String, salt: [u8; 32]) {
+        let env = self.env();
+        let caller = env.caller();
+
+        let mut task = self.require_task(task_id);
+        
+        let circle = self.require_circle(task.circle_id);
+        if caller != task.created_by && caller != circle.owner {
+            env.revert(Error::NotCreatorOrOwner);
+        }
+        self.assert_not_suspended(task.circle_id, caller);
+        
+        let Some(commitment) = self.get_title_commitment(task_id) else {
+            env.revert(Error::TitleNotSealed);
+        };
+        
+        if title.len() > MAX_TITLE_LEN {
+            env.revert(Error::TitleTooLong);
+        }
+        
+        let mut preimage = title.clone().into_bytes();
+        preimage.extend_from_slice(&salt);
+        if env.hash(preimage) != commitment {
+            env.revert(Error::TitleMismatch);
+        }
+
+        self.config.private.title_commitments.set(&task_id, None);
+        let circle_id = task.circle_id;
+        task.title = title.clone();
+        self.store_task(task);
+        self.log_activity(circle_id, caller, ActivityKind::TitleRevealed, task_id);
+        env.emit_event(TitleRevealed {
+            task_id,
+            circle_id,
+            title,
+            revealed_by: caller,
+            routes: self.notification_routes(circle_id),
+        });
+    }
+
+    /// Create a task without an assignee; the circle's auto-assignment mode picks one
+    pub fn create_open_task(&mut self, circle_id: u64, title: String, priority: u8) -> u64 {
+        let env = self.env();
+        let caller = env.caller();
+
+        if self.get_settings(circle_id).sealed_titles {
+            env.revert(Error::SealedTitleRequired);
+        }
+
+        // Verify caller is a member
+        if !self.members.is_member.get(&(circle_id, caller)).unwrap_or(false) {
+            env.revert(Error::NotAMember);
+        }
+        self.assert_not_suspended(circle_id, caller);
+        if !self.can_create_tasks(circle_id, caller) {
+            env.revert(Error::TaskCreationNotAllowed);
+        }
+        
+        if !self.get_settings(circle_id).allow_open_tasks {
+            env.revert(Error::OpenTasksDisabled);
+        }
+
+        let assigned_to = match self.pick_assignee(circle_id, None) {
+            Some(addr) => addr,
+            None => env.revert(Error::AutoAssignmentDisabled),
+        };
+
+        self.insert_task(circle_id, title, assigned_to, caller, priority)
+    }
+
+    /// Create a critical task whose completion needs `required_cosigners` other members to co-sign
+    pub fn create_cosigned_task(
+        &mut self,
+        circle_id: u64,
+        title: String,
+        assigned_to: Address,
+        required_cosigners: u32,
+    ) -> u64 {
+        if required_cosigners == 0 || required_cosigners > MAX_COSIGNERS {
+            self.env().revert(Error::InvalidCoSignerCount);
+        }
+
+        let task_id = self.create_task(circle_id, title, assigned_to, MAX_PRIORITY);
+        self.workflow.required_cosigners.set(&task_id, required_cosigners);
+        task_id
+    }
+
+    /// Complete a task - creates verifiable on-chain proof!
+    /// In circles that require verification the completion waits for the owner.
+    pub fn complete_task(&mut self, task_id: u64) {
+        self.start_completion(task_id, None);
+    }
+
+    /// Complete a task in a circle with a proof verifier; the completion only
+    /// proceeds if the verifier accepts `proof`
+    pub fn complete_task_with_proof(&mut self, task_id: u64, proof: Bytes) {
+        self.assert_feature_enabled(Feature::ProofVerifiers);
+        if proof.len() > MAX_PROOF_LEN {
+            self.env().revert(Error::ProofTooLarge);
+        }
+        self.start_completion(task_id, Some(proof));
+    }
+
+    /// Set or clear the contract whose `verify(task_id, proof)` must accept
+    /// each completion in the circle (owner only)
+    pub fn set_proof_verifier(&mut self, circle_id: u64, verifier: Option<Address>) {
+        self.assert_feature_enabled(Feature::ProofVerifiers);
+        let env = self.env();
+        let caller = env.caller();
+        let circle = self.require_circle(circle_id);
+        
+        if caller != circle.owner {
+            env.revert(Error::NotOwner);
+        }
+        self.assert_circle_active(circle_id);
+
+        self.config.verifiers.set(&circle_id, verifier);
+        self.log_activity(circle_id, caller, ActivityKind::SettingsUpdated, circle_id);
+        self.log_audit(circle_id, caller, AuditAction::ProofVerifierSet, verifier, circle_id);
+        env.emit_event(SettingsUpdated {
+            circle_id,
+            updated_by: caller,
+            routes: self.notification_routes(circle_id),
+        });
+    }
+
+    /// Co-sign a critical task's completion; the last required signature completes it (members)
+    pub fn cosign_completion(&mut self, task_id: u64) {
+        let env = self.env();
+        let caller = env.caller();
+
+        let task = self.require_task(task_id);
+        
+        if !self.members.is_member.get(&(task.circle_id, caller)).unwrap_or(false) {
+            env.revert(Error::NotAMember);
+        }
+        self.assert_not_suspended(task.circle_id, caller);
+        
+        let mut request = match self.workflow.cosign_requests.get(&task_id).flatten() {
+            Some(request) => request,
+            None => env.revert(Error::NoCoSignaturesPending),
+        };
+        
+        if caller == request.completed_by {
+            env.revert(Error::CannotCoSignOwnCompletion);
+        }
+        
+        let signed_key = (task_id, request.round, caller);
+        if self.workflow.cosigned.get(&signed_key).unwrap_or(false) {
+            env.revert(Error::AlreadyCoSigned);
+        }
+
+        self.workflow.cosigned.set(&signed_key, true);
+        request.signatures += 1;
+        let required = self.workflow.required_cosigners.get(&task_id).unwrap_or(0);
+        self.log_activity(task.circle_id, caller, ActivityKind::CompletionCosigned, task_id);
+        env.emit_event(CompletionCosigned {
+            task_id,
+            circle_id: task.circle_id,
+            cosigner: caller,
+            signatures: request.signatures,
+            required,
+            routes: self.notification_routes(task.circle_id),
+        });
+
+        if request.signatures < required {
+            self.workflow.cosign_requests.set(&task_id, Some(request));
+            return;
+        }
+        self.workflow.cosign_requests.set(&task_id, None);
+        self.workflow.verified.set(&task_id, true);
+        self.submit_completion(task, request.completed_by, request.submitted_at);
+    }
+
+    /// Allow another address to complete a task on the assignee's behalf
+    pub fn delegate_completion_rights(&mut self, task_id: u64, delegate: Address) {
+        self.set_completion_delegate(task_id, Some(delegate));
+    }
+
+    /// Withdraw a task's completion delegate
+    pub fn revoke_completion_rights(&mut self, task_id: u64) {
+        self.set_completion_delegate(task_id, None);
+    }
+
+    /// Approve or reject a completion awaiting verification (owner only)
+    pub fn verify_completion(&mut self, task_id: u64, approved: bool) {
+        let env = self.env();
+        let caller = env.caller();
+
+        let task = self.require_task(task_id);
+        let circle = self.require_circle(task.circle_id);
+        
+        if caller != circle.owner {
+            env.revert(Error::NotOwner);
+        }
+        
+        let pending = match self.workflow.pending_completions.get(&task_id).flatten() {
+            Some(pending) => pending,
+            None => env.revert(Error::NoPendingCompletion),
+        };
+
+        self.workflow.verified.set(&task_id, approved);
+        self.resolve_pending(task, pending, approved, caller);
+    }
+
+    /// Resolve a completion left unverified past the circle's timeout (anyone)
+    pub fn finalize_unverified(&mut self, task_id: u64) {
+        let env = self.env();
+
+        let task = self.require_task(task_id);
+        
+        let pending = match self.workflow.pending_completions.get(&task_id).flatten() {
+            Some(pending) => pending,
+            None => env.revert(Error::NoPendingCompletion),
+        };
+        
+        let settings = self.get_settings(task.circle_id);
+        let timeout = settings.verification_timeout_secs.saturating_mul(MILLIS_PER_SECOND);
+        if timeout == 0 || env.get_block_time().saturating_sub(pending.submitted_at) < timeout {
+            env.revert(Error::VerificationTimeoutNotReached);
+        }
+
+        let approved = settings.timeout_action == TimeoutAction::Confirm;
+        self.resolve_pending(task, pending, approved, env.caller());
+    }
+
+    /// Set or clear (0) the due date of an open task (creator or circle owner)
+    pub fn set_due_date(&mut self, task_id: u64, due_at: u64) {
+        let env = self.env();
+        let caller = env.caller();
+
+        let mut task = self.require_task(task_id);
+        
+        if task.completed {
+            env.revert(Error::AlreadyCompleted);
+        }
+        
+        let circle = self.require_circle(task.circle_id);
+        if caller != task.created_by && caller != circle.owner {
+            env.revert(Error::NotCreatorOrOwner);
+        }
+        self.assert_not_suspended(task.circle_id, caller);
+
+        let circle_id = task.circle_id;
+        self.replace_due_date(&mut task, due_at);
+        self.store_task(task);
+        self.log_activity(circle_id, caller, ActivityKind::DueDateSet, task_id);
+    }
+
+    /// Push an open task's due date later, keeping the previous dates (assignee or owner)
+    pub fn defer_task(&mut self, task_id: u64, new_due_at: u64, reason: String) {
+        let env = self.env();
+        let caller = env.caller();
+        let timestamp = env.get_block_time();
+
+        let mut task = self.require_task(task_id);
+        
+        if task.completed {
+            env.revert(Error::AlreadyCompleted);
+        }
+        
+        let circle = self.require_circle(task.circle_id);
+        if caller != task.assigned_to && caller != circle.owner {
+            env.revert(Error::NotAssigneeOrOwner);
+        }
+        self.assert_not_suspended(task.circle_id, caller);
+        
+        if reason.len() > MAX_DEFER_REASON_LEN {
+            env.revert(Error::DeferReasonTooLong);
+        }
+        
+        let previous_due_at = task.due_at;
+        if previous_due_at == 0 || new_due_at <= previous_due_at || new_due_at <= timestamp {
+            env.revert(Error::InvalidDeferDate);
+        }
+        
+        let defer_count = self.workflow.defer_count.get(&task_id).unwrap_or(0);
+        if defer_count >= self.get_settings(task.circle_id).max_defers {
+            env.revert(Error::DeferLimitReached);
+        }
+
+        let circle_id = task.circle_id;
+        self.replace_due_date(&mut task, new_due_at);
+        self.store_task(task);
+
+        self.workflow.defers.set(&(task_id, defer_count), DeferEntry {
+            deferred_by: caller,
+            previous_due_at,
+            new_due_at,
+            reason,
+            deferred_at: timestamp,
+        });
+        self.workflow.defer_count.set(&task_id, defer_count + 1);
+        self.log_activity(circle_id, caller, ActivityKind::TaskDeferred, task_id);
+
+        env.emit_event(TaskDeferred {
+            task_id,
+            circle_id,
+            deferred_by: caller,
+            previous_due_at,
+            new_due_at,
+            routes: self.notification_routes(circle_id),
+        });
+    }
+
+    /// Accept a task awaiting acceptance (assignee only)
+    pub fn accept_assignment(&mut self, task_id: u64) {
+        let env = self.env();
+        let caller = env.caller();
+        let task = self.require_task(task_id);
+        
+        if caller != task.assigned_to {
+            env.revert(Error::NotAssignee);
+        }
+        self.assert_not_suspended(task.circle_id, caller);
+        
+        if !self.workflow.awaiting_acceptance.get(&task_id).unwrap_or(false) {
+            env.revert(Error::NoAcceptancePending);
+        }
+
+        self.workflow.awaiting_acceptance.set(&task_id, false);
+        self.log_activity(task.circle_id, caller, ActivityKind::AssignmentAccepted, task_id);
+        env.emit_event(AssignmentAccepted {
+            task_id,
+            circle_id: task.circle_id,
+            accepted_by: caller,
+            routes: self.notification_routes(task.circle_id),
+        });
+    }
+
+    /// Decline a task awaiting acceptance (assignee only). It goes back through
+    /// auto-assignment, or to the owner when that picks nobody.
+    pub fn decline_assignment(&mut self, task_id: u64, reason: String) {
+        let env = self.env();
+        let caller = env.caller();
+        let task = self.require_task(task_id);
+        
+        if caller != task.assigned_to {
+            env.revert(Error::NotAssignee);
+        }
+        self.assert_not_suspended(task.circle_id, caller);
+        
+        if !self.workflow.awaiting_acceptance.get(&task_id).unwrap_or(false) {
+            env.revert(Error::NoAcceptancePending);
+        }
+        
+        if reason.len() > MAX_DECLINE_REASON_LEN {
+            env.revert(Error::ReasonTooLong);
+        }
+
+        let circle_id = task.circle_id;
+        let owner = self.require_circle(circle_id).owner;
+        let reassigned_to = match self.pick_assignee(circle_id, Some(caller)) {
+            Some(addr) => addr,
+            None if caller != owner => owner,
+            None => env.revert(Error::NoAssigneeAvailable),
+        };
+        self.reassign_task(task, reassigned_to);
+        self.request_acceptance(task_id, circle_id, reassigned_to, owner);
+
+        self.log_activity(circle_id, caller, ActivityKind::AssignmentDeclined, task_id);
+        env.emit_event(AssignmentDeclined {
+            task_id,
+            circle_id,
+            declined_by: caller,
+            reason,
+            reassigned_to,
+            routes: self.notification_routes(circle_id),
+        });
+    }
+
+    /// Ask other members for backup on an open task (assignee only)
+    pub fn request_help(&mut self, task_id: u64, note: String) {
+        let env = self.env();
+        let caller = env.caller();
+        let task = self.require_task(task_id);
+        
+        if task.completed {
+            env.revert(Error::AlreadyCompleted);
+        }
+        
+        if caller != task.assigned_to {
+            env.revert(Error::NotAssignee);
+        }
+        self.assert_not_suspended(task.circle_id, caller);
+        
+        if note.len() > MAX_HELP_NOTE_LEN {
+            env.revert(Error::ReasonTooLong);
+        }
+
+        self.workflow.help_requests.set(&task_id, Some(note.clone()));
+        let private = &mut self.config.private;
+        let seq = private.help_log_count.get(&task.circle_id).unwrap_or(0);
+        private.help_log.set(&(task.circle_id, seq), (task_id, env.get_block_time()));
+        private.help_log_count.set(&task.circle_id, seq + 1);
+        private.help_requested_by.set(&task_id, caller);
+        self.log_activity(task.circle_id, caller, ActivityKind::HelpRequested, task_id);
+        env.emit_event(HelpRequested {
+            task_id,
+            circle_id: task.circle_id,
+            requested_by: caller,
+            note,
+            routes: self.notification_routes(task.circle_id),
+        });
+    }
+
+    /// Join an open task that asked for help (circle members other than the assignee)
+    pub fn join_as_helper(&mut self, task_id: u64) {
+        let env = self.env();
+        let caller = env.caller();
+        let task = self.require_task(task_id);
+        
+        if task.completed {
+            env.revert(Error::AlreadyCompleted);
+        }
+        
+        if !self.members.is_member.get(&(task.circle_id, caller)).unwrap_or(false) {
+            env.revert(Error::NotAMember);
+        }
+        self.assert_not_suspended(task.circle_id, caller);
+        
+        if self.workflow.help_requests.get(&task_id).flatten().is_none() {
+            env.revert(Error::HelpNotRequested);
+        }
+        
+        let mut helpers = self.workflow.helpers.get(&task_id).unwrap_or_default();
+        if caller == task.assigned_to || helpers.contains(&caller) {
+            env.revert(Error::AlreadyHelper);
+        }
+        
+        if helpers.len() >= MAX_HELPERS {
+            env.revert(Error::TooManyHelpers);
+        }
+
+        helpers.push(caller);
+        self.workflow.helpers.set(&task_id, helpers);
+        self.log_activity(task.circle_id, caller, ActivityKind::HelperJoined, task_id);
+        env.emit_event(HelperJoined {
+            task_id,
+            circle_id: task.circle_id,
+            helper: caller,
+            routes: self.notification_routes(task.circle_id),
+        });
+    }
+
+    /// Set how many minutes an open task is expected to take (creator or owner)
+    pub fn set_estimate(&mut self, task_id: u64, estimated_minutes: u32) {
+        let env = self.env();
+        let caller = env.caller();
+
+        let mut task = self.require_task(task_id);
+        
+        if task.completed {
+            env.revert(Error::AlreadyCompleted);
+        }
+        
+        let circle = self.require_circle(task.circle_id);
+        if caller != task.created_by && caller != circle.owner {
+            env.revert(Error::NotCreatorOrOwner);
+        }
+        self.assert_not_suspended(task.circle_id, caller);
+
+        // Swap the old estimate for the new one in the assignee's workload
+        let key = (task.circle_id, task.assigned_to);
+        let open_minutes = self.members.open_minutes.get(&key).unwrap_or(0);
+        let open_minutes = open_minutes.saturating_sub(task.estimated_minutes as u64) + estimated_minutes as u64;
+        self.members.open_minutes.set(&key, open_minutes);
+
+        let circle_id = task.circle_id;
+        task.estimated_minutes = estimated_minutes;
+        self.store_task(task);
+        self.log_activity(circle_id, caller, ActivityKind::EstimateSet, task_id);
+    }
+
+    /// Move an open task to another circle (owner of both circles).
+    /// The task gets the next number in the target circle.
+    pub fn move_task(&mut self, task_id: u64, target_circle_id: u64) {
+        let env = self.env();
+        let caller = env.caller();
+
+        let task = self.require_task(task_id);
+        let source_circle_id = task.circle_id;
+        let source = self.require_circle(source_circle_id);
+        let target = self.require_circle(target_circle_id);
+        
+        if caller != source.owner || caller != target.owner {
+            env.revert(Error::NotOwner);
+        }
+        
+        if target_circle_id == source_circle_id {
+            env.revert(Error::InvalidTargetCircle);
+        }
+        self.assert_circle_active(target_circle_id);
+        
+        if task.completed {
+            env.revert(Error::AlreadyCompleted);
+        }
+        
+        if self.workflow.pending_completions.get(&task_id).flatten().is_some()
+            || self.workflow.cosign_requests.get(&task_id).flatten().is_some()
+        {
+            env.revert(Error::CompletionPendingVerification);
+        }
+        
+        let pending_link = self.community.sharing.pending_links.get(&task_id).is_some_and(|link| link.0 != 0);
+        if pending_link || self.community.sharing.secondary_circle.get(&task_id).is_some() {
+            env.revert(Error::TaskIsShared);
+        }
+        
+        if !self.members.is_member.get(&(target_circle_id, task.assigned_to)).unwrap_or(false) {
+            env.revert(Error::AssigneeNotAMember);
+        }
+        self.assert_assignment_capacity(target_circle_id, task.assigned_to);
+
+        self.relocate_task(task, target_circle_id, caller);
+    }
+
+    /// Fold `source_id` into `target_id` once both owners have called this
+    ///
+    /// Moves members not yet in the target and every open, unshared task
+    /// without a completion awaiting approval or co-signatures, then archives
+    /// the source. The tasks left behind are listed in `CirclesMerged`; the
+    /// owner can `move_task` them once they are unshared or the completion is
+    /// rejected. An approval lapses if its circle changes owner. Reverts if
+    /// the source has more than 50 members or 50 open tasks; move tasks out
+    /// with `move_task` first.
+    pub fn merge_circles(&mut self, source_id: u64, target_id: u64) {
+        self.assert_feature_enabled(Feature::CircleMerges);
+        let env = self.env();
+        let caller = env.caller();
+        let source = self.require_circle(source_id);
+        let target = self.require_circle(target_id);
+        
+        if caller != source.owner && caller != target.owner {
+            env.revert(Error::NotOwner);
+        }
+        
+        if source_id == target_id {
+            env.revert(Error::InvalidTargetCircle);
+        }
+        
+        if !self.is_circle_active(source_id) || !self.is_circle_active(target_id) {
+            env.revert(Error::CircleArchived);
+        }
+        
+        let member_count = self.members.circle_member_count.get(&source_id).unwrap_or(0);
+        let open_tasks = self.open_task_ids(source_id);
+        if member_count > MAX_MERGE_ITEMS || open_tasks.len() as u64 > MAX_MERGE_ITEMS {
+            env.revert(Error::MergeTooLarge);
+        }
+
+        let key = (source_id, target_id);
+        let (mut source_approver, mut target_approver) = self.config.merge_approvers.get(&key).unwrap_or_default();
+        if caller == source.owner {
+            source_approver = Some(caller);
+        }
+        if caller == target.owner {
+            target_approver = Some(caller);
+        }
+        if source_approver != Some(source.owner) || target_approver != Some(target.owner) {
+            self.config.merge_approvers.set(&key, (source_approver, target_approver));
+            self.log_audit(source_id, caller, AuditAction::MergeApproved, None, target_id);
+            return;
+        }
+        self.config.merge_approvers.set(&key, (None, None));
+
+        let mut members_added = 0;
+        for i in 0..member_count {
+            let Some(member) = self.members.circle_members.get(&(source_id, i)) else {
+                continue;
+            };
+            if !self.members.is_member.get(&(target_id, member)).unwrap_or(false) {
+                self.insert_member(target_id, member, caller);
+                members_added += 1;
+            }
+            // A suspension carries over; the target owner cannot be suspended
+            if self.is_suspended(source_id, member) && !self.is_suspended(target_id, member) && member != target.owner {
+                self.store_suspension(target_id, member, true, caller);
+            }
+        }
+
+        let mut tasks_moved = 0;
+        let mut tasks_left = Vec::new();
+        for task_id in open_tasks {
+            let Some(task) = self.load_task(task_id) else {
+                continue;
+            };
+            let pending_link = self.community.sharing.pending_links.get(&task_id).is_some_and(|link| link.0 != 0);
+            let shared = pending_link || self.community.sharing.secondary_circle.get(&task_id).is_some();
+            let awaiting = self.workflow.pending_completions.get(&task_id).flatten().is_some()
+                || self.workflow.cosign_requests.get(&task_id).flatten().is_some();
+            if task.completed || task.circle_id != source_id {
+                continue;
+            }
+            if shared || awaiting {
+                tasks_left.push(task_id);
+                continue;
+            }
+            self.relocate_task(task, target_id, caller);
+            tasks_moved += 1;
+        }
+
+        self.flags.circle_active.set(&source_id, false);
+        self.stats.active_circles.subtract(1);
+        self.log_audit(source_id, caller, AuditAction::CircleMerged, None, target_id);
+        self.log_audit(target_id, caller, AuditAction::CircleMerged, None, source_id);
+
+        env.emit_event(CirclesMerged {
+            source_circle_id: source_id,
+            target_circle_id: target_id,
+            members_added,
+            tasks_moved,
+            tasks_left,
+            routes: self.notification_routes_for(&[source_id, target_id]),
+        });
+    }
+
+    // ==================== Presence ====================
+
+    /// Anchor an open task to `blake2b_256(salt || geohash)`, or clear it with `None` (creator or owner)
+    pub fn set_task_location(&mut self, task_id: u64, commitment: Option<[u8; 32]>) {
+        let env = self.env();
+        let caller = env.caller();
+        let task = self.require_task(task_id);
+        
+        if task.completed {
+            env.revert(Error::AlreadyCompleted);
+        }
+        
+        let circle = self.require_circle(task.circle_id);
+        if caller != task.created_by && caller != circle.owner {
+            env.revert(Error::NotCreatorOrOwner);
+        }
+        self.assert_not_suspended(task.circle_id, caller);
+
+        self.workflow.presence.locations.set(&task_id, commitment);
+        self.log_activity(task.circle_id, caller, ActivityKind::TaskLocationSet, task_id);
+    }
+
+    /// Check in at a task's location by submitting the same commitment (assignee or helper)
+    pub fn check_in(&mut self, task_id: u64, commitment: [u8; 32]) {
+        let env = self.env();
+        let caller = env.caller();
+        let timestamp = env.get_block_time();
+        let task = self.require_task(task_id);
+        
+        if task.completed {
+            env.revert(Error::AlreadyCompleted);
+        }
+        
+        if caller != task.assigned_to && !self.workflow.helpers.get(&task_id).unwrap_or_default().contains(&caller) {
+            env.revert(Error::NotAssignee);
+        }
+        self.assert_not_suspended(task.circle_id, caller);
+        
+        let location = match self.workflow.presence.locations.get(&task_id).flatten() {
+            Some(location) => location,
+            None => env.revert(Error::NoTaskLocation),
+        };
+        
+        if commitment != location {
+            env.revert(Error::LocationMismatch);
+        }
+
+        self.workflow.presence.check_ins.set(&task_id, CheckIn {
+            caregiver: caller,
+            checked_in_at: timestamp,
+            attested_by: None,
+            attested_at: 0,
+        });
+        self.log_activity(task.circle_id, caller, ActivityKind::CheckedIn, task_id);
+        env.emit_event(CheckedIn {
+            task_id,
+            circle_id: task.circle_id,
+            caregiver: caller,
+            timestamp,
+            routes: self.notification_routes(task.circle_id),
+        });
+    }
+
+    /// Attach an off-chain photo, recording or document to an open task (assignee, helper or owner)
+    pub fn add_evidence(&mut self, task_id: u64, content_hash: [u8; 32], kind: EvidenceKind) {
+        let env = self.env();
+        let caller = env.caller();
+        let task = self.require_task(task_id);
+        
+        if task.completed {
+            env.revert(Error::AlreadyCompleted);
+        }
+        
+        let circle = self.require_circle(task.circle_id);
+        if caller != task.assigned_to && caller != circle.owner && !self.workflow.helpers.get(&task_id).unwrap_or_default().contains(&caller) {
+            env.revert(Error::NotAssigneeOrOwner);
+        }
+        self.assert_not_suspended(task.circle_id, caller);
+        
+        let count = self.get_evidence_count(task_id);
+        if count >= MAX_EVIDENCE_PER_TASK {
+            env.revert(Error::TooManyEvidenceItems);
+        }
+
+        self.workflow.evidence.set(&(task_id, count), EvidenceItem {
+            content_hash,
+            kind,
+            added_by: caller,
+            added_at: env.get_block_time(),
+        });
+        self.workflow.evidence_count.set(&task_id, count + 1);
+        let retention = &mut self.config.retention;
+        let seq = retention.evidence_log_count.get(&task.circle_id).unwrap_or(0);
+        retention.evidence_log.set(&(task.circle_id, seq), (task_id, count));
+        retention.evidence_log_count.set(&task.circle_id, seq + 1);
+        self.log_activity(task.circle_id, caller, ActivityKind::EvidenceAdded, task_id);
+        env.emit_event(EvidenceAdded {
+            task_id,
+            circle_id: task.circle_id,
+            content_hash,
+            kind,
+            added_by: caller,
+            routes: self.notification_routes(task.circle_id),
+        });
+    }
+
+    /// Authorize or revoke an address that attests caregiver proximity (owner only)
+    pub fn set_presence_oracle(&mut self, circle_id: u64, oracle: Address, authorized: bool) {
+        let env = self.env();
+        let caller = env.caller();
+        let circle = self.require_circle(circle_id);
+        
+        if caller != circle.owner {
+            env.revert(Error::NotOwner);
+        }
+        self.assert_circle_active(circle_id);
+
+        self.workflow.presence.oracles.set(&(circle_id, oracle), authorized);
+        self.log_activity(circle_id, caller, ActivityKind::SettingsUpdated, circle_id);
+    }
+
+    /// Attest that the latest check-in happened near the task's location (circle oracles only)
+    pub fn attest_presence(&mut self, task_id: u64) {
+        let env = self.env();
+        let caller = env.caller();
+        let task = self.require_task(task_id);
+        
+        if !self.workflow.presence.oracles.get(&(task.circle_id, caller)).unwrap_or(false) {
+            env.revert(Error::NotPresenceOracle);
+        }
+        
+        let mut check_in = match self.workflow.presence.check_ins.get(&task_id) {
+            Some(check_in) => check_in,
+            None => env.revert(Error::NoCheckIn),
+        };
+
+        check_in.attested_by = Some(caller);
+        check_in.attested_at = env.get_block_time();
+        let caregiver = check_in.caregiver;
+        self.workflow.presence.check_ins.set(&task_id, check_in);
+        self.log_activity(task.circle_id, caller, ActivityKind::PresenceAttested, task_id);
+        env.emit_event(PresenceAttested {
+            task_id,
+            circle_id: task.circle_id,
+            caregiver,
+            oracle: caller,
+            routes: self.notification_routes(task.circle_id),
+        });
+    }
+
+    // ==================== Encrypted Envelopes ====================
+
+    /// Store client-encrypted bytes under the circle's current key epoch, optionally
+    /// for one of its tasks, and return the envelope id (members)
+    pub fn store_envelope(&mut self, circle_id: u64, task_id: Option<u64>, ciphertext: Bytes) -> u64 {
+        let env = self.env();
+        let caller = env.caller();
+        
+        if !self.members.is_member.get(&(circle_id, caller)).unwrap_or(false) {
+            env.revert(Error::NotAMember);
+        }
+        self.assert_not_suspended(circle_id, caller);
+        
+        if ciphertext.len() > MAX_CIPHERTEXT_LEN {
+            env.revert(Error::CiphertextTooLarge);
+        }
+        
+        if let Some(task_id) = task_id {
+            let task = self.require_task(task_id);
+            if task.circle_id != circle_id {
+                env.revert(Error::TaskNotInCircle);
+            }
+        }
+
+        let private = &mut self.config.private;
+        let live = private.live_envelopes.get(&circle_id).unwrap_or(0);
+        if live >= MAX_ENVELOPES_PER_CIRCLE {
+            env.revert(Error::TooManyEnvelopes);
+        }
+        private.live_envelopes.set(&circle_id, live + 1);
+        let envelope_id = private.envelope_count.get(&circle_id).unwrap_or(0) + 1;
+        private.envelope_count.set(&circle_id, envelope_id);
+        self.store_envelope_record(circle_id, EncryptedEnvelope {
+            id: envelope_id,
+            task_id,
+            ciphertext,
+            key_epoch: self.get_key_epoch(circle_id),
+            stored_by: caller,
+            stored_at: env.get_block_time(),
+        }, caller, false);
+        envelope_id
+    }
+
+    /// Replace an envelope's ciphertext, e.g. re-encrypted after a key rotation (author or owner)
+    pub fn replace_envelope(&mut self, circle_id: u64, envelope_id: u64, ciphertext: Bytes) {
+        let env = self.env();
+        let caller = env.caller();
+        
+        if ciphertext.len() > MAX_CIPHERTEXT_LEN {
+            env.revert(Error::CiphertextTooLarge);
+        }
+        
+        let mut envelope = self.envelope_for_author(circle_id, envelope_id, caller);
+        envelope.ciphertext = ciphertext;
+        envelope.key_epoch = self.get_key_epoch(circle_id);
+        envelope.stored_at = env.get_block_time();
+        self.store_envelope_record(circle_id, envelope, caller, false);
+    }
+
+    /// Delete an envelope, freeing its slot (author or owner)
+    pub fn delete_envelope(&mut self, circle_id: u64, envelope_id: u64) {
+        let caller = self.env().caller();
+        let envelope = self.envelope_for_author(circle_id, envelope_id, caller);
+        let private = &mut self.config.private;
+        let live = private.live_envelopes.get(&circle_id).unwrap_or(0);
+        private.live_envelopes.set(&circle_id, live.saturating_sub(1));
+        self.store_envelope_record(circle_id, envelope, caller, true);
+    }
+
+    /// Start a new key epoch after the circle's key changes, e.g. when a member
+    /// leaves; existing envelopes keep the epoch they were encrypted under (owner or guardian)
+    pub fn rotate_circle_key_epoch(&mut self, circle_id: u64) -> u64 {
+        let env = self.env();
+        let caller = env.caller();
+        self.assert_owner_or_guardian(circle_id, caller);
+
+        let key_epoch = self.get_key_epoch(circle_id) + 1;
+        self.config.private.key_epochs.set(&circle_id, key_epoch);
+        self.log_activity(circle_id, caller, ActivityKind::KeyEpochRotated, circle_id);
+        env.emit_event(KeyEpochRotated {
+            circle_id,
+            key_epoch,
+            rotated_by: caller,
+            routes: self.notification_routes(circle_id),
+        });
+        key_epoch
+    }
+
+    // ==================== Labels ====================
+
+    /// Add a free-form label to a task (circle members)
+    pub fn add_task_label(&mut self, task_id: u64, label: String) {
+        let env = self.env();
+        let caller = env.caller();
+
+        let task = self.require_task(task_id);
+        
+        if !self.members.is_member.get(&(task.circle_id, caller)).unwrap_or(false) {
+            env.revert(Error::NotAMember);
+        }
+        self.assert_not_suspended(task.circle_id, caller);
+        
+        if label.is_empty() || label.len() > MAX_LABEL_LEN {
+            env.revert(Error::InvalidLabel);
+        }
+        
+        let mut labels = self.task_index.task_labels.get(&task_id).unwrap_or_default();
+        if labels.contains(&label) {
+            env.revert(Error::LabelAlreadySet);
+        }
+        
+        if labels.len() >= MAX_LABELS_PER_TASK {
+            env.revert(Error::TooManyLabels);
+        }
+
+        let label_hash = env.hash(label.as_bytes());
+        self.index_label(task.circle_id, task_id, label_hash);
+
+        labels.push(label);
+        self.task_index.task_labels.set(&task_id, labels);
+        self.log_activity(task.circle_id, caller, ActivityKind::LabelAdded, task_id);
+    }
+
+    /// Remove a label from a task (circle members)
+    pub fn remove_task_label(&mut self, task_id: u64, label: String) {
+        let env = self.env();
+        let caller = env.caller();
+
+        let task = self.require_task(task_id);
+        
+        if !self.members.is_member.get(&(task.circle_id, caller)).unwrap_or(false) {
+            env.revert(Error::NotAMember);
+        }
+        self.assert_not_suspended(task.circle_id, caller);
+
+        let mut labels = self.task_index.task_labels.get(&task_id).unwrap_or_default();
+        let position = match labels.iter().position(|existing| *existing == label) {
+            Some(position) => position,
+            None => env.revert(Error::InvalidLabel),
+        };
+
+        labels.remove(position);
+        self.task_index.task_labels.set(&task_id, labels);
+        self.log_activity(task.circle_id, caller, ActivityKind::LabelRemoved, task_id);
+    }
+
+    // ==================== Shared Tasks ====================
+
+    /// Propose sharing a task with a secondary circle (owner of the task's circle).
+    /// The secondary circle's owner must accept before the link takes effect.
+    pub fn propose_task_link(&mut self, task_id: u64, secondary_circle_id: u64, shared_completion: bool) {
+        let env = self.env();
+        let task = self.require_task(task_id);
+        let circle = self.require_circle(task.circle_id);
+        
+        if env.caller() != circle.owner {
+            env.revert(Error::NotOwner);
+        }
+        self.assert_circle_active(task.circle_id);
+        self.assert_circle_active(secondary_circle_id);
+        
+        if secondary_circle_id == task.circle_id
+            || self.circles.get(&secondary_circle_id).is_none()
+            || self.community.sharing.secondary_circle.get(&task_id).is_some()
+        {
+            env.revert(Error::InvalidTaskLink);
+        }
+
+        self.community.sharing.pending_links.set(&task_id, (secondary_circle_id, shared_completion));
+        self.log_activity(task.circle_id, env.caller(), ActivityKind::TaskLinkProposed, task_id);
+    }
+
+    /// Accept a proposed task link (owner of the secondary circle)
+    pub fn accept_task_link(&mut self, task_id: u64) {
+        let env = self.env();
+        let (secondary_circle_id, shared_completion) = match self.community.sharing.pending_links.get(&task_id) {
+            Some(link) if link.0 != 0 => link,
+            _ => env.revert(Error::InvalidTaskLink),
+        };
+        let secondary = self.require_circle(secondary_circle_id);
+        
+        if env.caller() != secondary.owner {
+            env.revert(Error::NotOwner);
+        }
+        self.assert_circle_active(secondary_circle_id);
+        
+        let task = self.require_task(task_id);
+        self.assert_circle_active(task.circle_id);
+
+        // Index the task in the secondary circle as well
+        self.push_circle_task(secondary_circle_id, task_id);
+
+        self.community.sharing.secondary_circle.set(&task_id, secondary_circle_id);
+        self.community.sharing.shared_completion.set(&task_id, shared_completion);
+        self.community.sharing.pending_links.set(&task_id, (0, false));
+        self.log_activity(task.circle_id, env.caller(), ActivityKind::TaskLinked, task_id);
+        self.log_activity(secondary_circle_id, env.caller(), ActivityKind::TaskLinked, task_id);
+        self.log_audit(secondary_circle_id, env.caller(), AuditAction::TaskLinkAccepted, None, task_id);
+
+        env.emit_event(TaskLinked {
+            task_id,
+            circle_id: task.circle_id,
+            secondary_circle_id,
+            shared_completion,
+            routes: self.notification_routes(task.circle_id),
+        });
+    }
+
+    // ==================== SLA ====================
+
+    /// Set completion targets in seconds indexed by priority, 0 = no target (owner only)
+    pub fn set_sla_targets(&mut self, circle_id: u64, targets: Vec<u64>) {
+        let env = self.env();
+        let circle = self.require_circle(circle_id);
+        
+        if env.caller() != circle.owner {
+            env.revert(Error::NotOwner);
+        }
+        self.assert_circle_active(circle_id);
+        
+        if targets.len() > MAX_PRIORITY as usize + 1 {
+            env.revert(Error::TooManySlaTargets);
+        }
+
+        self.scheduling.sla.targets.set(&circle_id, targets);
+        self.log_activity(circle_id, env.caller(), ActivityKind::SlaTargetsUpdated, circle_id);
+        self.log_audit(circle_id, env.caller(), AuditAction::SlaTargetsUpdated, None, circle_id);
+    }
+
+    // ==================== Volunteers ====================
+
+    /// Register the caller as a community volunteer
+    pub fn register_volunteer(&mut self) {
+        let caller = self.env().caller();
+        self.community.volunteers.volunteers.set(&caller, true);
+    }
+
+    /// Remove the caller from the volunteer registry
+    pub fn unregister_volunteer(&mut self) {
+        let caller = self.env().caller();
+        self.community.volunteers.volunteers.set(&caller, false);
+    }
+
+    /// Flag or unflag an open task for the public volunteer board (owner only)
+    pub fn set_open_to_volunteers(&mut self, task_id: u64, open: bool) {
+        let env = self.env();
+        let task = self.require_task(task_id);
+        let circle = self.require_circle(task.circle_id);
+        
+        if env.caller() != circle.owner {
+            env.revert(Error::NotOwner);
+        }
+        self.assert_circle_active(task.circle_id);
+        
+        if task.completed {
+            env.revert(Error::AlreadyCompleted);
+        }
+
+        // A task keeps its board slot when unflagged, so reopening reuses it
+        if open && !self.community.volunteers.listed.get(&task_id).unwrap_or(false) {
+            let board_index = self.community.volunteers.board_count.get_or_default();
+            self.community.volunteers.board.set(&board_index, task_id);
+            self.community.volunteers.board_count.set(board_index + 1);
+            self.community.volunteers.listed.set(&task_id, true);
+        }
+        self.community.volunteers.open_tasks.set(&task_id, open);
+        self.log_activity(task.circle_id, env.caller(), ActivityKind::VolunteerListingChanged, task_id);
+    }
+
+    /// Apply as a registered volunteer for a task on the board
+    pub fn apply_for_task(&mut self, task_id: u64) {
+        let env = self.env();
+        let caller = env.caller();
+
+        if !self.community.volunteers.volunteers.get(&caller).unwrap_or(false) {
+            env.revert(Error::NotAVolunteer);
+        }
+        
+        let task = self.require_task(task_id);
+        
+        if task.completed || !self.community.volunteers.open_tasks.get(&task_id).unwrap_or(false) {
+            env.revert(Error::NotOpenToVolunteers);
+        }
+        
+        if self.community.volunteers.has_applied.get(&(task_id, caller)).unwrap_or(false) {
+            env.revert(Error::AlreadyApplied);
+        }
+        
+        let applicant_count = self.community.volunteers.applicant_count.get(&task_id).unwrap_or(0);
+        if applicant_count >= MAX_APPLICANTS_PER_TASK {
+            env.revert(Error::TooManyApplicants);
+        }
+
+        self.community.volunteers.applicants.set(&(task_id, applicant_count), caller);
+        self.community.volunteers.applicant_count.set(&task_id, applicant_count + 1);
+        self.community.volunteers.has_applied.set(&(task_id, caller), true);
+        self.log_activity(task.circle_id, caller, ActivityKind::VolunteerApplied, task_id);
+
+        env.emit_event(VolunteerApplied {
+            task_id,
+            circle_id: task.circle_id,
+            volunteer: caller,
+            routes: self.notification_routes(task.circle_id),
+        });
+    }
+
+    /// Accept an applicant; the task is reassigned to them and only they can complete it
+    pub fn accept_volunteer(&mut self, task_id: u64, volunteer: Address) {
+        let env = self.env();
+        let caller = env.caller();
+
+        let task = self.require_task(task_id);
+        let circle = self.require_circle(task.circle_id);
+        
+        if caller != circle.owner {
+            env.revert(Error::NotOwner);
+        }
+        
+        if task.completed || !self.community.volunteers.open_tasks.get(&task_id).unwrap_or(false) {
+            env.revert(Error::NotOpenToVolunteers);
+        }
+        
+        if !self.community.volunteers.has_applied.get(&(task_id, volunteer)).unwrap_or(false) {
+            env.revert(Error::NotAnApplicant);
+        }
+
+        let circle_id = task.circle_id;
+        self.reassign_task(task, volunteer);
+        self.community.volunteers.open_tasks.set(&task_id, false);
+        self.log_activity(circle_id, caller, ActivityKind::VolunteerAccepted, task_id);
+        self.log_audit(circle_id, caller, AuditAction::VolunteerAccepted, Some(volunteer), task_id);
+
+        env.emit_event(VolunteerAccepted {
+            task_id,
+            circle_id,
+            volunteer,
+            accepted_by: caller,
+            routes: self.notification_routes(circle_id),
+        });
+    }
+
+    // ==================== Availability ====================
+
+    /// Replace the caller's availability windows in a circle.
+    /// Members without declared windows are treated as always available.
+    pub fn set_availability(&mut self, circle_id: u64, windows: Vec<AvailabilityWindow>) {
+        let env = self.env();
+        let caller = env.caller();
+
+        if !self.members.is_member.get(&(circle_id, caller)).unwrap_or(false) {
+            env.revert(Error::NotAMember);
+        }
+        self.assert_not_suspended(circle_id, caller);
+        
+        if windows.len() > MAX_AVAILABILITY_WINDOWS {
+            env.revert(Error::TooManyWindows);
+        }
+        
+        if windows.iter().any(|window| window.start >= window.end) {
+            env.revert(Error::InvalidWindow);
+        }
+
+        self.members.availability.set(&(circle_id, caller), windows);
+        self.log_activity(circle_id, caller, ActivityKind::AvailabilityUpdated, circle_id);
+    }
+
+    // ==================== Care Feedback ====================
+
+    /// Rate an ended week, month or care episode (care recipient or guardian)
+    ///
+    /// Scores are credited to the circle and to every member who completed a
+    /// task in the circle during that period, up to 50 members per call;
+    /// `credit_survey` continues from there.
+    pub fn submit_survey(
+        &mut self,
+        circle_id: u64,
+        kind: SurveyPeriod,
+        period: u64,
+        scores: Bytes,
+        comment_hash: [u8; 32],
+    ) {
+        let env = self.env();
+        let caller = env.caller();
+        let timestamp = env.get_block_time();
+
+        self.assert_recipient_or_guardian(circle_id, caller);
+        
+        let settings = self.get_settings(circle_id);
+        match kind {
+            SurveyPeriod::Week if period >= local_week(&settings, timestamp) => env.revert(Error::WeekNotOver),
+            SurveyPeriod::Month if period >= local_month(&settings, timestamp) => env.revert(Error::PeriodNotOver),
+            SurveyPeriod::Episode => {
+                let Some(episode) = self.config.episodes.get(&(circle_id, period)).flatten() else {
+                    env.revert(Error::EpisodeNotFound);
+                };
+                if episode.ends_at == 0 || episode.ends_at > timestamp {
+                    env.revert(Error::PeriodNotOver);
+                }
+            }
+            _ => {}
+        }
+        
+        let valid_score = |score: &u8| (MIN_SURVEY_SCORE..=MAX_SURVEY_SCORE).contains(score);
+        if scores.is_empty() || scores.len() > MAX_SURVEY_SCORES || !scores.iter().all(valid_score) {
+            env.revert(Error::InvalidSurveyScores);
+        }
+        
+        if self.feedback.surveys.get(&(circle_id, kind, period)).is_some() {
+            env.revert(Error::SurveyAlreadySubmitted);
+        }
+
+        let sum: u64 = scores.iter().map(|score| *score as u64).sum();
+        let (total, count) = self.feedback.circle_scores.get(&circle_id).unwrap_or_default();
+        self.feedback.circle_scores.set(&circle_id, (total + sum, count + scores.len() as u64));
+
+        let survey = Survey {
+            submitted_by: caller,
+            scores,
+            comment_hash,
+            submitted_at: timestamp,
+        };
+        self.feedback.surveys.set(&(circle_id, kind, period), survey);
+        self.credit_survey(circle_id, kind, period, MAX_SURVEY_CREDITS_PER_CALL);
+        self.log_activity(circle_id, caller, ActivityKind::SurveySubmitted, period);
+    }
+
+    /// Credit a submitted survey's scores to the next members who completed
+    /// tasks in its period, examining up to 50 from where the last call
+    /// stopped. Permissionless keeper entry point. Returns the number of
+    /// members examined, 0 once every member has been or if there is no survey.
+    pub fn credit_survey(&mut self, circle_id: u64, kind: SurveyPeriod, period: u64, max_items: u64) -> u64 {
+        let Some(survey) = self.feedback.surveys.get(&(circle_id, kind, period)) else {
+            return 0;
+        };
+        let sum: u64 = survey.scores.iter().map(|score| *score as u64).sum();
+        let count = survey.scores.len() as u64;
+
+        let key = (circle_id, kind, period);
+        let cursor = self.feedback.survey_credit_cursors.get(&key).unwrap_or(0);
+        let member_count = self.members.circle_member_count.get(&circle_id).unwrap_or(0);
+        let end = member_count.min(cursor.saturating_add(max_items.min(MAX_SURVEY_CREDITS_PER_CALL)));
+        for i in cursor..end {
+            let Some(member) = self.members.circle_members.get(&(circle_id, i)) else {
+                continue;
+            };
+            let weekly = &self.stats.weekly;
+            let completions = match kind {
+                SurveyPeriod::Week => weekly.member_completions.get(&(circle_id, period, member)),
+                SurveyPeriod::Month => weekly.member_month_completions.get(&(circle_id, period, member)),
+                SurveyPeriod::Episode => weekly.member_episode_completions.get(&(circle_id, period, member)),
+            };
+            if completions.unwrap_or(0) == 0 {
+                continue;
+            }
+            let (total, n) = self.feedback.caregiver_scores.get(&member).unwrap_or_default();
+            self.feedback.caregiver_scores.set(&member, (total + sum, n + count));
+            self.add_weighted_scores(member, sum, count);
+        }
+        if end > cursor {
+            self.feedback.survey_credit_cursors.set(&key, end);
+        }
+        end.saturating_sub(cursor)
+    }
+
+    /// Add or update an allergy or condition of the care recipient (owner or guardian)
+    pub fn set_health_record(
+        &mut self,
+        circle_id: u64,
+        substance_hash: [u8; 32],
+        kind: HealthRecordKind,
+        severity: Severity,
+        notes_hash: [u8; 32],
+    ) {
+        let env = self.env();
+        let caller = env.caller();
+        self.assert_owner_or_guardian(circle_id, caller);
+
+        let key = (circle_id, substance_hash);
+        if self.feedback.health_records.get(&key).flatten().is_none() {
+            let count = self.feedback.health_record_count.get(&circle_id).unwrap_or(0);
+            if count >= MAX_HEALTH_RECORDS {
+                env.revert(Error::TooManyHealthRecords);
+            }
+            self.feedback.health_record_list.set(&(circle_id, count), substance_hash);
+            self.feedback.health_record_slot.set(&key, count);
+            self.feedback.health_record_count.set(&circle_id, count + 1);
+        }
+
+        self.feedback.health_records.set(&key, Some(HealthRecord {
+            substance_hash,
+            kind,
+            severity,
+            notes_hash,
+            updated_by: caller,
+            updated_at: env.get_block_time(),
+        }));
+        self.log_audit(circle_id, caller, AuditAction::HealthRecordSet, None, circle_id);
+    }
+
+    /// Remove an allergy or condition of the care recipient (owner or guardian)
+    pub fn remove_health_record(&mut self, circle_id: u64, substance_hash: [u8; 32]) {
+        let env = self.env();
+        let caller = env.caller();
+        self.assert_owner_or_guardian(circle_id, caller);
+
+        let key = (circle_id, substance_hash);
+        if self.feedback.health_records.get(&key).flatten().is_none() {
+            env.revert(Error::HealthRecordNotFound);
+        }
+        self.feedback.health_records.set(&key, None);
+
+        let count = self.feedback.health_record_count.get(&circle_id).unwrap_or(0);
+        let slot = self.feedback.health_record_slot.get(&key).unwrap_or(0);
+        let last = count.saturating_sub(1);
+        if slot != last {
+            if let Some(moved) = self.feedback.health_record_list.get(&(circle_id, last)) {
+                self.feedback.health_record_list.set(&(circle_id, slot), moved);
+                self.feedback.health_record_slot.set(&(circle_id, moved), slot);
+            }
+        }
+        self.feedback.health_record_count.set(&circle_id, last);
+        self.log_audit(circle_id, caller, AuditAction::HealthRecordRemoved, None, circle_id);
+    }
+
+    /// Add a medication the care recipient takes and return its index (owner or guardian)
+    pub fn add_medication(&mut self, circle_id: u64, substance_hash: [u8; 32], dose_hash: [u8; 32]) -> u64 {
+        let env = self.env();
+        let caller = env.caller();
+        self.assert_owner_or_guardian(circle_id, caller);
+
+        let journal = &mut self.feedback.journal;
+        let medication_id = journal.medication_count.get(&circle_id).unwrap_or(0);
+        journal.medications.set(&(circle_id, medication_id), Medication {
+            substance_hash,
+            dose_hash,
+            added_by: caller,
+            added_at: env.get_block_time(),
+            stopped_at: 0,
+        });
+        journal.medication_count.set(&circle_id, medication_id + 1);
+        self.log_audit(circle_id, caller, AuditAction::MedicationAdded, None, circle_id);
+
+        // Warn, without refusing the medication: the prescriber may know better
+        let allergy = self.feedback.health_records.get(&(circle_id, substance_hash)).flatten()
+            .filter(|record| record.kind == HealthRecordKind::Allergy);
+        if let Some(allergy) = allergy {
+            env.emit_event(AllergyConflict {
+                circle_id,
+                medication_id,
+                substance_hash,
+                severity: allergy.severity,
+                added_by: caller,
+                routes: self.notification_routes(circle_id),
+            });
+        }
+        medication_id
+    }
+
+    /// Mark a medication as no longer taken (owner or guardian)
+    pub fn stop_medication(&mut self, circle_id: u64, medication_id: u64) {
+        let env = self.env();
+        let caller = env.caller();
+        self.assert_owner_or_guardian(circle_id, caller);
+
+        let key = (circle_id, medication_id);
+        let Some(mut medication) = self.feedback.journal.medications.get(&key) else {
+            env.revert(Error::MedicationNotFound);
+        };
+        if medication.stopped_at != 0 {
+            env.revert(Error::MedicationStopped);
+        }
+        medication.stopped_at = env.get_block_time();
+        self.feedback.journal.medications.set(&key, medication);
+        self.log_audit(circle_id, caller, AuditAction::MedicationStopped, None, circle_id);
+    }
+
+    /// Post how the care recipient is doing to the circle's status board (members)
+    pub fn update_status(&mut self, circle_id: u64, status_code: u32, note_hash: [u8; 32]) {
+        let env = self.env();
+        let caller = env.caller();
+        
+        if !self.members.is_member.get(&(circle_id, caller)).unwrap_or(false) {
+            env.revert(Error::NotAMember);
+        }
+        self.assert_not_suspended(circle_id, caller);
+
+        let journal = &mut self.feedback.journal;
+        let seq = journal.status_count.get(&circle_id).unwrap_or(0);
+        journal.statuses.set(&(circle_id, seq % STATUS_HISTORY_SIZE), StatusUpdate {
+            status_code,
+            note_hash,
+            updated_by: caller,
+            updated_at: env.get_block_time(),
+        });
+        journal.status_count.set(&circle_id, seq + 1);
+
+        self.log_activity(circle_id, caller, ActivityKind::StatusUpdated, circle_id);
+        env.emit_event(StatusUpdated {
+            circle_id,
+            updated_by: caller,
+            routes: self.notification_routes(circle_id),
+        });
+    }
+
+    /// Log an observed symptom or behavior of the care recipient (members)
+    pub fn log_symptom(&mut self, circle_id: u64, symptom_hash: [u8; 32], severity: u8, notes_hash: [u8; 32]) {
+        let env = self.env();
+        let caller = env.caller();
+        let timestamp = env.get_block_time();
+        
+        if !self.members.is_member.get(&(circle_id, caller)).unwrap_or(false) {
+            env.revert(Error::NotAMember);
+        }
+        self.assert_not_suspended(circle_id, caller);
+        
+        if severity > MAX_SYMPTOM_SEVERITY {
+            env.revert(Error::SeverityOutOfRange);
+        }
+
+        let journal = &mut self.feedback.journal;
+        let index = journal.symptom_count.get(&circle_id).unwrap_or(0);
+        journal.symptoms.set(&(circle_id, index), SymptomEntry {
+            symptom_hash,
+            severity,
+            notes_hash,
+            logged_by: caller,
+            logged_at: timestamp,
+        });
+        journal.symptom_count.set(&circle_id, index + 1);
+
+        let day = self.day_of(circle_id, timestamp);
+        let journal = &mut self.feedback.journal;
+        let key = (circle_id, symptom_hash, day);
+        let (entries, severity_sum) = journal.symptom_days.get(&key).unwrap_or((0, 0));
+        journal.symptom_days.set(&key, (entries + 1, severity_sum + severity as u64));
+        self.log_activity(circle_id, caller, ActivityKind::SymptomLogged, circle_id);
+    }
+
+    /// Log a meal served to the care recipient, optionally linked to its preparation task (members)
+    pub fn log_meal(
+        &mut self,
+        circle_id: u64,
+        task_id: Option<u64>,
+        meal_type: MealType,
+        consumed_pct: u8,
+        notes_hash: [u8; 32],
+    ) {
+        let env = self.env();
+        let caller = env.caller();
+        let timestamp = env.get_block_time();
+        
+        if !self.members.is_member.get(&(circle_id, caller)).unwrap_or(false) {
+            env.revert(Error::NotAMember);
+        }
+        self.assert_not_suspended(circle_id, caller);
+        
+        if consumed_pct > 100 {
+            env.revert(Error::InvalidConsumedPercent);
+        }
+        
+        if let Some(task_id) = task_id {
+            let task = self.require_task(task_id);
+            if task.circle_id != circle_id {
+                env.revert(Error::TaskNotInCircle);
+            }
+        }
+
+        let journal = &mut self.feedback.journal;
+        let index = journal.meal_count.get(&circle_id).unwrap_or(0);
+        journal.meals.set(&(circle_id, index), MealEntry {
+            task_id,
+            meal_type,
+            consumed_pct,
+            notes_hash,
+            logged_by: caller,
+            logged_at: timestamp,
+        });
+        journal.meal_count.set(&circle_id, index + 1);
+
+        let day = self.day_of(circle_id, timestamp);
+        let journal = &mut self.feedback.journal;
+        let (meals, consumed_sum) = journal.meal_days.get(&(circle_id, day)).unwrap_or((0, 0));
+        journal.meal_days.set(&(circle_id, day), (meals + 1, consumed_sum + consumed_pct as u64));
+        self.log_activity(circle_id, caller, ActivityKind::MealLogged, circle_id);
+    }
+
+    /// Report an incident involving the care recipient and queue a hook for
+    /// the circle's extensions (members); returns its index in the incident log
+    pub fn report_incident(&mut self, circle_id: u64, kind_code: u32, details_hash: [u8; 32]) -> u64 {
+        let env = self.env();
+        let caller = env.caller();
+        
+        if !self.members.is_member.get(&(circle_id, caller)).unwrap_or(false) {
+            env.revert(Error::NotAMember);
+        }
+        self.assert_not_suspended(circle_id, caller);
+
+        let journal = &mut self.feedback.journal;
+        let incident_id = journal.incident_count.get(&circle_id).unwrap_or(0);
+        journal.incidents.set(&(circle_id, incident_id), IncidentReport {
+            kind_code,
+            details_hash,
+            reported_by: caller,
+            reported_at: env.get_block_time(),
+        });
+        journal.incident_count.set(&circle_id, incident_id + 1);
+
+        self.log_activity(circle_id, caller, ActivityKind::IncidentReported, circle_id);
+        self.queue_hook(circle_id, HookKind::IncidentReported, incident_id);
+        env.emit_event(IncidentReported {
+            circle_id,
+            incident_id,
+            kind_code,
+            reported_by: caller,
+            routes: self.notification_routes(circle_id),
+        });
+        incident_id
+    }
+
+    /// Grant or revoke an address's read access to a data scope (care recipient or guardian)
+    pub fn set_consent(&mut self, circle_id: u64, scope: ConsentScope, grantee: Address, granted: bool) {
+        let caller = self.env().caller();
+        self.assert_recipient_or_guardian(circle_id, caller);
+
+        self.feedback.address_consents.set(&(circle_id, scope, grantee), granted);
+        let action = if granted { AuditAction::ConsentGranted } else { AuditAction::ConsentRevoked };
+        self.log_audit(circle_id, caller, action, Some(grantee), circle_id);
+    }
+
+    /// Grant or revoke every member's read access to a data scope (care recipient or guardian)
+    pub fn set_member_consent(&mut self, circle_id: u64, scope: ConsentScope, granted: bool) {
+        let caller = self.env().caller();
+        self.assert_recipient_or_guardian(circle_id, caller);
+
+        self.feedback.member_consents.set(&(circle_id, scope), granted);
+        let action = if granted { AuditAction::ConsentGranted } else { AuditAction::ConsentRevoked };
+        self.log_audit(circle_id, caller, action, None, circle_id);
+    }
+
+    /// Let an auditor read a data scope until `expires_at`, at most 90 days
+    /// ahead (care recipient or guardian)
+    pub fn grant_auditor_access(&mut self, circle_id: u64, auditor: Address, scope: ConsentScope, expires_at: u64) {
+        let env = self.env();
+        let caller = env.caller();
+        self.assert_recipient_or_guardian(circle_id, caller);
+        
+        let now = env.get_block_time();
+        if expires_at <= now || expires_at - now > MAX_AUDITOR_GRANT_MS {
+            env.revert(Error::InvalidAuditorExpiry);
+        }
+
+        self.set_auditor_access(circle_id, auditor, scope, expires_at, caller);
+    }
+
+    /// End an auditor's access to a data scope early (care recipient or guardian)
+    pub fn revoke_auditor_access(&mut self, circle_id: u64, auditor: Address, scope: ConsentScope) {
+        let caller = self.env().caller();
+        self.assert_recipient_or_guardian(circle_id, caller);
+        self.set_auditor_access(circle_id, auditor, scope, 0, caller);
+    }
+
+    /// Publish or hide the caller's work history from a circle
+    pub fn set_public_history(&mut self, circle_id: u64, public: bool) {
+        let env = self.env();
+        let caller = env.caller();
+        let is_member = self.members.is_member.get(&(circle_id, caller)).unwrap_or(false);
+        
+        if public && !is_member {
+            env.revert(Error::NotAMember);
+        }
+
+        self.history.public_history.set(&(caller, circle_id), public);
+        // Former members may still hide their history; only members show in the feed
+        if is_member {
+            self.log_activity(circle_id, caller, ActivityKind::ProfileUpdated, circle_id);
+        }
+    }
+
+    // ==================== Supplies ====================
+
+    /// Start tracking a supply and return its id within the circle (owner only)
+    pub fn add_supply(&mut self, circle_id: u64, name: String, quantity: u64, low_threshold: u64) -> u64 {
+        let env = self.env();
+        let caller = env.caller();
+        let circle = self.require_circle(circle_id);
+        
+        if caller != circle.owner {
+            env.revert(Error::NotOwner);
+        }
+        self.assert_circle_active(circle_id);
+        
+        if name.len() > MAX_TITLE_LEN {
+            env.revert(Error::TitleTooLong);
+        }
+
+        let logistics = &mut self.config.logistics;
+        let supply_id = logistics.supply_count.get(&circle_id).unwrap_or(0) + 1;
+        logistics.supply_count.set(&circle_id, supply_id);
+        self.store_supply(circle_id, Supply {
+            id: supply_id,
+            name,
+            quantity,
+            low_threshold,
+            updated_at: env.get_block_time(),
+        }, u64::MAX);
+        self.log_activity(circle_id, caller, ActivityKind::SupplyAdjusted, circle_id);
+        supply_id
+    }
+
+    /// Change the stock level at which a supply counts as low (owner only)
+    pub fn set_supply_threshold(&mut self, circle_id: u64, supply_id: u64, low_threshold: u64) {
+        let env = self.env();
+        let caller = env.caller();
+        let circle = self.require_circle(circle_id);
+        
+        if caller != circle.owner {
+            env.revert(Error::NotOwner);
+        }
+
+        let Some(mut supply) = self.get_supply(circle_id, supply_id) else {
+            env.revert(Error::SupplyNotFound);
+        };
+        let previous = supply.quantity;
+        supply.low_threshold = low_threshold;
+        self.store_supply(circle_id, supply, previous);
+        self.log_activity(circle_id, caller, ActivityKind::SupplyAdjusted, circle_id);
+    }
+
+    /// Record supplies used (negative) or bought (positive) (members)
+    pub fn adjust_supply(&mut self, circle_id: u64, supply_id: u64, delta: i64) {
+        let env = self.env();
+        let caller = env.caller();
+        
+        if !self.members.is_member.get(&(circle_id, caller)).unwrap_or(false) {
+            env.revert(Error::NotAMember);
+        }
+        self.assert_not_suspended(circle_id, caller);
+
+        let Some(mut supply) = self.get_supply(circle_id, supply_id) else {
+            env.revert(Error::SupplyNotFound);
+        };
+        let previous = supply.quantity;
+        supply.quantity = match previous.checked_add_signed(delta) {
+            Some(quantity) => quantity,
+            None if delta < 0 => env.revert(Error::InsufficientSupply),
+            None => u64::MAX,
+        };
+        supply.updated_at = env.get_block_time();
+        self.store_supply(circle_id, supply, previous);
+        self.log_activity(circle_id, caller, ActivityKind::SupplyAdjusted, circle_id);
+    }
+
+    /// Make completing an open task add `quantity` of a supply, or stop it with `None` (creator or owner)
+    pub fn set_restock_task(&mut self, task_id: u64, restock: Option<(u64, u64)>) {
+        let env = self.env();
+        let caller = env.caller();
+
+        let task = self.require_task(task_id);
+        
+        if task.completed {
+            env.revert(Error::AlreadyCompleted);
+        }
+        
+        let circle = self.require_circle(task.circle_id);
+        if caller != task.created_by && caller != circle.owner {
+            env.revert(Error::NotCreatorOrOwner);
+        }
+        self.assert_not_suspended(task.circle_id, caller);
+        
+        if let Some((supply_id, _)) = restock {
+            if self.get_supply(task.circle_id, supply_id).is_none() {
+                env.revert(Error::SupplyNotFound);
+            }
+        }
+
+        self.config.logistics.restocks.set(&task_id, restock);
+        self.log_activity(task.circle_id, caller, ActivityKind::RestockTaskSet, task_id);
+    }
+
+    // ==================== Transport ====================
+
+    /// Log a trip driven for one of the circle's tasks (members)
+    pub fn log_trip(&mut self, circle_id: u64, task_id: u64, distance_m: u64, purpose_hash: [u8; 32]) {
+        let env = self.env();
+        let caller = env.caller();
+        let timestamp = env.get_block_time();
+        
+        if !self.members.is_member.get(&(circle_id, caller)).unwrap_or(false) {
+            env.revert(Error::NotAMember);
+        }
+        self.assert_not_suspended(circle_id, caller);
+        
+        let task = self.require_task(task_id);
+        if task.circle_id != circle_id {
+            env.revert(Error::TaskNotInCircle);
+        }
+
+        let month = local_month(&self.get_settings(circle_id), timestamp);
+        let logistics = &mut self.config.logistics;
+        let index = logistics.trip_count.get(&circle_id).unwrap_or(0);
+        logistics.trips.set(&(circle_id, index), Trip {
+            task_id,
+            driver: caller,
+            distance_m,
+            purpose_hash,
+            logged_at: timestamp,
+        });
+        logistics.trip_count.set(&circle_id, index + 1);
+
+        let key = (circle_id, caller, month);
+        let (driven, claimed) = logistics.mileage.get(&key).unwrap_or((0, 0));
+        logistics.mileage.set(&key, (driven.saturating_add(distance_m), claimed));
+
+        self.log_activity(circle_id, caller, ActivityKind::TripLogged, task_id);
+        env.emit_event(TripLogged {
+            circle_id,
+            task_id,
+            driver: caller,
+            distance_m,
+            routes: self.notification_routes(circle_id),
+        });
+    }
+
+    /// Turn the caller's unclaimed mileage for a local month into a
+    /// reimbursement request at the circle's current rate (members)
+    pub fn request_reimbursement(&mut self, circle_id: u64, month: u64) -> u64 {
+        self.assert_feature_enabled(Feature::Reimbursements);
+        let env = self.env();
+        let caller = env.caller();
+        
+        if !self.members.is_member.get(&(circle_id, caller)).unwrap_or(false) {
+            env.revert(Error::NotAMember);
+        }
+        self.assert_not_suspended(circle_id, caller);
+        
+        let rate = self.get_settings(circle_id).mileage_rate;
+        if rate == 0 {
+            env.revert(Error::ReimbursementDisabled);
+        }
+        
+        let key = (circle_id, caller, month);
+        let (driven, claimed) = self.config.logistics.mileage.get(&key).unwrap_or((0, 0));
+        let distance_m = driven - claimed;
+        if distance_m == 0 {
+            env.revert(Error::NothingToReimburse);
+        }
+
+        let logistics = &mut self.config.logistics;
+        logistics.mileage.set(&key, (driven, driven));
+        let request_id = logistics.reimbursement_count.get(&circle_id).unwrap_or(0) + 1;
+        logistics.reimbursement_count.set(&circle_id, request_id);
+        self.store_reimbursement(circle_id, ReimbursementRequest {
+            id: request_id,
+            member: caller,
+            month,
+            distance_m,
+            rate,
+            amount: distance_m.saturating_mul(rate) / METRES_PER_KM,
+            requested_at: env.get_block_time(),
+            status: ReimbursementStatus::Pending,
+        }, caller);
+        request_id
+    }
+
+    /// Approve or reject a pending reimbursement request; settlement happens off-chain (owner only)
+    pub fn resolve_reimbursement(&mut self, circle_id: u64, request_id: u64, approved: bool) {
+        self.assert_feature_enabled(Feature::Reimbursements);
+        let env = self.env();
+        let caller = env.caller();
+        let circle = self.require_circle(circle_id);
+        
+        if caller != circle.owner {
+            env.revert(Error::NotOwner);
+        }
+
+        let Some(mut request) = self.config.logistics.reimbursements.get(&(circle_id, request_id)) else {
+            env.revert(Error::ReimbursementNotFound);
+        };
+        if request.status != ReimbursementStatus::Pending {
+            env.revert(Error::ReimbursementResolved);
+        }
+
+        request.status = if approved { ReimbursementStatus::Approved } else { ReimbursementStatus::Rejected };
+        self.store_reimbursement(circle_id, request, caller);
+    }
+
+    // ==================== Agencies ====================
+
+    /// Hand the contract admin role to another address (admin only)
+    pub fn transfer_admin(&mut self, new_admin: Address) {
+        self.assert_admin();
+        self.community.admin.set(new_admin);
+    }
+
+    /// Set the half-life in seconds of survey scores in caregiver reputation, 0 for no decay (admin only)
+    pub fn set_reputation_half_life(&mut self, half_life_secs: u64) {
+        self.assert_admin();
+        self.community.reputation_half_life_secs.set(half_life_secs);
+    }
+
+    /// Verify or unverify an agency address (admin only)
+    pub fn set_agency_verified(&mut self, agency: Address, verified: bool) {
+        self.assert_admin();
+        self.community.agencies.verified.set(&agency, verified);
+    }
+
+    /// Register a caregiver address operated by the calling verified agency
+    pub fn register_caregiver(&mut self, caregiver: Address) {
+        let env = self.env();
+        let agency = env.caller();
+        let agencies = &mut self.community.agencies;
+        
+        if !agencies.verified.get(&agency).unwrap_or(false) {
+            env.revert(Error::AgencyNotVerified);
+        }
+        
+        if agencies.agency_of.get(&caregiver).flatten().is_some() {
+            env.revert(Error::CaregiverHasAgency);
+        }
+
+        if !agencies.listed.get(&(agency, caregiver)).unwrap_or(false) {
+            let count = agencies.caregiver_count.get(&agency).unwrap_or(0);
+            if count >= MAX_AGENCY_CAREGIVERS {
+                env.revert(Error::TooManyAgencyCaregivers);
+            }
+            agencies.caregivers.set(&(agency, count), caregiver);
+            agencies.caregiver_count.set(&agency, count + 1);
+            agencies.listed.set(&(agency, caregiver), true);
+        }
+        agencies.agency_of.set(&caregiver, Some(agency));
+    }
+
+    /// Stop operating a caregiver address (its agency only).
+    /// Circles drop it the next time their owner calls `grant_agency`.
+    pub fn deregister_caregiver(&mut self, caregiver: Address) {
+        let env = self.env();
+        let agency = env.caller();
+        
+        if self.community.agencies.agency_of.get(&caregiver).flatten() != Some(agency) {
+            env.revert(Error::NotAgencyCaregiver);
+        }
+
+        self.community.agencies.agency_of.set(&caregiver, None);
+    }
+
+    /// Grant a verified agency's role in a circle, or resync it (owner only).
+    /// Adds the agency's active caregivers as members and suspends members it
+    /// has deregistered. Returns the number of caregivers added.
+    pub fn grant_agency(&mut self, circle_id: u64, agency: Address) -> u64 {
+        let env = self.env();
+        let caller = env.caller();
+        let circle = self.require_circle(circle_id);
+        
+        if caller != circle.owner {
+            env.revert(Error::NotOwner);
+        }
+        
+        if !self.community.agencies.verified.get(&agency).unwrap_or(false) {
+            env.revert(Error::AgencyNotVerified);
+        }
+
+        self.community.agencies.granted.set(&(circle_id, agency), true);
+        let mut added = 0;
+        let mut suspended = 0;
+        for caregiver in self.listed_caregivers(agency) {
+            let active = self.community.agencies.agency_of.get(&caregiver).flatten() == Some(agency);
+            let is_member = self.members.is_member.get(&(circle_id, caregiver)).unwrap_or(false);
+            if active && !is_member {
+                self.insert_member(circle_id, caregiver, caller);
+                self.community.agencies.member_agency.set(&(circle_id, caregiver), Some(agency));
+                added += 1;
+            } else if !active && self.suspend_agency_member(circle_id, agency, caregiver) {
+                suspended += 1;
+            }
+        }
+
+        self.log_audit(circle_id, caller, AuditAction::AgencyGranted, Some(agency), circle_id);
+        env.emit_event(AgencyRoleChanged {
+            circle_id,
+            agency,
+            granted: true,
+            changed_by: caller,
+            members_affected: added + suspended,
+            routes: self.notification_routes(circle_id),
+        });
+        added
+    }
+
+    /// Revoke an agency's role and suspend the members it brought in (owner only)
+    pub fn revoke_agency(&mut self, circle_id: u64, agency: Address) {
+        let env = self.env();
+        let caller = env.caller();
+        let circle = self.require_circle(circle_id);
+        
+        if caller != circle.owner {
+            env.revert(Error::NotOwner);
+        }
+
+        self.community.agencies.granted.set(&(circle_id, agency), false);
+        let mut suspended = 0;
+        for caregiver in self.listed_caregivers(agency) {
+            if self.suspend_agency_member(circle_id, agency, caregiver) {
+                suspended += 1;
+            }
+        }
+
+        self.log_audit(circle_id, caller, AuditAction::AgencyRevoked, Some(agency), circle_id);
+        env.emit_event(AgencyRoleChanged {
+            circle_id,
+            agency,
+            granted: false,
+            changed_by: caller,
+            members_affected: suspended,
+            routes: self.notification_routes(circle_id),
+        });
+    }
+
+    // ==================== Announcements ====================
+
+    /// Post a platform-wide notice, e.g. a migration or policy change (admin only)
+    pub fn post_announcement(&mut self, severity: AnnouncementSeverity, content_hash: [u8; 32], uri: String) -> u64 {
+        let env = self.env();
+        self.assert_admin();
+        
+        if uri.len() > MAX_URI_LEN {
+            env.revert(Error::UriTooLong);
+        }
+
+        let seq = self.community.announcement_count.get_or_default() + 1;
+        self.community.announcement_count.set(seq);
+        self.community.announcements.set(&seq, Announcement {
+            seq,
+            severity,
+            content_hash,
+            uri: uri.clone(),
+            posted_at: env.get_block_time(),
+        });
+
+        env.emit_event(AnnouncementPosted {
+            seq,
+            severity,
+            content_hash,
+            uri,
+        });
+        seq
+    }
+
+    // ==================== Feature Flags ====================
+
+    /// Switch a gated feature on or off for every circle (admin only)
+    pub fn set_feature_enabled(&mut self, feature: Feature, enabled: bool) {
+        self.assert_admin();
+        self.community.disabled_features.set(&feature, !enabled);
+        self.env().emit_event(FeatureToggled { feature, enabled });
+    }
+
+    // ==================== Reader Accounting ====================
+
+    /// Register a consumer the caller will report heavy view usage for;
+    /// returns its reader id
+    pub fn register_reader(&mut self, label_hash: [u8; 32]) -> u64 {
+        let env = self.env();
+        let readers = &mut self.community.readers;
+        let id = readers.reader_count.get_or_default() + 1;
+        readers.reader_count.set(id);
+        readers.readers.set(&id, Reader {
+            id,
+            operator: env.caller(),
+            label_hash,
+            registered_at: env.get_block_time(),
+            active: true,
+        });
+        id
+    }
+
+    /// Stop accepting usage reports for a reader (its operator only)
+    pub fn deactivate_reader(&mut self, reader_id: u64) {
+        let mut reader = self.reader_for_operator(reader_id);
+        reader.active = false;
+        self.community.readers.readers.set(&reader_id, reader);
+    }
+
+    /// Add calls a reader made to a heavy view in the current UTC day (its
+    /// operator only). Views cannot write state, so the operator serving the
+    /// reads reports them here.
+    pub fn record_reader_usage(&mut self, reader_id: u64, view: HeavyView, calls: u64) {
+        let env = self.env();
+        let reader = self.reader_for_operator(reader_id);
+        if !reader.active {
+            env.revert(Error::ReaderInactive);
+        }
+        let day = env.get_block_time() / MILLIS_PER_DAY;
+        self.community.readers.usage.add(&(reader_id, day, view), calls);
+    }
+
+    // ==================== Extensions ====================
+
+    /// Register an add-on contract implementing `CareExtension` (owner only).
+    /// A new extension is delivered the hooks queued from now on; one registered
+    /// before resumes after the last hook it was delivered or skipped. The
+    /// starting index is in `ExtensionUpdated::next_hook`.
+    pub fn register_extension(&mut self, circle_id: u64, extension: Address) {
+        self.assert_feature_enabled(Feature::Extensions);
+        let env = self.env();
+        let caller = env.caller();
+        let circle = self.require_circle(circle_id);
+        
+        if caller != circle.owner {
+            env.revert(Error::NotOwner);
+        }
+        self.assert_circle_active(circle_id);
+
+        let hooks = &mut self.config.extensions;
+        let mut registered = hooks.registered.get(&circle_id).unwrap_or_default();
+        if registered.contains(&extension) {
+            env.revert(Error::AlreadyExtension);
+        }
+        if registered.len() >= MAX_EXTENSIONS {
+            env.revert(Error::TooManyExtensions);
+        }
+        registered.push(extension);
+        hooks.registered.set(&circle_id, registered);
+        let next_hook = match hooks.cursors.get(&(circle_id, extension)) {
+            Some(cursor) => cursor,
+            None => hooks.hook_count.get(&circle_id).unwrap_or(0),
+        };
+        hooks.cursors.set(&(circle_id, extension), next_hook);
+
+        self.log_audit(circle_id, caller, AuditAction::ExtensionRegistered, Some(extension), circle_id);
+        env.emit_event(ExtensionUpdated {
+            circle_id,
+            extension,
+            registered: true,
+            next_hook,
+            updated_by: caller,
+            routes: self.notification_routes(circle_id),
+        });
+    }
+
+    /// Stop notifying an extension contract (owner only)
+    pub fn remove_extension(&mut self, circle_id: u64, extension: Address) {
+        let env = self.env();
+        let caller = env.caller();
+        let circle = self.require_circle(circle_id);
+        
+        if caller != circle.owner {
+            env.revert(Error::NotOwner);
+        }
+
+        let hooks = &mut self.config.extensions;
+        let mut registered = hooks.registered.get(&circle_id).unwrap_or_default();
+        let Some(index) = registered.iter().position(|registered| *registered == extension) else {
+            env.revert(Error::NotExtension);
+        };
+        registered.remove(index);
+        hooks.registered.set(&circle_id, registered);
+        let next_hook = hooks.cursors.get(&(circle_id, extension)).unwrap_or(0);
+
+        self.log_audit(circle_id, caller, AuditAction::ExtensionRemoved, Some(extension), circle_id);
+        env.emit_event(ExtensionUpdated {
+            circle_id,
+            extension,
+            registered: false,
+            next_hook,
+            updated_by: caller,
+            routes: self.notification_routes(circle_id),
+        });
+    }
+
+    /// Advance an extension past its next queued hook without delivering it,
+    /// e.g. one its `on_care_hook` keeps reverting on (owner or the extension).
+    /// Returns the skipped hook's index.
+    pub fn skip_hook(&mut self, circle_id: u64, extension: Address) -> u64 {
+        let env = self.env();
+        let caller = env.caller();
+        let circle = self.require_circle(circle_id);
+        
+        if caller != circle.owner && caller != extension {
+            env.revert(Error::NotOwnerOrExtension);
+        }
+
+        let hooks = &mut self.config.extensions;
+        if !hooks.registered.get(&circle_id).unwrap_or_default().contains(&extension) {
+            env.revert(Error::NotExtension);
+        }
+        let index = hooks.cursors.get(&(circle_id, extension)).unwrap_or(0);
+        if index >= hooks.hook_count.get(&circle_id).unwrap_or(0) {
+            env.revert(Error::NoHookQueued);
+        }
+        hooks.cursors.set(&(circle_id, extension), index + 1);
+
+        self.log_audit(circle_id, caller, AuditAction::HookSkipped, Some(extension), circle_id);
+        env.emit_event(HookSkipped {
+            circle_id,
+            extension,
+            index,
+            skipped_by: caller,
+            routes: self.notification_routes(circle_id),
+        });
+        index
+    }
+
+    // ==================== Keepers ====================
+
+    /// Deliver up to `max_items` queued hooks to one extension, in order.
+    /// Permissionless keeper entry point. Hooks are delivered outside the
+    /// calls that queued them, so extension code never runs inside circle
+    /// actions. Delivery is not isolated: if the extension reverts, the whole
+    /// call reverts and its cursor stays put until the owner or the extension
+    /// calls `skip_hook`. Casper gives the nested call no gas limit of its own,
+    /// so the extension may use up the caller's payment; keepers bound that
+    /// with the payment amount and `max_items`. Returns the number delivered.
+    pub fn deliver_hooks(&mut self, circle_id: u64, extension: Address, max_items: u64) -> u64 {
+        self.assert_feature_enabled(Feature::Extensions);
+        let env = self.env();
+        let hooks = &self.config.extensions;
+        if !hooks.registered.get(&circle_id).unwrap_or_default().contains(&extension) {
+            env.revert(Error::NotExtension);
+        }
+
+        let cursor = hooks.cursors.get(&(circle_id, extension)).unwrap_or(0);
+        let queued = hooks.hook_count.get(&circle_id).unwrap_or(0);
+        let end = cursor.saturating_add(max_items.min(MAX_HOOKS_PER_CALL)).min(queued);
+        let mut target = CareExtensionContractRef::new(env, extension);
+        let mut delivered = 0;
+        loop {
+            // Re-read the cursor: the extension may have re-entered
+            // `deliver_hooks` or `skip_hook` and moved it past this call's range
+            let index = self.config.extensions.cursors.get(&(circle_id, extension)).unwrap_or(0);
+            if index >= end {
+                break;
+            }
+            self.config.extensions.cursors.set(&(circle_id, extension), index + 1);
+            let Some(call) = self.config.extensions.hooks.get(&(circle_id, index)) else {
+                continue;
+            };
+            target.on_care_hook(circle_id, call.kind, call.subject_id);
+            delivered += 1;
+        }
+        delivered
+    }
+
+    /// Emit `TaskDueSoon` for open tasks due within `horizon_secs` from now.
+    /// Permissionless keeper entry point; each due date is reminded once, and
+    /// tasks already overdue are left to the overdue views.
+    /// Examines up to `max_items` open tasks, resuming after the last one the
+    /// previous call examined. Returns the number of reminders emitted.
+    pub fn emit_due_reminders(&mut self, circle_id: u64, horizon_secs: u64, max_items: u64) -> u64 {
+        let env = self.env();
+        let now = env.get_block_time();
+        let horizon_end = now.saturating_add(horizon_secs.saturating_mul(MILLIS_PER_SECOND));
+        let max_items = max_items.min(MAX_REMINDERS_PER_CALL);
+        if !self.is_circle_active(circle_id) {
+            return 0;
+        }
+
+        let page = self.next_open_tasks(circle_id, OpenTaskKeeper::DueReminders, 0..=MAX_PRIORITY, max_items);
+        let routes = self.notification_routes(circle_id);
+        let mut emitted = 0;
+        for task_id in page {
+            let task = match self.load_task(task_id) {
+                Some(task) => task,
+                None => continue,
+            };
+            if task.completed || task.circle_id != circle_id {
+                continue;
+            }
+            // Only due dates still ahead, within the horizon
+            if task.due_at == 0 || task.due_at < now || task.due_at > horizon_end {
+                continue;
+            }
+            // Skip if this due date was already reminded
+            if self.scheduling.reminded_due_at.get(&task_id) == Some(task.due_at) {
+                continue;
+            }
+
+            self.scheduling.reminded_due_at.set(&task_id, task.due_at);
+            env.emit_event(TaskDueSoon {
+                task_id,
+                circle_id,
+                assigned_to: task.assigned_to,
+                due_at: task.due_at,
+                routes: routes.clone(),
+            });
+            emitted += 1;
+        }
+
+        emitted
+    }
+
+    /// Raise by one level the priority of open tasks that aged past the circle threshold.
+    /// Permissionless keeper entry point. Examines up to `max_items` open tasks
+    /// below the top priority, resuming after the last one the previous call
+    /// examined. Returns the number of tasks escalated.
+    pub fn escalate_priorities(&mut self, circle_id: u64, max_items: u64) -> u64 {
+        let env = self.env();
+        let now = env.get_block_time();
+        let threshold_secs = self.get_settings(circle_id).priority_aging_secs;
+        if threshold_secs == 0 || !self.is_circle_active(circle_id) {
+            return 0;
+        }
+        let threshold = threshold_secs.saturating_mul(MILLIS_PER_SECOND);
+        let max_items = max_items.min(MAX_ESCALATIONS_PER_CALL);
+
+        let page = self.next_open_tasks(circle_id, OpenTaskKeeper::PriorityAging, 0..=MAX_PRIORITY - 1, max_items);
+        let routes = self.notification_routes(circle_id);
+        let mut escalated = 0;
+        for task_id in page {
+            let mut task = match self.load_task(task_id) {
+                Some(task) => task,
+                None => continue,
+            };
+            // Shared tasks age under their primary circle's policy only
+            if task.completed || task.priority >= MAX_PRIORITY || task.circle_id != circle_id {
+                continue;
+            }
+            // Age is measured from creation or the previous escalation
+            let aged_since = self.scheduling.last_escalated_at.get(&task_id).unwrap_or(task.created_at);
+            if now.saturating_sub(aged_since) < threshold {
+                continue;
+            }
+
+            let old_priority = task.priority;
+            task.priority += 1;
+            let new_priority = task.priority;
+            self.store_task(task);
+            self.unindex_open_task(circle_id, old_priority, task_id);
+            self.index_open_task(circle_id, new_priority, task_id);
+            self.scheduling.last_escalated_at.set(&task_id, now);
+
+            env.emit_event(PriorityEscalated {
+                task_id,
+                circle_id,
+                old_priority,
+                new_priority,
+                routes: routes.clone(),
+            });
+            escalated += 1;
+        }
+
+        escalated
+    }
+
+    /// Flag assignees of open critical tasks who have been idle longer than
+    /// `inactivity_secs`, reassigning the tasks when the circle opts in.
+    /// Permissionless keeper entry point. Examines up to `max_items` open
+    /// critical tasks, resuming after the last one the previous call examined.
+    /// Returns the number of assignees flagged.
+    pub fn flag_inactive_assignees(&mut self, circle_id: u64, max_items: u64) -> u64 {
+        let env = self.env();
+        let now = env.get_block_time();
+        let settings = self.get_settings(circle_id);
+        if settings.inactivity_secs == 0 || !self.is_circle_active(circle_id) {
+            return 0;
+        }
+        let threshold = settings.inactivity_secs.saturating_mul(MILLIS_PER_SECOND);
+        let max_items = max_items.min(MAX_INACTIVITY_FLAGS_PER_CALL);
+
+        let page = self.next_open_tasks(circle_id, OpenTaskKeeper::Inactivity, MAX_PRIORITY..=MAX_PRIORITY, max_items);
+        let routes = self.notification_routes(circle_id);
+        let mut flagged = 0;
+        for task_id in page {
+            let task = match self.load_task(task_id) {
+                Some(task) => task,
+                None => continue,
+            };
+            if task.completed || task.priority < MAX_PRIORITY || task.circle_id != circle_id {
+                continue;
+            }
+            let assignee = task.assigned_to;
+            let last_active_at = self.get_last_active(circle_id, assignee);
+            if now.saturating_sub(last_active_at) < threshold {
+                continue;
+            }
+            // Flag each idle stretch once until the assignee acts again
+            if self.scheduling.inactivity_flagged_at.get(&task_id) == Some(last_active_at) {
+                continue;
+            }
+            self.scheduling.inactivity_flagged_at.set(&task_id, last_active_at);
+
+            let mut reassigned_to = None;
+            if settings.reassign_inactive {
+                if let Some(new_assignee) = self.pick_assignee(circle_id, Some(assignee)) {
+                    self.reassign_task(task, new_assignee);
+                    // The circle reassigns, not the keeper: acceptance follows the owner's rules
+                    let owner = self.require_circle(circle_id).owner;
+                    self.request_acceptance(task_id, circle_id, new_assignee, owner);
+                    reassigned_to = Some(new_assignee);
+                }
+            }
+
+            env.emit_event(AssigneeInactive {
+                task_id,
+                circle_id,
+                assignee,
+                last_active_at,
+                reassigned_to,
+                routes: routes.clone(),
+            });
+            flagged += 1;
+        }
+
+        flagged
+    }
+
+    /// Clear note, evidence and trip purpose hashes older than the circle's
+    /// `retention_days`, oldest first. Permissionless keeper entry point.
+    /// Counters, trend buckets and completion receipts are kept. Returns the
+    /// number of entries cleared.
+    pub fn purge_expired_records(&mut self, circle_id: u64, max_items: u64) -> u64 {
+        let env = self.env();
+        let retention_days = self.get_settings(circle_id).retention_days;
+        if retention_days == 0 {
+            return 0;
+        }
+        let cutoff = env.get_block_time().saturating_sub(retention_days.saturating_mul(MILLIS_PER_DAY));
+
+        let mut budget = max_items.min(MAX_PURGES_PER_CALL);
+        let mut purged = 0;
+        for log in RETAINED_LOGS {
+            let key = (circle_id, log);
+            let mut cursor = self.config.retention.cursors.get(&key).unwrap_or(0);
+            while budget > 0 && self.purge_entry(circle_id, log, cursor, |logged_at, _| logged_at < cutoff) == Some(true) {
+                cursor += 1;
+                budget -= 1;
+                purged += 1;
+            }
+            self.config.retention.cursors.set(&key, cursor);
+        }
+
+        if purged > 0 {
+            env.emit_event(RecordsPurged {
+                circle_id,
+                cutoff,
+                purged,
+                routes: self.notification_routes(circle_id),
+            });
+        }
+        purged
+    }
+
+    /// Snapshot the circle's oldest unfinalized week once it has ended (anyone)
+    pub fn finalize_week(&mut self, circle_id: u64) -> WeeklySnapshot {
+        let env = self.env();
+        let circle = self.require_circle(circle_id);
+        self.assert_circle_active(circle_id);
+
+        let settings = self.get_settings(circle_id);
+        let weekly = &mut self.stats.weekly;
+        let week = weekly.next_week.get(&circle_id)
+            .unwrap_or_else(|| local_week(&settings, circle.created_at));
+        if week >= local_week(&settings, env.get_block_time()) {
+            env.revert(Error::WeekNotOver);
+        }
+
+        let key = (circle_id, week);
+        let due = weekly.due.get(&key).unwrap_or(0);
+        let due_met = weekly.due_met.get(&key).unwrap_or(0);
+        let top = weekly.top_contributor.get(&key);
+        let snapshot = WeeklySnapshot {
+            week,
+            completions: weekly.completions.get(&key).unwrap_or(0),
+            overdue: due.saturating_sub(due_met),
+            top_contributor: top.map(|(addr, _)| addr),
+            top_contributor_completions: top.map(|(_, count)| count).unwrap_or(0),
+        };
+        weekly.snapshots.set(&key, snapshot.clone());
+        weekly.next_week.set(&circle_id, week + 1);
+
+        env.emit_event(WeeklyDigest {
+            circle_id,
+            week,
+            completions: snapshot.completions,
+            overdue: snapshot.overdue,
+            top_contributor: snapshot.top_contributor.map(|addr| self.shown_address(circle_id, addr)),
+            routes: self.notification_routes(circle_id),
+        });
+        self.check_anniversary(circle_id, circle.created_at, env.get_block_time());
+        self.shown_snapshot(circle_id, snapshot)
+    }
+
+    // ==================== View Functions ====================
+
+    /// Get circle details
+    pub fn get_circle(&self, circle_id: u64) -> Option<Circle> {
+        self.circles.get(&circle_id).map(|circle| Circle {
+            id: circle.id,
+            name: circle.name,
+            owner: self.shown_address(circle_id, circle.owner),
+            created_at: circle.created_at,
+            member_count: self.get_member_count(circle_id),
+            task_count: self.get_task_count(circle_id),
+        })
+    }
+
+    /// Get a task by its number within the circle it currently belongs to
+    pub fn get_task_by_seq(&self, circle_id: u64, circle_task_seq: u64) -> Option<Task> {
+        let task_id = self.task_index.task_by_seq.get(&(circle_id, circle_task_seq))?;
+        self.load_task(task_id)
+            .filter(|task| task.circle_id == circle_id)
+            .map(|task| self.shown_task(task))
+    }
+
+    /// `get_task_by_seq` as shown to `viewer`: the title is empty if the circle hides it
+    pub fn get_task_by_seq_for(&self, viewer: Address, circle_id: u64, circle_task_seq: u64) -> Option<Task> {
+        self.get_task_by_seq(circle_id, circle_task_seq)
+            .map(|task| self.redact_task(task, viewer))
+    }
+
+    /// Check whether a circle exists
+    pub fn circle_exists(&self, circle_id: u64) -> bool {
+        self.flags.circle_exists.get(&circle_id).unwrap_or(false)
+    }
+
+    /// Check whether a circle exists and is active
+    pub fn is_circle_active(&self, circle_id: u64) -> bool {
+        self.flags.circle_active.get(&circle_id).unwrap_or(false)
+    }
+
+    /// Check whether a task exists
+    pub fn task_exists(&self, task_id: u64) -> bool {
+        self.flags.task_exists.get(&task_id).unwrap_or(false)
+    }
+
+    /// Get task details
+    pub fn get_task(&self, task_id: u64) -> Option<Task> {
+        self.load_task(task_id).map(|task| self.shown_task(task))
+    }
+
+    /// `get_task` as shown to `viewer`: the title is empty if the circle hides it
+    pub fn get_task_for(&self, viewer: Address, task_id: u64) -> Option<Task> {
+        self.get_task(task_id).map(|task| self.redact_task(task, viewer))
+    }
+
+    /// Check if address is a member of a circle
+    pub fn check_is_member(&self, circle_id: u64, addr: Address) -> bool {
+        self.members.is_member.get(&(circle_id, addr)).unwrap_or(false)
+    }
+
+    /// Get a circle's join code by the hash of its code
+    pub fn get_join_code(&self, circle_id: u64, code_hash: [u8; 32]) -> Option<JoinCode> {
+        self.members.join_codes.get(&(circle_id, code_hash))
+    }
+
+    /// Get the member credited for joins with a join code
+    pub fn get_join_code_referrer(&self, circle_id: u64, code_hash: [u8; 32]) -> Option<Address> {
+        self.config.referrals.code_referrers.get(&(circle_id, code_hash)).flatten()
+            .map(|referrer| self.shown_address(circle_id, referrer))
+    }
+
+    /// Get how a member was recruited into a circle, if through a referral
+    pub fn get_referral(&self, circle_id: u64, recruit: Address) -> Option<Referral> {
+        self.config.referrals.recruits.get(&(circle_id, recruit)).map(|mut referral| {
+            referral.referrer = self.shown_address(circle_id, referral.referrer);
+            referral.recruit = self.shown_address(circle_id, referral.recruit);
+            referral
+        })
+    }
+
+    /// Get a page of the members a referrer recruited into a circle, in join order;
+    /// walk `get_referrals` of each recruit for the rest of the tree
+    pub fn get_referrals(&self, circle_id: u64, referrer: Address, offset: u64, limit: u64) -> Vec<Referral> {
+        let referrals = &self.config.referrals;
+        let count = referrals.recruit_count.get(&(circle_id, referrer)).unwrap_or(0);
+        let end = offset.saturating_add(limit).min(count);
+        (offset..end)
+            .filter_map(|index| referrals.recruit_list.get(&(circle_id, referrer, index)))
+            .filter_map(|recruit| self.get_referral(circle_id, recruit))
+            .collect()
+    }
+
+    /// Get the number of members a referrer recruited into a circle
+    pub fn get_referral_count(&self, circle_id: u64, referrer: Address) -> u64 {
+        self.config.referrals.recruit_count.get(&(circle_id, referrer)).unwrap_or(0)
+    }
+
+    /// Get a referrer's points in a circle
+    pub fn get_referral_points(&self, circle_id: u64, referrer: Address) -> u64 {
+        self.config.referrals.points.get(&(circle_id, referrer)).unwrap_or(0)
+    }
+
+    /// Get how many more members a join code can admit (0 if revoked or expired)
+    pub fn get_join_code_remaining_uses(&self, circle_id: u64, code_hash: [u8; 32]) -> u64 {
+        let Some(join_code) = self.get_join_code(circle_id, code_hash) else {
+            return 0;
+        };
+        let expired = join_code.expires_at != 0 && self.env().get_block_time() > join_code.expires_at;
+        if join_code.revoked || expired {
+            return 0;
+        }
+        join_code.max_uses.saturating_sub(join_code.uses)
+    }
+
+    /// Get the block time of a member's latest action in a circle
+    pub fn get_last_active(&self, circle_id: u64, member: Address) -> u64 {
+        self.members.last_active.get(&(circle_id, member)).unwrap_or_else(|| {
+            self.circles.get(&circle_id).map(|circle| circle.created_at).unwrap_or(0)
+        })
+    }
+
+    /// Get the steps `start_discharge_plan` creates for a circle
+    pub fn get_discharge_template(&self, circle_id: u64) -> Vec<DischargeStep> {
+        self.config.discharge_steps.get(&circle_id).flatten()
+            .unwrap_or_else(default_discharge_steps)
+    }
+
+    /// Get the circle's most recent discharge plan
+    pub fn get_discharge_plan(&self, circle_id: u64) -> Option<DischargePlan> {
+        self.config.discharge_plans.get(&circle_id).map(|mut plan| {
+            plan.started_by = self.shown_address(circle_id, plan.started_by);
+            plan
+        })
+    }
+
+    /// Get the contract that must accept each completion proof in a circle
+    pub fn get_proof_verifier(&self, circle_id: u64) -> Option<Address> {
+        self.config.verifiers.get(&circle_id).flatten()
+    }
+
+    /// Get the extension contracts registered with a circle
+    pub fn get_extensions(&self, circle_id: u64) -> Vec<Address> {
+        self.config.extensions.registered.get(&circle_id).unwrap_or_default()
+    }
+
+    /// Get how many hooks an extension has been delivered and how many the circle has queued
+    pub fn get_hook_progress(&self, circle_id: u64, extension: Address) -> (u64, u64) {
+        let hooks = &self.config.extensions;
+        (
+            hooks.cursors.get(&(circle_id, extension)).unwrap_or(0),
+            hooks.hook_count.get(&circle_id).unwrap_or(0),
+        )
+    }
+
+    /// Get a queued hook by its index in the circle's queue
+    pub fn get_hook(&self, circle_id: u64, index: u64) -> Option<HookCall> {
+        self.config.extensions.hooks.get(&(circle_id, index))
+    }
+
+    /// Get a page of a circle's trips, oldest first
+    pub fn get_trips(&self, circle_id: u64, offset: u64, limit: u64) -> Vec<Trip> {
+        let count = self.config.logistics.trip_count.get(&circle_id).unwrap_or(0);
+        let end = offset.saturating_add(limit).min(count);
+        (offset..end)
+            .filter_map(|index| self.config.logistics.trips.get(&(circle_id, index)))
+            .map(|mut trip| {
+                if self.is_erased(circle_id, trip.driver) {
+                    trip.purpose_hash = PURGED_HASH;
+                    trip.driver = self.shown_address(circle_id, trip.driver);
+                }
+                trip
+            })
+            .collect()
+    }
+
+    /// Get a member's mileage for up to 24 consecutive local months from `from_month`
+    pub fn get_mileage_report(&self, circle_id: u64, member: Address, from_month: u64, months: u64) -> Vec<MileageReport> {
+        let end = from_month.saturating_add(months.min(MAX_REPORT_MONTHS));
+        (from_month..end)
+            .map(|month| {
+                let (distance_m, claimed_m) = self.config.logistics.mileage
+                    .get(&(circle_id, member, month))
+                    .unwrap_or((0, 0));
+                MileageReport { month, distance_m, claimed_m }
+            })
+            .collect()
+    }
+
+    /// Get a reimbursement request
+    pub fn get_reimbursement(&self, circle_id: u64, request_id: u64) -> Option<ReimbursementRequest> {
+        self.config.logistics.reimbursements.get(&(circle_id, request_id)).map(|mut request| {
+            request.member = self.shown_address(circle_id, request.member);
+            request
+        })
+    }
+
+    /// Get a page of a circle's reimbursement requests, oldest first
+    pub fn get_reimbursements(&self, circle_id: u64, offset: u64, limit: u64) -> Vec<ReimbursementRequest> {
+        let count = self.config.logistics.reimbursement_count.get(&circle_id).unwrap_or(0);
+        let end = offset.saturating_add(limit).min(count);
+        (offset..end)
+            .filter_map(|index| self.get_reimbursement(circle_id, index + 1))
+            .collect()
+    }
+
+    /// Get a supply and its stock level
+    pub fn get_supply(&self, circle_id: u64, supply_id: u64) -> Option<Supply> {
+        self.config.logistics.supplies.get(&(circle_id, supply_id))
+    }
+
+    /// Get a page of a circle's supplies, oldest first
+    pub fn get_supplies(&self, circle_id: u64, offset: u64, limit: u64) -> Vec<Supply> {
+        let count = self.config.logistics.supply_count.get(&circle_id).unwrap_or(0);
+        let end = offset.saturating_add(limit).min(count);
+        (offset..end)
+            .filter_map(|index| self.get_supply(circle_id, index + 1))
+            .collect()
+    }
+
+    /// Get the supply and quantity an open task restocks on completion
+    pub fn get_restock(&self, task_id: u64) -> Option<(u64, u64)> {
+        self.config.logistics.restocks.get(&task_id).flatten()
+    }
+
+    /// Get how many entries of a log the retention keeper has cleared
+    pub fn get_purge_cursor(&self, circle_id: u64, log: RetainedLog) -> u64 {
+        self.config.retention.cursors.get(&(circle_id, log)).unwrap_or(0)
+    }
+
+    /// Get how many entries of a log `purge_erased_notes` has examined for a member
+    pub fn get_erasure_cursor(&self, circle_id: u64, member: Address, log: RetainedLog) -> u64 {
+        self.config.private.erasure_cursors.get(&(circle_id, member, log)).unwrap_or(0)
+    }
+
+    /// Get the priority bucket and slot a keeper examines next
+    pub fn get_keeper_cursor(&self, circle_id: u64, keeper: OpenTaskKeeper) -> (u8, u64) {
+        self.scheduling.keeper_cursors.get(&(circle_id, keeper)).unwrap_or((0, 0))
+    }
+
+    /// Get the circle's current encryption key epoch, starting at 0
+    pub fn get_key_epoch(&self, circle_id: u64) -> u64 {
+        self.config.private.key_epochs.get(&circle_id).unwrap_or(0)
+    }
+
+    /// Get an encrypted envelope, None if it never existed or was deleted
+    pub fn get_envelope(&self, circle_id: u64, envelope_id: u64) -> Option<EncryptedEnvelope> {
+        self.config.private.envelopes.get(&(circle_id, envelope_id)).flatten().map(|mut envelope| {
+            envelope.stored_by = self.shown_address(circle_id, envelope.stored_by);
+            envelope
+        })
+    }
+
+    /// Get a page of a circle's envelopes, oldest first, skipping deleted ones
+    pub fn get_envelopes(&self, circle_id: u64, offset: u64, limit: u64) -> Vec<EncryptedEnvelope> {
+        let count = self.config.private.envelope_count.get(&circle_id).unwrap_or(0);
+        let end = offset.saturating_add(limit).min(count);
+        (offset..end)
+            .filter_map(|index| self.get_envelope(circle_id, index + 1))
+            .collect()
+    }
+
+    /// Get the commitment of a task whose title has not been revealed
+    pub fn get_title_commitment(&self, task_id: u64) -> Option<[u8; 32]> {
+        self.config.private.title_commitments.get(&task_id).flatten()
+    }
+
+    /// Get a care episode, None if it never existed or was deleted
+    pub fn get_episode(&self, circle_id: u64, episode_id: u64) -> Option<CareEpisode> {
+        self.config.episodes.get(&(circle_id, episode_id)).flatten().map(|mut episode| {
+            episode.created_by = self.shown_address(circle_id, episode.created_by);
+            episode
+        })
+    }
+
+    /// Get a page of a circle's episodes, oldest first, skipping deleted ones
+    pub fn get_episodes(&self, circle_id: u64, offset: u64, limit: u64) -> Vec<CareEpisode> {
+        let count = self.config.episode_count.get(&circle_id).unwrap_or(0);
+        let end = offset.saturating_add(limit).min(count);
+        (offset..end)
+            .filter_map(|index| self.get_episode(circle_id, index + 1))
+            .collect()
+    }
+
+    /// Get task and completion counts of an episode
+    pub fn get_episode_stats(&self, circle_id: u64, episode_id: u64) -> EpisodeStats {
+        self.config.episode_stats.get(&(circle_id, episode_id)).unwrap_or_default()
+    }
+
+    /// Get a circle's succession plan and any open claim
+    pub fn get_succession_plan(&self, circle_id: u64) -> Option<SuccessionPlan> {
+        self.config.succession.get(&circle_id).flatten().map(|mut plan| {
+            plan.successor = self.shown_address(circle_id, plan.successor);
+            plan
+        })
+    }
+
+    /// Get which owners approved merging `source_id` into `target_id` (source, target),
+    /// counting only approvals by the circles' current owners
+    pub fn get_merge_approval(&self, source_id: u64, target_id: u64) -> (bool, bool) {
+        let (source_approver, target_approver) = self.config.merge_approvers.get(&(source_id, target_id)).unwrap_or_default();
+        let approved_by_owner = |circle_id: u64, approver: Option<Address>| {
+            approver.is_some() && self.circles.get(&circle_id).map(|circle| circle.owner) == approver
+        };
+        (approved_by_owner(source_id, source_approver), approved_by_owner(target_id, target_approver))
+    }
+
+    /// Get the topic hashes copied into a circle's events
+    pub fn get_notification_routes(&self, circle_id: u64) -> Vec<[u8; 32]> {
+        self.notification_routes(circle_id)
+    }
+
+    /// Get the tasks created for members who join by code
+    pub fn get_onboarding_tasks(&self, circle_id: u64) -> Vec<TaskTemplate> {
+        self.members.onboarding_tasks.get(&circle_id).unwrap_or_default()
+    }
+
+    /// Get the open task cap that applies to a member, 0 = unlimited
+    pub fn get_member_task_cap(&self, circle_id: u64, member: Address) -> u64 {
+        self.assignment_cap(circle_id, member)
+    }
+
+    /// Check if an address may create tasks in a circle
+    pub fn can_create_tasks(&self, circle_id: u64, addr: Address) -> bool {
+        let Some(circle) = self.circles.get(&circle_id) else {
+            return false;
+        };
+        if addr == circle.owner {
+            return true;
+        }
+        if !self.members.is_member.get(&(circle_id, addr)).unwrap_or(false) {
+            return false;
+        }
+        if let Some(allowed) = self.members.task_creator_overrides.get(&(circle_id, addr)).flatten() {
+            return allowed;
+        }
+        match self.get_settings(circle_id).task_creation {
+            TaskCreationPolicy::Anyone => true,
+            TaskCreationPolicy::MembersWithRole => self.is_guardian(circle_id, addr),
+            TaskCreationPolicy::OwnerOnly => false,
+        }
+    }
+
+    /// Check if a member is suspended in a circle
+    pub fn is_suspended(&self, circle_id: u64, address: Address) -> bool {
+        self.members.suspended.get(&(circle_id, address)).unwrap_or(false)
+    }
+
+    /// Check if an address is a guardian in a circle
+    pub fn is_guardian(&self, circle_id: u64, address: Address) -> bool {
+        self.members.guardians.get(&(circle_id, address)).unwrap_or(false)
+    }
+
+    /// Get member count for a circle
+    pub fn get_member_count(&self, circle_id: u64) -> u64 {
+        self.members.circle_member_count.get(&circle_id).unwrap_or(0)
+    }
+
+    /// Get each member's open task count and estimated open minutes
+    pub fn get_workload(&self, circle_id: u64) -> Vec<MemberWorkload> {
+        let member_count = self.members.circle_member_count.get(&circle_id).unwrap_or(0);
+        (0..member_count)
+            .filter_map(|index| self.members.circle_members.get(&(circle_id, index)))
+            .map(|member| MemberWorkload {
+                member: self.shown_address(circle_id, member),
+                open_tasks: self.members.open_tasks.get(&(circle_id, member)).unwrap_or(0),
+                open_minutes: self.members.open_minutes.get(&(circle_id, member)).unwrap_or(0),
+            })
+            .collect()
+    }
+
+    /// Get a page of circle members, in join order, with their profiles
+    pub fn get_members(&self, circle_id: u64, offset: u64, limit: u64) -> Vec<MemberInfo> {
+        let member_count = self.members.circle_member_count.get(&circle_id).unwrap_or(0);
+        let end = offset.saturating_add(limit).min(member_count);
+        (offset..end)
+            .filter_map(|index| self.members.circle_members.get(&(circle_id, index)))
+            .map(|address| MemberInfo {
+                address: self.shown_address(circle_id, address),
+                profile: self.members.profiles.get(&(circle_id, address)).unwrap_or_default(),
+                is_guardian: self.members.guardians.get(&(circle_id, address)).unwrap_or(false),
+                is_suspended: self.is_suspended(circle_id, address),
+            })
+            .collect()
+    }
+
+    /// Get the labels of a task
+    pub fn get_task_labels(&self, task_id: u64) -> Vec<String> {
+        self.task_index.task_labels.get(&task_id).unwrap_or_default()
+    }
+
+    /// `get_task_labels` as shown to `viewer`: empty if the circle hides titles
+    pub fn get_task_labels_for(&self, viewer: Address, task_id: u64) -> Vec<String> {
+        match self.load_task(task_id) {
+            Some(task) if self.can_view_task(&task, viewer) => self.get_task_labels(task_id),
+            _ => Vec::new(),
+        }
+    }
+
+    /// `get_tasks_by_label` as shown to `viewer`: empty if the circle hides titles
+    pub fn get_tasks_by_label_for(
+        &self,
+        viewer: Address,
+        circle_id: u64,
+        label: String,
+        offset: u64,
+        limit: u64,
+    ) -> Vec<u64> {
+        if !self.can_view_titles(circle_id, viewer) {
+            return Vec::new();
+        }
+        self.get_tasks_by_label(circle_id, label, offset, limit)
+    }
+
+    /// Get a page of task ids in a circle currently carrying a label
+    pub fn get_tasks_by_label(&self, circle_id: u64, label: String, offset: u64, limit: u64) -> Vec<u64> {
+        let label_hash = self.env().hash(label.as_bytes());
+        let indexed = self.task_index.label_task_count.get(&(circle_id, label_hash)).unwrap_or(0);
+        (0..indexed)
+            .filter_map(|index| self.task_index.label_tasks.get(&(circle_id, label_hash, index)))
+            .filter(|task_id| self.task_index.task_labels.get(task_id).unwrap_or_default().contains(&label))
+            .filter(|task_id| self.load_task(*task_id).is_some_and(|task| task.circle_id == circle_id))
+            .skip(offset as usize)
+            .take(limit as usize)
+            .collect()
+    }
+
+    /// Get a page of a circle's activity feed, most recent first
+    pub fn get_activity(&self, circle_id: u64, offset: u64, limit: u64) -> Vec<ActivityEntry> {
+        let appended = self.history.activity_count.get(&circle_id).unwrap_or(0);
+        let retained = appended.min(ACTIVITY_LOG_SIZE);
+        let end = offset.saturating_add(limit).min(retained);
+        (offset..end)
+            .filter_map(|back| {
+                let seq = appended - 1 - back;
+                self.history.activity.get(&(circle_id, seq % ACTIVITY_LOG_SIZE))
+            })
+            .map(|mut entry| {
+                entry.actor = self.shown_address(circle_id, entry.actor);
+                entry
+            })
+            .collect()
+    }
+
+    /// Get the address allowed to complete a task on the assignee's behalf
+    pub fn get_completion_delegate(&self, task_id: u64) -> Option<Address> {
+        let circle_id = self.load_task(task_id).map_or(0, |task| task.circle_id);
+        self.workflow.delegates.get(&task_id).flatten()
+            .map(|delegate| self.shown_address(circle_id, delegate))
+    }
+
+    /// Get how many co-signatures a task's completion needs (0 if none)
+    pub fn get_required_cosigners(&self, task_id: u64) -> u32 {
+        self.workflow.required_cosigners.get(&task_id).unwrap_or(0)
+    }
+
+    /// Get the completion currently collecting co-signatures
+    pub fn get_cosign_request(&self, task_id: u64) -> Option<CosignRequest> {
+        let circle_id = self.load_task(task_id).map_or(0, |task| task.circle_id);
+        self.workflow.cosign_requests.get(&task_id).flatten().map(|mut request| {
+            request.completed_by = self.shown_address(circle_id, request.completed_by);
+            request
+        })
+    }
+
+    /// Get a task's due date before its first deferral, or its current due date
+    pub fn get_original_due_at(&self, task_id: u64) -> u64 {
+        match self.workflow.defers.get(&(task_id, 0)) {
+            Some(first) => first.previous_due_at,
+            None => self.load_task(task_id).map(|task| task.due_at).unwrap_or(0),
+        }
+    }
+
+    /// Get a task's deferrals, oldest first (paginated)
+    pub fn get_defer_history(&self, task_id: u64, offset: u32, limit: u32) -> Vec<DeferEntry> {
+        let circle_id = self.load_task(task_id).map_or(0, |task| task.circle_id);
+        let defer_count = self.workflow.defer_count.get(&task_id).unwrap_or(0);
+        let end = offset.saturating_add(limit).min(defer_count);
+        (offset..end)
+            .filter_map(|i| self.workflow.defers.get(&(task_id, i)))
+            .map(|mut entry| {
+                entry.deferred_by = self.shown_address(circle_id, entry.deferred_by);
+                entry
+            })
+            .collect()
+    }
+
+    /// Get the number of entries in a circle's audit log
+    pub fn get_audit_count(&self, circle_id: u64) -> u64 {
+        self.history.audit_count.get(&circle_id).unwrap_or(0)
+    }
+
+    /// Get a page of a circle's audit log, oldest first
+    pub fn get_audit_log(&self, circle_id: u64, offset: u64, limit: u64) -> Vec<AuditEntry> {
+        let end = offset.saturating_add(limit).min(self.get_audit_count(circle_id));
+        (offset..end)
+            .filter_map(|seq| self.history.audit.get(&(circle_id, seq)))
+            .map(|mut entry| {
+                entry.actor = self.shown_address(circle_id, entry.actor);
+                entry.target = entry.target.map(|target| self.shown_address(circle_id, target));
+                entry
+            })
+            .collect()
+    }
+
+    /// Get up to `limit` open tasks of a circle, highest priority first and
+    /// then earliest due date, tasks without a due date last
+    pub fn get_open_tasks(&self, circle_id: u64, limit: u64) -> Vec<Task> {
+        let mut open_tasks = Vec::new();
+        for priority in (0..=MAX_PRIORITY).rev() {
+            if open_tasks.len() as u64 >= limit {
+                break;
+            }
+            let count = self.task_index.priority_bucket_count.get(&(circle_id, priority)).unwrap_or(0);
+            let mut bucket: Vec<Task> = (0..count)
+                .filter_map(|slot| self.task_index.priority_buckets.get(&(circle_id, priority, slot)))
+                .filter_map(|task_id| self.load_task(task_id))
+                .collect();
+            bucket.sort_by_key(|task| if task.due_at == 0 { u64::MAX } else { task.due_at });
+            let remaining = limit - open_tasks.len() as u64;
+            open_tasks.extend(bucket.into_iter().take(remaining as usize));
+        }
+        open_tasks.into_iter().map(|task| self.shown_task(task)).collect()
+    }
+
+    /// `get_open_tasks` as shown to `viewer`: titles are empty if the circle hides them
+    pub fn get_open_tasks_for(&self, viewer: Address, circle_id: u64, limit: u64) -> Vec<Task> {
+        let mut open_tasks = self.get_open_tasks(circle_id, limit);
+        if !self.can_view_titles(circle_id, viewer) {
+            open_tasks.iter_mut().for_each(|task| task.title.clear());
+        }
+        open_tasks
+    }
+
+    /// Get task count for a circle
+    pub fn get_task_count(&self, circle_id: u64) -> u64 {
+        self.task_index.circle_task_count.get(&circle_id).unwrap_or(0)
+    }
+
+    /// Get the number of open tasks assigned to a member
+    pub fn get_open_task_count(&self, circle_id: u64, addr: Address) -> u64 {
+        self.members.open_tasks.get(&(circle_id, addr)).unwrap_or(0)
+    }
+
+    /// Get the settings of a circle
+    pub fn get_settings(&self, circle_id: u64) -> CircleSettings {
+        self.settings.get(&circle_id).unwrap_or_default()
+    }
+
+    /// Get the completion awaiting verification for a task
+    pub fn get_pending_completion(&self, task_id: u64) -> Option<PendingCompletion> {
+        let circle_id = self.load_task(task_id).map_or(0, |task| task.circle_id);
+        self.workflow.pending_completions.get(&task_id).flatten().map(|mut pending| {
+            pending.completed_by = self.shown_address(circle_id, pending.completed_by);
+            pending
+        })
+    }
+
+    /// Get the availability windows a member declared in a circle
+    pub fn get_availability(&self, circle_id: u64, addr: Address) -> Vec<AvailabilityWindow> {
+        self.members.availability.get(&(circle_id, addr)).unwrap_or_default()
+    }
+
+    /// Get the members of a circle who are available at a timestamp
+    pub fn get_available_members(&self, circle_id: u64, at: u64) -> Vec<Address> {
+        let member_count = self.members.circle_member_count.get(&circle_id).unwrap_or(0);
+        (0..member_count)
+            .filter_map(|index| self.members.circle_members.get(&(circle_id, index)))
+            .filter(|addr| self.is_available_at(circle_id, *addr, at))
+            .map(|addr| self.shown_address(circle_id, addr))
+            .collect()
+    }
+
+    /// Get the SLA completion targets of a circle, indexed by priority
+    pub fn get_sla_targets(&self, circle_id: u64) -> Vec<u64> {
+        self.scheduling.sla.targets.get(&circle_id).unwrap_or_default()
+    }
+
+    /// Get the number of evidence items attached to a task
+    pub fn get_evidence_count(&self, task_id: u64) -> u64 {
+        self.workflow.evidence_count.get(&task_id).unwrap_or(0)
+    }
+
+    /// Get a page of a task's evidence items, oldest first
+    pub fn get_evidence(&self, task_id: u64, offset: u64, limit: u64) -> Vec<EvidenceItem> {
+        let circle_id = self.load_task(task_id).map_or(0, |task| task.circle_id);
+        let end = offset.saturating_add(limit).min(self.get_evidence_count(task_id));
+        (offset..end)
+            .filter_map(|index| self.workflow.evidence.get(&(task_id, index)))
+            .map(|mut item| {
+                if self.is_erased(circle_id, item.added_by) {
+                    item.content_hash = PURGED_HASH;
+                    item.added_by = self.shown_address(circle_id, item.added_by);
+                }
+                item
+            })
+            .collect()
+    }
+
+    /// Get the location commitment a task is anchored to
+    pub fn get_task_location(&self, task_id: u64) -> Option<[u8; 32]> {
+        self.workflow.presence.locations.get(&task_id).flatten()
+    }
+
+    /// Get the latest check-in at a task's location
+    pub fn get_check_in(&self, task_id: u64) -> Option<CheckIn> {
+        let circle_id = self.load_task(task_id).map_or(0, |task| task.circle_id);
+        self.workflow.presence.check_ins.get(&task_id).map(|mut check_in| {
+            check_in.caregiver = self.shown_address(circle_id, check_in.caregiver);
+            check_in.attested_by = check_in.attested_by.map(|oracle| self.shown_address(circle_id, oracle));
+            check_in
+        })
+    }
+
+    /// Check if an address attests proximity for a circle
+    pub fn is_presence_oracle(&self, circle_id: u64, oracle: Address) -> bool {
+        self.workflow.presence.oracles.get(&(circle_id, oracle)).unwrap_or(false)
+    }
+
+    /// Get the assignee's open help request note for a task, empty once
+    /// its requester's data was erased
+    pub fn get_help_request(&self, task_id: u64) -> Option<String> {
+        let circle_id = self.load_task(task_id).map_or(0, |task| task.circle_id);
+        let note = self.workflow.help_requests.get(&task_id).flatten()?;
+        match self.config.private.help_requested_by.get(&task_id) {
+            Some(requested_by) if self.is_erased(circle_id, requested_by) => Some(String::new()),
+            _ => Some(note),
+        }
+    }
+
+    /// Get the members helping on a task
+    pub fn get_helpers(&self, task_id: u64) -> Vec<Address> {
+        let circle_id = self.load_task(task_id).map_or(0, |task| task.circle_id);
+        self.workflow.helpers.get(&task_id).unwrap_or_default()
+            .into_iter()
+            .map(|helper| self.shown_address(circle_id, helper))
+            .collect()
+    }
+
+    /// Check if a task's assignee has yet to accept it
+    pub fn is_awaiting_acceptance(&self, task_id: u64) -> bool {
+        self.workflow.awaiting_acceptance.get(&task_id).unwrap_or(false)
+    }
+
+    /// Get the recorded completion latency of a task in seconds
+    pub fn get_task_latency(&self, task_id: u64) -> Option<u64> {
+        self.scheduling.sla.task_latency_secs.get(&task_id)
+    }
+
+    /// Get SLA results for a week bucket
+    pub fn get_sla_stats(&self, circle_id: u64, week: u64) -> SlaPeriodStats {
+        self.scheduling.sla.period_stats.get(&(circle_id, week)).unwrap_or_default()
+    }
+
+    /// `get_sla_stats` as shown to `viewer`: zeroed if the circle hides its statistics
+    pub fn get_sla_stats_for(&self, viewer: Address, circle_id: u64, week: u64) -> SlaPeriodStats {
+        if !self.can_view_stats(circle_id, viewer) {
+            return SlaPeriodStats::default();
+        }
+        self.get_sla_stats(circle_id, week)
+    }
+
+    /// Get SLA compliance for a week bucket in basis points (10000 when nothing was tracked)
+    pub fn get_sla_compliance_bps(&self, circle_id: u64, week: u64) -> u64 {
+        Self::compliance_bps(self.get_sla_stats(circle_id, week))
+    }
+
+    /// `get_sla_compliance_bps` as shown to `viewer`: 10000 if the circle hides its statistics
+    pub fn get_sla_compliance_bps_for(&self, viewer: Address, circle_id: u64, week: u64) -> u64 {
+        Self::compliance_bps(self.get_sla_stats_for(viewer, circle_id, week))
+    }
+
+    /// Get whether a completed task was late and how many seconds past its due date
+    pub fn get_completion_lateness(&self, task_id: u64) -> Option<(bool, u64)> {
+        self.scheduling.sla.lateness.get(&task_id)
+    }
+
+    /// `get_on_time_rate_bps` as shown to `viewer`: 10000 if the circle hides its statistics
+    pub fn get_on_time_rate_bps_for(&self, viewer: Address, circle_id: u64, member: Address) -> u64 {
+        if !self.can_view_stats(circle_id, viewer) {
+            return BPS_DENOMINATOR;
+        }
+        self.get_on_time_rate_bps(circle_id, member)
+    }
+
+    /// Get a member's on-time completion rate in basis points (10000 when nothing was tracked)
+    pub fn get_on_time_rate_bps(&self, circle_id: u64, member: Address) -> u64 {
+        let (on_time, tracked) = self.scheduling.sla.member_on_time.get(&(circle_id, member))
+            .unwrap_or((0, 0));
+        if tracked == 0 {
+            return BPS_DENOMINATOR;
+        }
+        on_time * BPS_DENOMINATOR / tracked
+    }
+
+    /// Get the version of the published message catalog, so clients can
+    /// tell when their translations of error and event codes are stale
+    pub fn get_message_catalog_version(&self) -> u32 {
+        MESSAGE_CATALOG_VERSION
+    }
+
+    /// Get the code MESSAGES.md gives an event, by the name it is emitted
+    /// under, so notifications can be localized like errors. 0 if no event
+    /// has that name.
+    pub fn get_event_code(&self, name: String) -> u32 {
+        event_code(&name)
+    }
+
+    /// Check whether a gated feature is switched on
+    pub fn is_feature_enabled(&self, feature: Feature) -> bool {
+        !self.community.disabled_features.get(&feature).unwrap_or(false)
+    }
+
+    /// Get every gated feature with whether it is switched on, so clients
+    /// can hide what is off
+    pub fn get_feature_flags(&self) -> Vec<(Feature, bool)> {
+        FEATURES.iter().map(|feature| (*feature, self.is_feature_enabled(*feature))).collect()
+    }
+
+    /// Get a registered reader
+    pub fn get_reader(&self, reader_id: u64) -> Option<Reader> {
+        self.community.readers.readers.get(&reader_id)
+    }
+
+    /// Get the calls reported for a reader on one UTC day (days since
+    /// 1970-01-01), for every heavy view
+    pub fn get_reader_usage(&self, reader_id: u64, day: u64) -> Vec<(HeavyView, u64)> {
+        HEAVY_VIEWS.iter()
+            .map(|view| (*view, self.community.readers.usage.get(&(reader_id, day, *view)).unwrap_or(0)))
+            .collect()
+    }
+
+    /// Get the contract admin
+    pub fn get_admin(&self) -> Option<Address> {
+        self.community.admin.get()
+    }
+
+    /// Get up to 20 announcements posted after `since_seq`, oldest first;
+    /// pass 0 for the first page and the last `seq` seen afterwards
+    pub fn get_announcements(&self, since_seq: u64) -> Vec<Announcement> {
+        let count = self.community.announcement_count.get_or_default();
+        let end = since_seq.saturating_add(MAX_ANNOUNCEMENTS_PER_PAGE).min(count);
+        (since_seq.saturating_add(1)..=end)
+            .filter_map(|seq| self.community.announcements.get(&seq))
+            .collect()
+    }
+
+    /// Get the number of announcements posted, which is also the latest `seq`
+    pub fn get_announcement_count(&self) -> u64 {
+        self.community.announcement_count.get_or_default()
+    }
+
+    /// Check if an agency is verified by the contract admin
+    pub fn is_verified_agency(&self, agency: Address) -> bool {
+        self.community.agencies.verified.get(&agency).unwrap_or(false)
+    }
+
+    /// Get the agency currently operating a caregiver address
+    pub fn get_caregiver_agency(&self, caregiver: Address) -> Option<Address> {
+        self.community.agencies.agency_of.get(&caregiver).flatten()
+    }
+
+    /// Get the caregivers an agency currently operates
+    pub fn get_agency_caregivers(&self, agency: Address) -> Vec<Address> {
+        self.listed_caregivers(agency)
+            .into_iter()
+            .filter(|caregiver| self.get_caregiver_agency(*caregiver) == Some(agency))
+            .collect()
+    }
+
+    /// Check if a circle currently grants an agency's role
+    pub fn is_agency_granted(&self, circle_id: u64, agency: Address) -> bool {
+        self.community.agencies.granted.get(&(circle_id, agency)).unwrap_or(false)
+    }
+
+    /// Get the agency that brought a member into a circle
+    pub fn get_member_agency(&self, circle_id: u64, member: Address) -> Option<Address> {
+        self.community.agencies.member_agency.get(&(circle_id, member)).flatten()
+            .map(|agency| self.shown_address(circle_id, agency))
+    }
+
+    /// Check if an address is a registered volunteer
+    pub fn is_volunteer(&self, addr: Address) -> bool {
+        self.community.volunteers.volunteers.get(&addr).unwrap_or(false)
+    }
+
+    /// Check if a task is currently listed for volunteers
+    pub fn is_open_to_volunteers(&self, task_id: u64) -> bool {
+        self.community.volunteers.open_tasks.get(&task_id).unwrap_or(false)
+    }
+
+    /// Get a page of task ids currently listed on the volunteer board
+    pub fn get_volunteer_board(&self, offset: u64, limit: u64) -> Vec<u64> {
+        let board_count = self.community.volunteers.board_count.get_or_default();
+        (0..board_count)
+            .filter_map(|index| self.community.volunteers.board.get(&index))
+            .filter(|task_id| self.community.volunteers.open_tasks.get(task_id).unwrap_or(false))
+            .filter(|task_id| self.load_task(*task_id).is_some_and(|task| !task.completed))
+            .skip(offset as usize)
+            .take(limit as usize)
+            .collect()
+    }
+
+    /// Get the volunteers who applied for a task
+    pub fn get_task_applicants(&self, task_id: u64) -> Vec<Address> {
+        let circle_id = self.load_task(task_id).map_or(0, |task| task.circle_id);
+        let applicant_count = self.community.volunteers.applicant_count.get(&task_id).unwrap_or(0);
+        (0..applicant_count)
+            .filter_map(|index| self.community.volunteers.applicants.get(&(task_id, index)))
+            .map(|applicant| self.shown_address(circle_id, applicant))
+            .collect()
+    }
+
+    /// Get the secondary circle a task is shared with
+    pub fn get_task_secondary_circle(&self, task_id: u64) -> Option<u64> {
+        self.community.sharing.secondary_circle.get(&task_id)
+    }
+
+    /// Get the number of completed tasks in a circle, including shared tasks
+    pub fn get_completion_count(&self, circle_id: u64) -> u64 {
+        self.stats.circle_completions.get(&circle_id).unwrap_or(0)
+    }
+
+    /// `get_completion_count` as shown to `viewer`: 0 if the circle hides its statistics
+    pub fn get_completion_count_for(&self, viewer: Address, circle_id: u64) -> u64 {
+        if !self.can_view_stats(circle_id, viewer) {
+            return 0;
+        }
+        self.get_completion_count(circle_id)
+    }
+
+    /// Get the head of a caregiver's completion receipt chain (zero if empty)
+    pub fn get_receipt_head(&self, caregiver: Address) -> [u8; 32] {
+        self.history.receipt_heads.get(&caregiver).unwrap_or([0u8; 32])
+    }
+
+    /// Get the number of receipts in a caregiver's chain
+    pub fn get_receipt_count(&self, caregiver: Address) -> u64 {
+        self.history.receipt_counts.get(&caregiver).unwrap_or(0)
+    }
+
+    /// Get a page of a caregiver's receipts as work records, oldest first
+    pub fn get_work_history(&self, caregiver: Address, offset: u64, limit: u64) -> Vec<WorkRecord> {
+        self.work_history(caregiver, offset, limit, |_| true)
+    }
+
+    /// `get_work_history` as shown to `viewer`: viewers other than the caregiver only see
+    /// records from circles the caregiver made public, so pages may be short
+    pub fn get_work_history_for(&self, viewer: Address, caregiver: Address, offset: u64, limit: u64) -> Vec<WorkRecord> {
+        self.work_history(caregiver, offset, limit, |receipt| {
+            viewer == caregiver
+                || self.history.public_history.get(&(caregiver, receipt.circle_id)).unwrap_or(false)
+        })
+    }
+
+    /// Check that a chain segment starting at `from_head` hashes to `expected_head`
+    pub fn verify_receipt_chain(
+        &self,
+        from_head: [u8; 32],
+        receipts: Vec<CompletionReceipt>,
+        expected_head: [u8; 32],
+    ) -> bool {
+        let head = receipts
+            .iter()
+            .fold(from_head, |prev, receipt| self.receipt_hash(&prev, receipt));
+        head == expected_head
+    }
+
+    /// Get a finalized week snapshot
+    pub fn get_weekly_snapshot(&self, circle_id: u64, week: u64) -> Option<WeeklySnapshot> {
+        self.stats.weekly.snapshots.get(&(circle_id, week))
+            .map(|snapshot| self.shown_snapshot(circle_id, snapshot))
+    }
+
+    /// `get_weekly_snapshot` as shown to `viewer`: None if the circle hides its statistics
+    pub fn get_weekly_snapshot_for(&self, viewer: Address, circle_id: u64, week: u64) -> Option<WeeklySnapshot> {
+        if !self.can_view_stats(circle_id, viewer) {
+            return None;
+        }
+        self.get_weekly_snapshot(circle_id, week)
+    }
+
+    /// Get the circle's current week or month bucket
+    pub fn get_current_period(&self, circle_id: u64, period: LeaderboardPeriod) -> u64 {
+        let settings = self.get_settings(circle_id);
+        let now = self.env().get_block_time();
+        match period {
+            LeaderboardPeriod::Week => local_week(&settings, now),
+            LeaderboardPeriod::Month => local_month(&settings, now),
+        }
+    }
+
+    /// Get the members with the most completions in a week or month bucket, ties in join order
+    pub fn get_leaderboard(
+        &self,
+        circle_id: u64,
+        period: LeaderboardPeriod,
+        bucket: u64,
+        limit: u64,
+    ) -> Vec<LeaderboardEntry> {
+        let weekly = &self.stats.weekly;
+        let member_count = self.members.circle_member_count.get(&circle_id).unwrap_or(0);
+        let mut entries: Vec<LeaderboardEntry> = (0..member_count)
+            .filter_map(|index| self.members.circle_members.get(&(circle_id, index)))
+            .map(|member| {
+                let key = (circle_id, bucket, member);
+                let completions = match period {
+                    LeaderboardPeriod::Week => weekly.member_completions.get(&key),
+                    LeaderboardPeriod::Month => weekly.member_month_completions.get(&key),
+                };
+                LeaderboardEntry { member: self.shown_address(circle_id, member), completions: completions.unwrap_or(0) }
+            })
+            .filter(|entry| entry.completions > 0)
+            .collect();
+        entries.sort_by_key(|entry| core::cmp::Reverse(entry.completions));
+        entries.truncate(limit.min(member_count) as usize);
+        entries
+    }
+
+    /// `get_leaderboard` as shown to `viewer`: empty if the circle hides its statistics
+    pub fn get_leaderboard_for(
+        &self,
+        viewer: Address,
+        circle_id: u64,
+        period: LeaderboardPeriod,
+        bucket: u64,
+        limit: u64,
+    ) -> Vec<LeaderboardEntry> {
+        if !self.can_view_stats(circle_id, viewer) {
+            return Vec::new();
+        }
+        self.get_leaderboard(circle_id, period, bucket, limit)
+    }
+
+    /// Get the next week bucket `finalize_week` will snapshot
+    pub fn get_next_digest_week(&self, circle_id: u64) -> u64 {
+        let created_week = self.circles.get(&circle_id)
+            .map(|circle| self.week_of(circle_id, circle.created_at))
+            .unwrap_or(0);
+        self.stats.weekly.next_week.get(&circle_id).unwrap_or(created_week)
+    }
+
+    /// Get the next block time (ms) at which the circle's local clock reads `minute_of_day`
+    pub fn get_next_local_time(&self, circle_id: u64, minute_of_day: u64) -> u64 {
+        let env = self.env();
+        if minute_of_day * MILLIS_PER_MINUTE >= MILLIS_PER_DAY {
+            env.revert(Error::InvalidTimeOfDay);
+        }
+
+        let settings = self.get_settings(circle_id);
+        let now = env.get_block_time();
+        let local_now = local_time(&settings, now);
+        let mut local_next = local_now - local_now % MILLIS_PER_DAY + minute_of_day * MILLIS_PER_MINUTE;
+        if local_next <= local_now {
+            local_next += MILLIS_PER_DAY;
+        }
+
+        // Convert back with the offset in effect then, not now, in case DST changes in between
+        let offset_at = |timestamp: u64| local_time(&settings, timestamp) as i64 - timestamp as i64;
+        let guess = now + (local_next - local_now);
+        local_next.saturating_add_signed(-offset_at(guess))
+    }
+
+    /// Get a circle's care recipient
+    pub fn get_care_recipient(&self, circle_id: u64) -> Option<Address> {
+        self.feedback.recipients.get(&circle_id)
+            .map(|recipient| self.shown_address(circle_id, recipient))
+    }
+
+    /// Check whether an address may read a circle's data scope
+    ///
+    /// The care recipient, guardians and the owner always may; anyone else
+    /// needs a grant to them or to all members, or unexpired auditor access.
+    pub fn has_consent(&self, circle_id: u64, scope: ConsentScope, address: Address) -> bool {
+        let is_owner = self.circles.get(&circle_id).is_some_and(|circle| circle.owner == address);
+        let is_member = self.members.is_member.get(&(circle_id, address)).unwrap_or(false);
+        is_owner
+            || self.feedback.recipients.get(&circle_id) == Some(address)
+            || self.members.guardians.get(&(circle_id, address)).unwrap_or(false)
+            || self.feedback.address_consents.get(&(circle_id, scope, address)).unwrap_or(false)
+            || (is_member && self.feedback.member_consents.get(&(circle_id, scope)).unwrap_or(false))
+            || self.get_auditor_access(circle_id, address, scope) != 0
+    }
+
+    /// Get when an auditor's access to a scope ends, 0 if none is active
+    pub fn get_auditor_access(&self, circle_id: u64, auditor: Address, scope: ConsentScope) -> u64 {
+        let now = self.env().get_block_time();
+        self.feedback.auditor_grants.get(&(circle_id, scope, auditor))
+            .filter(|expires_at| *expires_at > now)
+            .unwrap_or(0)
+    }
+
+    /// Get the care recipient's allergy or condition record for a substance, None unless `viewer` has consent
+    pub fn get_health_record_for(&self, viewer: Address, circle_id: u64, substance_hash: [u8; 32]) -> Option<HealthRecord> {
+        if !self.has_consent(circle_id, ConsentScope::HealthRecords, viewer) {
+            return None;
+        }
+        self.feedback.health_records.get(&(circle_id, substance_hash)).flatten()
+            .map(|record| self.shown_record(circle_id, record))
+    }
+
+    /// Get the care recipient's allergy and condition records (paginated), empty unless `viewer` has consent
+    pub fn get_health_records_for(&self, viewer: Address, circle_id: u64, offset: u64, limit: u64) -> Vec<HealthRecord> {
+        if !self.has_consent(circle_id, ConsentScope::HealthRecords, viewer) {
+            return Vec::new();
+        }
+        let count = self.feedback.health_record_count.get(&circle_id).unwrap_or(0);
+        let end = offset.saturating_add(limit).min(count);
+        (offset..end)
+            .filter_map(|i| self.feedback.health_record_list.get(&(circle_id, i)))
+            .filter_map(|substance_hash| self.feedback.health_records.get(&(circle_id, substance_hash)).flatten())
+            .map(|record| self.shown_record(circle_id, record))
+            .collect()
+    }
+
+    /// Get the care recipient's latest status
+    pub fn get_status(&self, circle_id: u64) -> Option<StatusUpdate> {
+        self.get_status_history(circle_id, 0, 1).pop()
+    }
+
+    /// `get_status` as shown to `viewer`: None unless they are a member
+    pub fn get_status_for(&self, viewer: Address, circle_id: u64) -> Option<StatusUpdate> {
+        self.get_status_history_for(viewer, circle_id, 0, 1).pop()
+    }
+
+    /// `get_status_history` as shown to `viewer`: empty unless they are a member
+    pub fn get_status_history_for(&self, viewer: Address, circle_id: u64, offset: u64, limit: u64) -> Vec<StatusUpdate> {
+        if !self.members.is_member.get(&(circle_id, viewer)).unwrap_or(false) {
+            return Vec::new();
+        }
+        self.get_status_history(circle_id, offset, limit)
+    }
+
+    /// Get a page of the last 20 status updates, newest first
+    pub fn get_status_history(&self, circle_id: u64, offset: u64, limit: u64) -> Vec<StatusUpdate> {
+        let journal = &self.feedback.journal;
+        let appended = journal.status_count.get(&circle_id).unwrap_or(0);
+        let retained = appended.min(STATUS_HISTORY_SIZE);
+        let end = offset.saturating_add(limit).min(retained);
+        (offset..end)
+            .filter_map(|back| {
+                let seq = appended - 1 - back;
+                journal.statuses.get(&(circle_id, seq % STATUS_HISTORY_SIZE))
+            })
+            .map(|mut status| {
+                if self.is_erased(circle_id, status.updated_by) {
+                    status.note_hash = PURGED_HASH;
+                    status.updated_by = self.shown_address(circle_id, status.updated_by);
+                }
+                status
+            })
+            .collect()
+    }
+
+    /// Get the number of symptom entries logged for a circle
+    pub fn get_symptom_count(&self, circle_id: u64) -> u64 {
+        self.feedback.journal.symptom_count.get(&circle_id).unwrap_or(0)
+    }
+
+    /// Get a page of the symptom log, oldest first, empty unless `viewer` has consent
+    pub fn get_symptom_log_for(&self, viewer: Address, circle_id: u64, offset: u64, limit: u64) -> Vec<SymptomEntry> {
+        if !self.has_consent(circle_id, ConsentScope::Symptoms, viewer) {
+            return Vec::new();
+        }
+        let end = offset.saturating_add(limit).min(self.get_symptom_count(circle_id));
+        (offset..end)
+            .filter_map(|index| self.feedback.journal.symptoms.get(&(circle_id, index)))
+            .map(|mut entry| {
+                if self.is_erased(circle_id, entry.logged_by) {
+                    entry.notes_hash = PURGED_HASH;
+                    entry.logged_by = self.shown_address(circle_id, entry.logged_by);
+                }
+                entry
+            })
+            .collect()
+    }
+
+    /// Get one symptom's daily entries and mean severity, up to 92 days, empty unless `viewer` has consent
+    pub fn get_symptom_trend_for(
+        &self,
+        viewer: Address,
+        circle_id: u64,
+        symptom_hash: [u8; 32],
+        from_day: u64,
+        days: u64,
+    ) -> Vec<SymptomTrendPoint> {
+        if !self.has_consent(circle_id, ConsentScope::Symptoms, viewer) {
+            return Vec::new();
+        }
+        let end = from_day.saturating_add(days.min(MAX_TREND_DAYS));
+        (from_day..end)
+            .map(|day| {
+                let (entries, severity_sum) = self.feedback.journal.symptom_days
+                    .get(&(circle_id, symptom_hash, day))
+                    .unwrap_or((0, 0));
+                SymptomTrendPoint {
+                    day,
+                    entries,
+                    average_severity: Self::survey_average((severity_sum, entries)),
+                }
+            })
+            .collect()
+    }
+
+    /// Get the number of meals logged for a circle
+    pub fn get_meal_count(&self, circle_id: u64) -> u64 {
+        self.feedback.journal.meal_count.get(&circle_id).unwrap_or(0)
+    }
+
+    /// Get a page of the meal log, oldest first, empty unless `viewer` has consent
+    pub fn get_meal_log_for(&self, viewer: Address, circle_id: u64, offset: u64, limit: u64) -> Vec<MealEntry> {
+        if !self.has_consent(circle_id, ConsentScope::Nutrition, viewer) {
+            return Vec::new();
+        }
+        let end = offset.saturating_add(limit).min(self.get_meal_count(circle_id));
+        (offset..end)
+            .filter_map(|index| self.feedback.journal.meals.get(&(circle_id, index)))
+            .map(|mut entry| {
+                if self.is_erased(circle_id, entry.logged_by) {
+                    entry.notes_hash = PURGED_HASH;
+                    entry.logged_by = self.shown_address(circle_id, entry.logged_by);
+                }
+                entry
+            })
+            .collect()
+    }
+
+    /// Get meals and mean share eaten per local day, up to 92 days, empty unless `viewer` has consent
+    pub fn get_meal_days_for(&self, viewer: Address, circle_id: u64, from_day: u64, days: u64) -> Vec<MealDay> {
+        if !self.has_consent(circle_id, ConsentScope::Nutrition, viewer) {
+            return Vec::new();
+        }
+        let end = from_day.saturating_add(days.min(MAX_TREND_DAYS));
+        (from_day..end)
+            .map(|day| {
+                let (meals, consumed_sum) = self.feedback.journal.meal_days
+                    .get(&(circle_id, day))
+                    .unwrap_or((0, 0));
+                MealDay {
+                    day,
+                    meals,
+                    average_consumed_pct: Self::survey_average((consumed_sum, meals)),
+                }
+            })
+            .collect()
+    }
+
+    /// Get the number of incidents reported in a circle
+    pub fn get_incident_count(&self, circle_id: u64) -> u64 {
+        self.feedback.journal.incident_count.get(&circle_id).unwrap_or(0)
+    }
+
+    /// Get the number of medications added to a circle, stopped ones included
+    pub fn get_medication_count(&self, circle_id: u64) -> u64 {
+        self.feedback.journal.medication_count.get(&circle_id).unwrap_or(0)
+    }
+
+    /// Get a page of the medication list, oldest first, empty unless `viewer` has consent
+    pub fn get_medications_for(&self, viewer: Address, circle_id: u64, offset: u64, limit: u64) -> Vec<Medication> {
+        if !self.has_consent(circle_id, ConsentScope::Medications, viewer) {
+            return Vec::new();
+        }
+        let end = offset.saturating_add(limit).min(self.get_medication_count(circle_id));
+        (offset..end)
+            .filter_map(|index| self.feedback.journal.medications.get(&(circle_id, index)))
+            .map(|mut medication| {
+                medication.added_by = self.shown_address(circle_id, medication.added_by);
+                medication
+            })
+            .collect()
+    }
+
+    /// Get a page of the incident log, oldest first, empty unless `viewer` has consent
+    pub fn get_incidents_for(&self, viewer: Address, circle_id: u64, offset: u64, limit: u64) -> Vec<IncidentReport> {
+        if !self.has_consent(circle_id, ConsentScope::Incidents, viewer) {
+            return Vec::new();
+        }
+        let end = offset.saturating_add(limit).min(self.get_incident_count(circle_id));
+        (offset..end)
+            .filter_map(|index| self.feedback.journal.incidents.get(&(circle_id, index)))
+            .map(|mut report| {
+                if self.is_erased(circle_id, report.reported_by) {
+                    report.details_hash = PURGED_HASH;
+                    report.reported_by = self.shown_address(circle_id, report.reported_by);
+                }
+                report
+            })
+            .collect()
+    }
+
+    /// Get the survey submitted for a circle's week, month or care episode
+    pub fn get_survey(&self, circle_id: u64, kind: SurveyPeriod, period: u64) -> Option<Survey> {
+        self.feedback.surveys.get(&(circle_id, kind, period)).map(|mut survey| {
+            survey.submitted_by = self.shown_address(circle_id, survey.submitted_by);
+            survey
+        })
+    }
+
+    /// Get a circle's average survey score in hundredths (0 if unrated)
+    pub fn get_circle_survey_average(&self, circle_id: u64) -> u64 {
+        Self::survey_average(self.feedback.circle_scores.get(&circle_id).unwrap_or_default())
+    }
+
+    /// Get a caregiver's average survey score across circles in hundredths (0 if unrated)
+    pub fn get_caregiver_survey_average(&self, caregiver: Address) -> u64 {
+        Self::survey_average(self.feedback.caregiver_scores.get(&caregiver).unwrap_or_default())
+    }
+
+    /// Get a caregiver's survey average in hundredths, older scores weighing less (0 if unrated)
+    pub fn get_caregiver_reputation(&self, caregiver: Address) -> u64 {
+        let (sum, count, _) = self.feedback.weighted_scores.get(&caregiver).unwrap_or_default();
+        Self::survey_average((sum, count))
+    }
+
+    /// Get the half-life of survey scores in caregiver reputation, 0 = no decay
+    pub fn get_reputation_half_life(&self) -> u64 {
+        self.community.reputation_half_life_secs.get_or_default()
+    }
+
+    /// Get global statistics
+    pub fn get_stats(&self) -> (u64, u64, u64) {
+        (
+            self.stats.total_circles.get_or_default(),
+            self.stats.total_tasks.get_or_default(),
+            self.stats.total_completions.get_or_default(),
+        )
+    }
+
+    /// Get platform-wide metrics for dashboards
+    pub fn get_platform_stats(&self) -> PlatformStats {
+        let week = week_bucket(self.env().get_block_time());
+        PlatformStats {
+            total_circles: self.stats.total_circles.get_or_default(),
+            active_circles: self.stats.active_circles.get_or_default(),
+            total_tasks: self.stats.total_tasks.get_or_default(),
+            total_completions: self.stats.total_completions.get_or_default(),
+            completions_this_week: self.stats.utc_week_completions.get(&week).unwrap_or(0),
+            unique_caregivers: self.stats.unique_caregivers.get_or_default(),
+        }
+    }
+
+    /// Predict whether `caller` could call `create_task` with these arguments,
+    /// running every check without creating anything
+    pub fn simulate_create_task(
+        &self,
+        caller: Address,
+        circle_id: u64,
+        title: String,
+        assigned_to: Address,
+        priority: u8,
+    ) -> Simulation {
+        let outcome = if self.get_settings(circle_id).sealed_titles {
+            Err(Error::SealedTitleRequired.into())
+        } else {
+            self.check_task_creation(caller, circle_id, &title, assigned_to, priority)
+        };
+        simulation(outcome)
+    }
+
+    /// Predict whether `caller` could call `complete_task` on a task now,
+    /// running every check without completing it
+    pub fn simulate_complete_task(&self, caller: Address, task_id: u64) -> Simulation {
+        let outcome = match self.load_task(task_id) {
+            Some(task) => self.check_completion(caller, &task, false),
+            None => Err(Error::TaskNotFound.into()),
+        };
+        simulation(outcome)
+    }
+
+    /// Check whether a circle contributes to the research aggregates
+    pub fn is_research_opted_in(&self, circle_id: u64) -> bool {
+        self.stats.research.opted_in.get(&circle_id).unwrap_or(false)
+    }
+
+    /// Get completion distributions pooled across research opt-in circles.
+    /// Only the circle count is filled in until at least 5 circles opted in.
+    pub fn get_research_aggregates(&self) -> ResearchAggregates {
+        let research = &self.stats.research;
+        let opted_in_circles = research.opted_in_circles.get_or_default();
+        if opted_in_circles < MIN_RESEARCH_CIRCLES {
+            return ResearchAggregates {
+                opted_in_circles,
+                ..Default::default()
+            };
+        }
+        let completions_by_priority: Vec<u64> = (0..=MAX_PRIORITY)
+            .map(|priority| research.completions.get(&priority).unwrap_or(0))
+            .collect();
+        let mean_latency_secs = (0..=MAX_PRIORITY)
+            .zip(&completions_by_priority)
+            .map(|(priority, completions)| {
+                research.latency_secs.get(&priority).unwrap_or(0).checked_div(*completions).unwrap_or(0)
+            })
+            .collect();
+        ResearchAggregates {
+            opted_in_circles,
+            completions_by_priority,
+            mean_latency_secs,
+            latency_histogram: (0..=RESEARCH_LATENCY_EDGES_SECS.len() as u8)
+                .map(|bucket| research.latency_buckets.get(&bucket).unwrap_or(0))
+                .collect(),
+        }
+    }
+}
+
+// ==================== Internal Helpers ====================
+
+impl CareCircle {
+    /// Revert if `addr` is suspended in the circle
+    fn assert_not_suspended(&self, circle_id: u64, addr: Address) {
+        if self.is_suspended(circle_id, addr) {
+            self.env().revert(Error::MemberSuspended);
+        }
+    }
+
+    /// Revert with `CircleArchived` once the circle has been merged away
+    fn assert_circle_active(&self, circle_id: u64) {
+        if !self.is_circle_active(circle_id) {
+            self.env().revert(Error::CircleArchived);
+        }
+    }
+
+    /// Suspend or reinstate a member (owner only)
+    fn set_suspended(&mut self, circle_id: u64, member: Address, suspended: bool) {
+        let env = self.env();
+        let caller = env.caller();
+        let circle = self.require_circle(circle_id);
+        
+        if caller != circle.owner {
+            env.revert(Error::NotOwner);
+        }
+        
+        if !self.members.is_member.get(&(circle_id, member)).unwrap_or(false) {
+            env.revert(Error::NotAMember);
+        }
+        
+        if member == circle.owner {
+            env.revert(Error::CannotSuspendOwner);
+        }
+
+        self.store_suspension(circle_id, member, suspended, caller);
+    }
+
+    /// Record a suspension change, audit it and emit its event; callers validate first
+    fn store_suspension(&mut self, circle_id: u64, member: Address, suspended: bool, changed_by: Address) {
+        let env = self.env();
+        self.members.suspended.set(&(circle_id, member), suspended);
+        let routes = self.notification_routes(circle_id);
+        if suspended {
+            self.log_audit(circle_id, changed_by, AuditAction::MemberSuspended, Some(member), circle_id);
+            env.emit_event(MemberSuspended {
+                circle_id,
+                member,
+                suspended_by: changed_by,
+                routes,
+            });
+        } else {
+            self.log_audit(circle_id, changed_by, AuditAction::MemberReinstated, Some(member), circle_id);
+            env.emit_event(MemberReinstated {
+                circle_id,
+                member,
+                reinstated_by: changed_by,
+                routes,
+            });
+        }
+    }
+
+    /// Revert unless the caller owns the circle or is one of its unsuspended guardians
+    fn assert_owner_or_guardian(&self, circle_id: u64, caller: Address) {
+        let circle = self.require_circle(circle_id);
+        if caller != circle.owner && !self.members.guardians.get(&(circle_id, caller)).unwrap_or(false) {
+            self.env().revert(Error::NotOwnerOrGuardian);
+        }
+        self.assert_not_suspended(circle_id, caller);
+    }
+
+    /// Revert unless the caller is the circle's care recipient or one of its guardians, and not suspended
+    fn assert_recipient_or_guardian(&self, circle_id: u64, caller: Address) {
+        let is_guardian = self.members.guardians.get(&(circle_id, caller)).unwrap_or(false);
+        if self.feedback.recipients.get(&circle_id) != Some(caller) && !is_guardian {
+            self.env().revert(Error::NotCareRecipientOrGuardian);
+        }
+        self.assert_not_suspended(circle_id, caller);
+    }
+
+    /// Store a new task and update all counters and indexes; callers validate first
+    fn insert_task(
+        &mut self,
+        circle_id: u64,
+        title: String,
+        assigned_to: Address,
+        created_by: Address,
+        priority: u8,
+    ) -> u64 {
+        let env = self.env();
+        let timestamp = env.get_block_time();
+        if let Err(error) = self.check_new_task(circle_id, &title, priority) {
+            env.revert(error);
+        }
+
+        let id = self.next_task_id.get_or_default();
+        self.next_task_id.set(id + 1);
+        let circle_task_seq = self.next_circle_task_seq(circle_id, id);
+
+        let task = Task {
+            id,
+            circle_id,
+            circle_task_seq,
+            title: title.clone(),
+            assigned_to,
+            created_by,
+            created_at: timestamp,
+            completed: false,
+            completed_at: 0,
+            priority,
+            due_at: 0,
+            estimated_minutes: 0,
+            episode_id: 0,
+        };
+
+        // Store task
+        self.store_task(task);
+        self.flags.task_exists.set(&id, true);
+        self.index_open_task(circle_id, priority, id);
+        
+        // Track assignee workload
+        self.add_workload(circle_id, assigned_to, 0);
+        self.request_acceptance(id, circle_id, assigned_to, created_by);
+        
+        // Update circle task count and index
+        self.push_circle_task(circle_id, id);
+        
+        // Update stats
+        self.stats.total_tasks.add(1);
+        self.log_activity(circle_id, created_by, ActivityKind::TaskCreated, id);
 
         // Emit event
-        self.env().emit_event(CircleCreated {
-            circle_id: id,
-            name,
-            owner,
+        self.env().emit_event(TaskCreated {
+            task_id: id,
+            circle_id,
+            circle_task_seq,
+            title,
+            assigned_to,
+            routes: self.notification_routes(circle_id),
+        });
+
+        id
+    }
+
+    /// Move a task, its indexes and its workload to another circle; callers validate first
+    fn relocate_task(&mut self, mut task: Task, target_circle_id: u64, moved_by: Address) {
+        let env = self.env();
+        let task_id = task.id;
+        let source_circle_id = task.circle_id;
+
+        self.remove_circle_task(source_circle_id, task_id);
+        self.push_circle_task(target_circle_id, task_id);
+        self.unindex_open_task(source_circle_id, task.priority, task_id);
+        self.index_open_task(target_circle_id, task.priority, task_id);
+        self.release_workload(source_circle_id, task.assigned_to, task.estimated_minutes);
+        self.add_workload(target_circle_id, task.assigned_to, task.estimated_minutes);
+        for label in self.task_index.task_labels.get(&task_id).unwrap_or_default() {
+            self.index_label(target_circle_id, task_id, env.hash(label.as_bytes()));
+        }
+        if task.due_at != 0 {
+            // Each circle buckets the due date by its own local week
+            self.uncount_due_date(source_circle_id, task_id);
+            self.count_due_date(target_circle_id, task_id, task.due_at);
+        }
+
+        // Episodes and supplies belong to the source circle
+        self.count_episode_task(&task, false);
+        task.episode_id = 0;
+        self.config.logistics.restocks.set(&task_id, None);
+        task.circle_id = target_circle_id;
+        task.circle_task_seq = self.next_circle_task_seq(target_circle_id, task_id);
+        let circle_task_seq = task.circle_task_seq;
+        self.store_task(task);
+        self.log_activity(source_circle_id, moved_by, ActivityKind::TaskMoved, task_id);
+        self.log_activity(target_circle_id, moved_by, ActivityKind::TaskMoved, task_id);
+        self.log_audit(target_circle_id, moved_by, AuditAction::TaskMoved, None, task_id);
+
+        env.emit_event(TaskMoved {
+            task_id,
+            from_circle_id: source_circle_id,
+            to_circle_id: target_circle_id,
+            circle_task_seq,
+            moved_by,
+            routes: self.notification_routes_for(&[source_circle_id, target_circle_id]),
+        });
+    }
+
+    /// Complete a task or queue it for verification, depending on circle settings
+    fn submit_completion(&mut self, task: Task, completed_by: Address, timestamp: u64) {
+        let env = self.env();
+        let task_id = task.id;
+
+        if self.get_settings(task.circle_id).require_verification {
+            self.workflow.pending_completions.set(&task_id, Some(PendingCompletion {
+                completed_by,
+                submitted_at: timestamp,
+            }));
+            self.log_activity(task.circle_id, completed_by, ActivityKind::CompletionSubmitted, task_id);
+            env.emit_event(CompletionSubmitted {
+                task_id,
+                circle_id: task.circle_id,
+                submitted_by: completed_by,
+                timestamp,
+                routes: self.notification_routes(task.circle_id),
+            });
+            return;
+        }
+
+        self.finalize_completion(task, completed_by, timestamp);
+    }
+
+    /// Check the caller may create a task for `assigned_to`, then insert it
+    fn create_assigned_task(&mut self, circle_id: u64, title: String, assigned_to: Address, priority: u8) -> u64 {
+        let env = self.env();
+        let caller = env.caller();
+        if let Err(error) = self.check_task_creation(caller, circle_id, &title, assigned_to, priority) {
+            env.revert(error);
+        }
+        self.insert_task(circle_id, title, assigned_to, caller, priority)
+    }
+
+    /// Why `caller` could not create a task assigned to `assigned_to`, if anything
+    fn check_task_creation(
+        &self,
+        caller: Address,
+        circle_id: u64,
+        title: &str,
+        assigned_to: Address,
+        priority: u8,
+    ) -> Result<(), OdraError> {
+        // Verify caller is a member
+        if !self.members.is_member.get(&(circle_id, caller)).unwrap_or(false) {
+            return Err(Error::NotAMember.into());
+        }
+        if self.is_suspended(circle_id, caller) {
+            return Err(Error::MemberSuspended.into());
+        }
+        if !self.can_create_tasks(circle_id, caller) {
+            return Err(Error::TaskCreationNotAllowed.into());
+        }
+        
+        // Verify assignee is a member
+        if !self.members.is_member.get(&(circle_id, assigned_to)).unwrap_or(false) {
+            return Err(Error::AssigneeNotAMember.into());
+        }
+        if self.is_suspended(circle_id, assigned_to) {
+            return Err(Error::MemberSuspended.into());
+        }
+        if !self.has_assignment_capacity(circle_id, assigned_to) {
+            return Err(Error::AssignmentLimitReached.into());
+        }
+        self.check_new_task(circle_id, title, priority)
+    }
+
+    /// Why a task with this title and priority could not be added to the circle, if anything
+    fn check_new_task(&self, circle_id: u64, title: &str, priority: u8) -> Result<(), OdraError> {
+        if !self.is_circle_active(circle_id) {
+            return Err(Error::CircleArchived.into());
+        }
+        
+        if title.len() > MAX_TITLE_LEN {
+            return Err(Error::TitleTooLong.into());
+        }
+        
+        if priority > MAX_PRIORITY {
+            return Err(Error::InvalidPriority.into());
+        }
+        Ok(())
+    }
+
+    /// Validate a completion attempt, consult the circle's proof verifier, then
+    /// open co-signing or submit the completion
+    fn start_completion(&mut self, task_id: u64, proof: Option<Bytes>) {
+        let env = self.env();
+        let caller = env.caller();
+        let timestamp = env.get_block_time();
+
+        let task = self.require_task(task_id);
+        if let Err(error) = self.check_completion(caller, &task, proof.is_some()) {
+            env.revert(error);
+        }
+        
+        if let (Some(verifier), Some(proof)) = (self.active_verifier(task.circle_id), proof) {
+            if !ProofVerifierContractRef::new(env.clone(), verifier).verify(task_id, proof) {
+                env.revert(Error::ProofRejected);
+            }
+        }
+
+        if self.workflow.required_cosigners.get(&task_id).unwrap_or(0) > 0 {
+            let round = self.workflow.cosign_rounds.get(&task_id).unwrap_or(0) + 1;
+            self.workflow.cosign_rounds.set(&task_id, round);
+            self.workflow.cosign_requests.set(&task_id, Some(CosignRequest {
+                completed_by: caller,
+                submitted_at: timestamp,
+                round,
+                signatures: 0,
+            }));
+            self.log_activity(task.circle_id, caller, ActivityKind::CompletionSubmitted, task_id);
+            env.emit_event(CompletionSubmitted {
+                task_id,
+                circle_id: task.circle_id,
+                submitted_by: caller,
+                timestamp,
+                routes: self.notification_routes(task.circle_id),
+            });
+            return;
+        }
+
+        self.submit_completion(task, caller, timestamp);
+    }
+
+    /// Why `caller` could not start completing a task, if anything, short of
+    /// the verifier rejecting the proof
+    fn check_completion(&self, caller: Address, task: &Task, has_proof: bool) -> Result<(), OdraError> {
+        let task_id = task.id;
+        if task.completed {
+            return Err(Error::AlreadyCompleted.into());
+        }
+        
+        let is_delegate = self.workflow.delegates.get(&task_id).flatten() == Some(caller);
+        if caller != task.assigned_to && !is_delegate && !self.can_complete_shared(task, caller) {
+            return Err(Error::NotAssignee.into());
+        }
+        if self.is_suspended(task.circle_id, caller) {
+            return Err(Error::MemberSuspended.into());
+        }
+        
+        if self.workflow.awaiting_acceptance.get(&task_id).unwrap_or(false) {
+            return Err(Error::AssignmentNotAccepted.into());
+        }
+        
+        if self.workflow.pending_completions.get(&task_id).flatten().is_some() {
+            return Err(Error::CompletionPendingVerification.into());
+        }
+        
+        if self.workflow.cosign_requests.get(&task_id).flatten().is_some() {
+            return Err(Error::CoSignaturesPending.into());
+        }
+        
+        match (self.active_verifier(task.circle_id).is_some(), has_proof) {
+            (false, true) => Err(Error::NoProofVerifier.into()),
+            (true, false) => Err(Error::ProofRequired.into()),
+            _ => Ok(()),
+        }
+    }
+
+    /// The proof verifier completions in the circle must pass, unless proof verifiers are switched off
+    fn active_verifier(&self, circle_id: u64) -> Option<Address> {
+        self.config.verifiers.get(&circle_id).flatten()
+            .filter(|_| self.is_feature_enabled(Feature::ProofVerifiers))
+    }
+
+    /// Approve or reject a pending completion
+    fn resolve_pending(&mut self, task: Task, pending: PendingCompletion, approved: bool, resolved_by: Address) {
+        let task_id = task.id;
+        self.workflow.pending_completions.set(&task_id, None);
+        if approved {
+            self.finalize_completion(task, pending.completed_by, pending.submitted_at);
+        } else {
+            self.log_activity(task.circle_id, resolved_by, ActivityKind::CompletionRejected, task_id);
+            self.env().emit_event(CompletionRejected {
+                task_id,
+                circle_id: task.circle_id,
+                rejected_by: resolved_by,
+                routes: self.notification_routes(task.circle_id),
+            });
+        }
+    }
+
+    /// Mark a task completed and update workload, stats and SLA; callers validate first
+    fn finalize_completion(&mut self, mut task: Task, completed_by: Address, timestamp: u64) {
+        let env = self.env();
+        let task_id = task.id;
+
+        let circle_id = task.circle_id;
+        let assigned_to = task.assigned_to;
+        self.unindex_open_task(circle_id, task.priority, task_id);
+        
+        // Release assignee workload
+        self.release_workload(circle_id, assigned_to, task.estimated_minutes);
+        
+        // Update circle and global stats
+        self.increment_circle_completions(circle_id);
+        if let Some(secondary_id) = self.community.sharing.secondary_circle.get(&task_id) {
+            self.increment_circle_completions(secondary_id);
+        }
+        self.record_platform_completion(completed_by, timestamp);
+        self.record_weekly_completion(&task, completed_by, timestamp);
+        
+        // Record latency against the circle SLA, and lateness against the due date
+        self.record_sla(&task, timestamp);
+        self.record_lateness(&task, completed_by, timestamp);
+        self.record_research_completion(&task, timestamp);
+        let verified = self.workflow.verified.get(&task_id).unwrap_or(false);
+        if verified {
+            self.credit_referral(circle_id, completed_by, timestamp);
+        }
+        self.log_activity(circle_id, completed_by, ActivityKind::TaskCompleted, task_id);
+        if verified {
+            self.append_receipt(completed_by, &CompletionReceipt {
+                task_id,
+                circle_id,
+                timestamp,
+            });
+        }
+
+        // Mark as completed
+        task.completed = true;
+        task.completed_at = timestamp;
+        self.record_episode_completion(&task, completed_by);
+        self.apply_restock(&task, timestamp);
+        self.queue_hook(circle_id, HookKind::TaskCompleted, task_id);
+        self.store_task(task);
+
+        // Emit event - THIS IS THE VERIFIABLE PROOF!
+        env.emit_event(TaskCompleted {
+            task_id,
+            circle_id,
+            assigned_to,
+            completed_by,
+            timestamp,
+            agency: self.community.agencies.member_agency.get(&(circle_id, completed_by)).flatten(),
+            evidence_count: self.get_evidence_count(task_id),
+            routes: self.notification_routes(circle_id),
+        });
+
+        let helpers = self.workflow.helpers.get(&task_id).unwrap_or_default();
+        if !helpers.is_empty() {
+            env.emit_event(HelpersCredited {
+                task_id,
+                circle_id,
+                helpers,
+                routes: self.notification_routes(circle_id),
+            });
+        }
+    }
+
+    /// Load a reader, reverting unless the caller is its operator
+    fn reader_for_operator(&self, reader_id: u64) -> Reader {
+        let env = self.env();
+        let Some(reader) = self.get_reader(reader_id) else {
+            env.revert(Error::ReaderNotFound);
+        };
+        if reader.operator != env.caller() {
+            env.revert(Error::NotReaderOperator);
+        }
+        reader
+    }
+
+    /// Revert unless the contract admin left the feature switched on
+    fn assert_feature_enabled(&self, feature: Feature) {
+        if !self.is_feature_enabled(feature) {
+            self.env().revert(Error::FeatureDisabled);
+        }
+    }
+
+    /// Revert unless the caller is the contract admin
+    fn assert_admin(&self) {
+        if self.community.admin.get() != Some(self.env().caller()) {
+            self.env().revert(Error::NotAdmin);
+        }
+    }
+
+    /// Every caregiver an agency has registered, including deregistered ones
+    fn listed_caregivers(&self, agency: Address) -> Vec<Address> {
+        let agencies = &self.community.agencies;
+        let count = agencies.caregiver_count.get(&agency).unwrap_or(0);
+        (0..count)
+            .filter_map(|index| agencies.caregivers.get(&(agency, index)))
+            .collect()
+    }
+
+    /// Suspend a member the agency brought into the circle; false if not theirs or already suspended
+    fn suspend_agency_member(&mut self, circle_id: u64, agency: Address, member: Address) -> bool {
+        if self.community.agencies.member_agency.get(&(circle_id, member)).flatten() != Some(agency)
+            || self.members.suspended.get(&(circle_id, member)).unwrap_or(false)
+        {
+            return false;
+        }
+        self.set_suspended(circle_id, member, true);
+        true
+    }
+
+    /// A page of a caregiver's receipts as work records, keeping those `shown` accepts
+    fn work_history(
+        &self,
+        caregiver: Address,
+        offset: u64,
+        limit: u64,
+        shown: impl Fn(&CompletionReceipt) -> bool,
+    ) -> Vec<WorkRecord> {
+        let count = self.get_receipt_count(caregiver);
+        let end = offset.saturating_add(limit).min(count);
+        (offset..end)
+            .filter_map(|index| self.history.receipts.get(&(caregiver, index)))
+            .filter(|receipt| shown(receipt))
+            .map(|receipt| self.work_record(receipt))
+            .collect()
+    }
+
+    /// Expand a stored receipt with its task, verification and survey rating
+    fn work_record(&self, receipt: CompletionReceipt) -> WorkRecord {
+        let week = self.week_of(receipt.circle_id, receipt.timestamp);
+        let rating = self.feedback.surveys.get(&(receipt.circle_id, SurveyPeriod::Week, week))
+            .map(|survey| {
+                let sum: u64 = survey.scores.iter().map(|score| *score as u64).sum();
+                Self::survey_average((sum, survey.scores.len() as u64))
+            })
+            .unwrap_or(0);
+        WorkRecord {
+            circle_id: receipt.circle_id,
+            task_id: receipt.task_id,
+            priority: self.load_task(receipt.task_id).map(|task| task.priority).unwrap_or(0),
+            completed_at: receipt.timestamp,
+            verified: self.workflow.verified.get(&receipt.task_id).unwrap_or(false),
+            rating,
+        }
+    }
+
+    /// Extend a caregiver's receipt chain with a completion
+    fn append_receipt(&mut self, caregiver: Address, receipt: &CompletionReceipt) {
+        let head = self.receipt_hash(&self.get_receipt_head(caregiver), receipt);
+        let index = self.get_receipt_count(caregiver);
+        self.history.receipt_heads.set(&caregiver, head);
+        self.history.receipts.set(&(caregiver, index), receipt.clone());
+        self.history.receipt_counts.add(&caregiver, 1);
+
+        self.env().emit_event(ReceiptAppended {
+            caregiver,
+            task_id: receipt.task_id,
+            circle_id: receipt.circle_id,
+            timestamp: receipt.timestamp,
+            head,
+            routes: self.notification_routes(receipt.circle_id),
+        });
+    }
+
+    /// H(prev || task_id || circle_id || timestamp), integers big-endian
+    fn receipt_hash(&self, prev: &[u8; 32], receipt: &CompletionReceipt) -> [u8; 32] {
+        let mut preimage = Vec::with_capacity(56);
+        preimage.extend_from_slice(prev);
+        preimage.extend_from_slice(&receipt.task_id.to_be_bytes());
+        preimage.extend_from_slice(&receipt.circle_id.to_be_bytes());
+        preimage.extend_from_slice(&receipt.timestamp.to_be_bytes());
+        self.env().hash(preimage)
+    }
+
+    /// Set or clear a task's completion delegate (assignee or owner only)
+    fn set_completion_delegate(&mut self, task_id: u64, delegate: Option<Address>) {
+        let env = self.env();
+        let caller = env.caller();
+
+        let task = self.require_task(task_id);
+        let circle = self.require_circle(task.circle_id);
+
+        if caller != task.assigned_to && caller != circle.owner {
+            env.revert(Error::NotAssigneeOrOwner);
+        }
+        self.assert_not_suspended(task.circle_id, caller);
+
+        if task.completed {
+            env.revert(Error::AlreadyCompleted);
+        }
+
+        self.workflow.delegates.set(&task_id, delegate);
+        self.log_activity(task.circle_id, caller, ActivityKind::CompletionDelegated, task_id);
+    }
+
+    /// Whether a secondary circle member may complete a shared task
+    fn can_complete_shared(&self, task: &Task, caller: Address) -> bool {
+        if !self.community.sharing.shared_completion.get(&task.id).unwrap_or(false) {
+            return false;
+        }
+        match self.community.sharing.secondary_circle.get(&task.id) {
+            Some(secondary_id) => self.members.is_member.get(&(secondary_id, caller)).unwrap_or(false),
+            None => false,
+        }
+    }
+
+    /// Append an entry to a circle's rolling activity feed, overwriting the oldest
+    fn log_activity(&mut self, circle_id: u64, actor: Address, kind: ActivityKind, subject_id: u64) {
+        let timestamp = self.env().get_block_time();
+        self.members.last_active.set(&(circle_id, actor), timestamp);
+        let seq = self.history.activity_count.get(&circle_id).unwrap_or(0);
+        self.history.activity.set(&(circle_id, seq % ACTIVITY_LOG_SIZE), ActivityEntry {
+            actor,
+            kind,
+            subject_id,
+            timestamp,
+        });
+        self.history.activity_count.set(&circle_id, seq + 1);
+    }
+
+    /// Add a member to a circle and emit MemberAdded
+    fn insert_member(&mut self, circle_id: u64, member_addr: Address, added_by: Address) {
+        // Check if already a member
+        if self.members.is_member.get(&(circle_id, member_addr)).unwrap_or(false) {
+            self.env().revert(Error::AlreadyMember);
+        }
+        
+        if !self.is_circle_active(circle_id) {
+            self.env().revert(Error::CircleArchived);
+        }
+
+        // Add member
+        let member_idx = self.members.circle_member_count.get(&circle_id).unwrap_or(0);
+        self.members.circle_members.set(&(circle_id, member_idx), member_addr);
+        self.members.circle_member_count.set(&circle_id, member_idx + 1);
+        self.members.is_member.set(&(circle_id, member_addr), true);
+        self.log_activity(circle_id, added_by, ActivityKind::MemberAdded, circle_id);
+
+        // Emit event
+        self.env().emit_event(MemberAdded {
+            circle_id,
+            member: member_addr,
+            added_by,
+            routes: self.notification_routes(circle_id),
+        });
+        if MEMBER_MILESTONES.contains(&(member_idx + 1)) {
+            self.emit_milestone(circle_id, MilestoneKind::Members, member_idx + 1);
+        }
+    }
+
+    /// Append an entry to a circle's audit log
+    fn log_audit(
+        &mut self,
+        circle_id: u64,
+        actor: Address,
+        action: AuditAction,
+        target: Option<Address>,
+        subject_id: u64,
+    ) {
+        let timestamp = self.env().get_block_time();
+        // Administrative actions count as activity even without a feed entry
+        self.members.last_active.set(&(circle_id, actor), timestamp);
+        let seq = self.history.audit_count.get(&circle_id).unwrap_or(0);
+        self.history.audit.set(&(circle_id, seq), AuditEntry {
+            seq,
+            actor,
+            action,
+            target,
+            subject_id,
+            timestamp,
         });
+        self.history.audit_count.set(&circle_id, seq + 1);
+    }
+
+    /// Change a task's due date and move it between weekly due buckets
+    fn replace_due_date(&mut self, task: &mut Task, due_at: u64) {
+        if task.due_at != 0 {
+            self.uncount_due_date(task.circle_id, task.id);
+        }
+        if due_at != 0 {
+            self.count_due_date(task.circle_id, task.id, due_at);
+        }
+        task.due_at = due_at;
+    }
+
+    /// Count a due date in the circle's week bucket for it, remembering the
+    /// week so a later change of time zone cannot misplace the count
+    fn count_due_date(&mut self, circle_id: u64, task_id: u64, due_at: u64) {
+        let week = local_week(&self.get_settings(circle_id), due_at);
+        let weekly = &mut self.stats.weekly;
+        weekly.due.add(&(circle_id, week), 1);
+        weekly.due_week.set(&task_id, week);
+    }
+
+    /// Take a task's due date out of the week bucket it was counted under
+    fn uncount_due_date(&mut self, circle_id: u64, task_id: u64) {
+        let weekly = &mut self.stats.weekly;
+        if let Some(week) = weekly.due_week.get(&task_id) {
+            weekly.due.subtract(&(circle_id, week), 1);
+        }
+    }
+
+    /// Whether the circle's visibility lets `viewer` read task titles.
+    ///
+    /// Only the `*_for` views consult this. It shapes what a client shows,
+    /// it keeps nothing confidential: chain state is public.
+    fn can_view_titles(&self, circle_id: u64, viewer: Address) -> bool {
+        self.get_settings(circle_id).visibility == Visibility::Public
+            || self.members.is_member.get(&(circle_id, viewer)).unwrap_or(false)
+    }
+
+    /// Whether the circle's visibility lets `viewer` read its statistics
+    fn can_view_stats(&self, circle_id: u64, viewer: Address) -> bool {
+        self.get_settings(circle_id).visibility != Visibility::Private
+            || self.members.is_member.get(&(circle_id, viewer)).unwrap_or(false)
+    }
+
+    /// Whether `viewer` may read a task's title, as a member of its own or its secondary circle
+    fn can_view_task(&self, task: &Task, viewer: Address) -> bool {
+        self.can_view_titles(task.circle_id, viewer)
+            || self.community.sharing.secondary_circle.get(&task.id)
+                .is_some_and(|secondary| self.members.is_member.get(&(secondary, viewer)).unwrap_or(false))
+    }
+
+    /// Clear a task's title unless `viewer` may read it
+    fn redact_task(&self, mut task: Task, viewer: Address) -> Task {
+        if !self.can_view_task(&task, viewer) {
+            task.title.clear();
+        }
+        task
+    }
+
+    /// A task with erased members shown by their pseudonym
+    fn shown_task(&self, mut task: Task) -> Task {
+        task.assigned_to = self.shown_address(task.circle_id, task.assigned_to);
+        task.created_by = self.shown_address(task.circle_id, task.created_by);
+        task
+    }
 
-        id
+    /// Whether a member's data has been erased from a circle
+    fn is_erased(&self, circle_id: u64, address: Address) -> bool {
+        self.config.private.pseudonyms.get(&(circle_id, address)).is_some()
     }
 
-    /// Add a member to a circle (only owner can add)
-    pub fn add_member(&mut self, circle_id: u64, member_addr: Address) {
-        let env = self.env();
-        let caller = env.caller();
+    /// The address views show for someone: their pseudonym once erased
+    fn shown_address(&self, circle_id: u64, address: Address) -> Address {
+        self.config.private.pseudonyms.get(&(circle_id, address)).unwrap_or(address)
+    }
 
-        // Get circle and verify caller is owner
-        let mut circle = self.circles.get(&circle_id)
-            .expect("Circle not found");
-        
-        if caller != circle.owner {
-            env.revert(OdraError::user(1)); // Not owner
+    /// A week snapshot with its top contributor shown by pseudonym once erased
+    fn shown_snapshot(&self, circle_id: u64, mut snapshot: WeeklySnapshot) -> WeeklySnapshot {
+        snapshot.top_contributor = snapshot.top_contributor.map(|addr| self.shown_address(circle_id, addr));
+        snapshot
+    }
+
+    /// A health record with its last editor shown by pseudonym once erased
+    fn shown_record(&self, circle_id: u64, mut record: HealthRecord) -> HealthRecord {
+        record.updated_by = self.shown_address(circle_id, record.updated_by);
+        record
+    }
+
+    /// Notification routes to attach to a circle's events
+    fn notification_routes(&self, circle_id: u64) -> Vec<[u8; 32]> {
+        self.config.notification_routes.get(&circle_id).unwrap_or_default()
+    }
+
+    /// Notification routes of several circles, without duplicates
+    fn notification_routes_for(&self, circle_ids: &[u64]) -> Vec<[u8; 32]> {
+        let mut routes = Vec::new();
+        for route in circle_ids.iter().flat_map(|circle_id| self.notification_routes(*circle_id)) {
+            if !routes.contains(&route) {
+                routes.push(route);
+            }
         }
-        
-        // Check if already a member
-        if self.is_member.get(&(circle_id, member_addr)).unwrap_or(false) {
-            env.revert(OdraError::user(2)); // Already member
+        routes
+    }
+
+    /// Day (since 1970-01-01) of a block time in the circle's local time
+    fn day_of(&self, circle_id: u64, timestamp: u64) -> u64 {
+        local_time(&self.get_settings(circle_id), timestamp) / MILLIS_PER_DAY
+    }
+
+    /// Week bucket of a block time in the circle's local time
+    fn week_of(&self, circle_id: u64, timestamp: u64) -> u64 {
+        local_week(&self.get_settings(circle_id), timestamp)
+    }
+
+    /// Count a completion towards the platform totals
+    fn record_platform_completion(&mut self, completed_by: Address, timestamp: u64) {
+        let stats = &mut self.stats;
+        stats.total_completions.add(1);
+        stats.utc_week_completions.add(&week_bucket(timestamp), 1);
+        if !stats.is_caregiver.get(&completed_by).unwrap_or(false) {
+            stats.is_caregiver.set(&completed_by, true);
+            stats.unique_caregivers.add(1);
+        }
+    }
+
+    /// Count a completion towards the circle's current week bucket
+    fn record_weekly_completion(&mut self, task: &Task, completed_by: Address, timestamp: u64) {
+        let circle_id = task.circle_id;
+        let settings = self.get_settings(circle_id);
+        let weekly = &mut self.stats.weekly;
+        let key = (circle_id, local_week(&settings, timestamp));
+        weekly.completions.add(&key, 1);
+
+        if task.due_at != 0 && !completed_late(&settings, task, timestamp) {
+            let due_week = weekly.due_week.get(&task.id).unwrap_or_else(|| local_week(&settings, task.due_at));
+            weekly.due_met.add(&(circle_id, due_week), 1);
         }
 
-        // Add member
-        let member_idx = self.circle_member_count.get(&circle_id).unwrap_or(0);
-        self.circle_members.set(&(circle_id, member_idx), member_addr);
-        self.circle_member_count.set(&circle_id, member_idx + 1);
-        self.is_member.set(&(circle_id, member_addr), true);
-        
-        // Update circle member count
-        circle.member_count += 1;
-        self.circles.set(&circle_id, circle);
+        let month = local_month(&settings, timestamp);
+        weekly.member_month_completions.add(&(circle_id, month, completed_by), 1);
 
-        // Emit event
-        self.env().emit_event(MemberAdded {
+        let member_key = (key.0, key.1, completed_by);
+        weekly.member_completions.add(&member_key, 1);
+        let count = weekly.member_completions.get(&member_key).unwrap_or(0);
+        if weekly.top_contributor.get(&key).is_none_or(|(_, top)| count > top) {
+            weekly.top_contributor.set(&key, (completed_by, count));
+        }
+    }
+
+    /// Share of tracked completions that met their target, in basis points (10000 when none were tracked)
+    fn compliance_bps(stats: SlaPeriodStats) -> u64 {
+        if stats.tracked == 0 {
+            return BPS_DENOMINATOR;
+        }
+        stats.met * BPS_DENOMINATOR / stats.tracked
+    }
+
+    /// Decay a caregiver's weighted score tally to now, then add a survey's scores at full weight
+    fn add_weighted_scores(&mut self, caregiver: Address, sum: u64, count: u64) {
+        let now = self.env().get_block_time();
+        let half_life = self.get_reputation_half_life().saturating_mul(MILLIS_PER_SECOND);
+        let (weighted_sum, weighted_count, decayed_at) = self.feedback.weighted_scores.get(&caregiver).unwrap_or_default();
+        let elapsed = now.saturating_sub(decayed_at);
+        self.feedback.weighted_scores.set(&caregiver, (
+            decayed(weighted_sum, elapsed, half_life).saturating_add(sum * REPUTATION_WEIGHT_SCALE),
+            decayed(weighted_count, elapsed, half_life).saturating_add(count * REPUTATION_WEIGHT_SCALE),
+            now,
+        ));
+    }
+
+    /// Average of a (sum, count) score tally in hundredths
+    fn survey_average((sum, count): (u64, u64)) -> u64 {
+        if count == 0 {
+            return 0;
+        }
+        sum * SURVEY_AVERAGE_SCALE / count
+    }
+
+    /// Read a circle, reverting with `Error::CircleNotFound` if there is none
+    fn require_circle(&self, circle_id: u64) -> StoredCircle {
+        self.circles.get(&circle_id).unwrap_or_else(|| self.env().revert(Error::CircleNotFound))
+    }
+
+    /// Read a task, reverting with `Error::TaskNotFound` if there is none
+    fn require_task(&self, task_id: u64) -> Task {
+        self.load_task(task_id).unwrap_or_else(|| self.env().revert(Error::TaskNotFound))
+    }
+
+    /// Read a task, expanding its compact storage form
+    fn load_task(&self, task_id: u64) -> Option<Task> {
+        let stored = self.tasks.get(&task_id)?;
+        let completed = stored.status & COMPLETED_FLAG != 0;
+        let millis = stored.millis;
+        let time = |seconds: u32, index: u32| {
+            seconds as u64 * MILLIS_PER_SECOND + ((millis >> (index * MILLIS_BITS)) & MILLIS_MASK) as u64
+        };
+        Some(Task {
+            id: task_id,
+            circle_id: stored.circle_id as u64,
+            circle_task_seq: stored.circle_task_seq as u64,
+            title: stored.title,
+            assigned_to: stored.assigned_to,
+            created_by: stored.created_by,
+            created_at: time(stored.created_at, 0),
+            completed,
+            completed_at: if completed { time(stored.completed_at, 1) } else { 0 },
+            priority: stored.status & PRIORITY_MASK,
+            due_at: time(stored.due_at, 2),
+            estimated_minutes: stored.estimated_minutes,
+            episode_id: stored.episode_id as u64,
+        })
+    }
+
+    /// Write a task in its compact storage form, reverting if a field does not fit
+    fn store_task(&mut self, task: Task) {
+        let mut status = task.priority.min(MAX_PRIORITY);
+        if task.completed {
+            status |= COMPLETED_FLAG;
+        }
+        let completed_at = if task.completed { task.completed_at } else { 0 };
+        let seconds = |millis: u64| u32::try_from(millis / MILLIS_PER_SECOND);
+        let (Ok(circle_id), Ok(circle_task_seq), Ok(episode_id), Ok(created_at), Ok(completed_secs), Ok(due_at)) = (
+            u32::try_from(task.circle_id),
+            u32::try_from(task.circle_task_seq),
+            u32::try_from(task.episode_id),
+            seconds(task.created_at),
+            seconds(completed_at),
+            seconds(task.due_at),
+        ) else {
+            self.env().revert(Error::TaskFieldOverflow);
+        };
+        self.tasks.set(&task.id, StoredTask {
             circle_id,
-            member: member_addr,
-            added_by: caller,
+            circle_task_seq,
+            title: task.title,
+            assigned_to: task.assigned_to,
+            created_by: task.created_by,
+            created_at,
+            completed_at: completed_secs,
+            due_at,
+            status,
+            estimated_minutes: task.estimated_minutes,
+            episode_id,
+            millis: [task.created_at, completed_at, task.due_at]
+                .iter()
+                .enumerate()
+                .map(|(index, time)| ((time % MILLIS_PER_SECOND) as u32) << (index as u32 * MILLIS_BITS))
+                .sum(),
         });
     }
 
-    // ==================== Task Management ====================
+    /// Append a task to a circle's task list
+    fn push_circle_task(&mut self, circle_id: u64, task_id: u64) {
+        let task_count = self.task_index.circle_task_count.get(&circle_id).unwrap_or(0);
+        self.task_index.circle_tasks.set(&(circle_id, task_count), task_id);
+        self.task_index.circle_task_slot.set(&(circle_id, task_id), task_count);
+        self.task_index.circle_task_count.set(&circle_id, task_count + 1);
+    }
 
-    /// Create a new task in a circle
-    pub fn create_task(
+    /// Swap-remove a task from a circle's task list
+    fn remove_circle_task(&mut self, circle_id: u64, task_id: u64) {
+        let count = self.task_index.circle_task_count.get(&circle_id).unwrap_or(0);
+        let Some(slot) = self.task_index.circle_task_slot.get(&(circle_id, task_id)) else {
+            return;
+        };
+        if count == 0 || self.task_index.circle_tasks.get(&(circle_id, slot)) != Some(task_id) {
+            return;
+        }
+        let last = count - 1;
+        if slot != last {
+            if let Some(moved) = self.task_index.circle_tasks.get(&(circle_id, last)) {
+                self.task_index.circle_tasks.set(&(circle_id, slot), moved);
+                self.task_index.circle_task_slot.set(&(circle_id, moved), slot);
+            }
+        }
+        self.task_index.circle_task_count.set(&circle_id, last);
+    }
+
+    /// Allocate the next per-circle task number
+    fn next_circle_task_seq(&mut self, circle_id: u64, task_id: u64) -> u64 {
+        let circle_task_seq = self.task_index.circle_task_seq.get(&circle_id).unwrap_or(0) + 1;
+        self.task_index.circle_task_seq.set(&circle_id, circle_task_seq);
+        self.task_index.task_by_seq.set(&(circle_id, circle_task_seq), task_id);
+        circle_task_seq
+    }
+
+    /// Index a task under a label once per circle; stale entries are filtered on read
+    fn index_label(&mut self, circle_id: u64, task_id: u64, label_hash: [u8; 32]) {
+        if self.task_index.label_indexed.get(&(circle_id, task_id, label_hash)).unwrap_or(false) {
+            return;
+        }
+        let index_key = (circle_id, label_hash);
+        let index = self.task_index.label_task_count.get(&index_key).unwrap_or(0);
+        self.task_index.label_tasks.set(&(circle_id, label_hash, index), task_id);
+        self.task_index.label_task_count.set(&index_key, index + 1);
+        self.task_index.label_indexed.set(&(circle_id, task_id, label_hash), true);
+    }
+
+    /// Snapshot a circle's open tasks from its priority buckets; relocating
+    /// swap-removes from them
+    fn open_task_ids(&self, circle_id: u64) -> Vec<u64> {
+        (0..=MAX_PRIORITY)
+            .flat_map(|priority| {
+                let count = self.task_index.priority_bucket_count.get(&(circle_id, priority)).unwrap_or(0);
+                (0..count).filter_map(move |slot| self.task_index.priority_buckets.get(&(circle_id, priority, slot)))
+            })
+            .collect()
+    }
+
+    /// Add an open task to its circle's priority bucket
+    fn index_open_task(&mut self, circle_id: u64, priority: u8, task_id: u64) {
+        let bucket = (circle_id, priority.min(MAX_PRIORITY));
+        let slot = self.task_index.priority_bucket_count.get(&bucket).unwrap_or(0);
+        self.task_index.priority_buckets.set(&(bucket.0, bucket.1, slot), task_id);
+        self.task_index.priority_slot.set(&task_id, slot);
+        self.task_index.priority_bucket_count.set(&bucket, slot + 1);
+    }
+
+    /// Take up to `limit` open tasks from the circle's `priorities` buckets,
+    /// continuing from the keeper's cursor and wrapping around to the lowest
+    /// bucket, so repeated calls visit every open task. Tasks swap-removed
+    /// behind the cursor are reached on the next pass.
+    fn next_open_tasks(
         &mut self,
         circle_id: u64,
-        title: String,
-        assigned_to: Address,
-        priority: u8,
-    ) -> u64 {
-        let env = self.env();
-        let caller = env.caller();
-        let timestamp = env.get_block_time();
+        keeper: OpenTaskKeeper,
+        priorities: RangeInclusive<u8>,
+        limit: u64,
+    ) -> Vec<u64> {
+        let (first, last) = (*priorities.start(), *priorities.end());
+        let bucket_count = |index: &TaskIndex, priority: u8| {
+            index.priority_bucket_count.get(&(circle_id, priority)).unwrap_or(0)
+        };
+        let open: u64 = priorities.clone().map(|priority| bucket_count(&self.task_index, priority)).sum();
+        // Never more than one pass, so no task is examined twice per call
+        let limit = limit.min(open);
 
-        // Verify caller is a member
-        if !self.is_member.get(&(circle_id, caller)).unwrap_or(false) {
-            env.revert(OdraError::user(3)); // Not a member
+        let key = (circle_id, keeper);
+        let (mut priority, mut slot) = self.scheduling.keeper_cursors.get(&key)
+            .filter(|(priority, _)| priorities.contains(priority))
+            .unwrap_or((first, 0));
+        let mut page = Vec::new();
+        let mut examined = 0;
+        while examined < limit {
+            if slot >= bucket_count(&self.task_index, priority) {
+                priority = if priority >= last { first } else { priority + 1 };
+                slot = 0;
+                continue;
+            }
+            if let Some(task_id) = self.task_index.priority_buckets.get(&(circle_id, priority, slot)) {
+                page.push(task_id);
+            }
+            slot += 1;
+            examined += 1;
         }
-        
-        // Verify assignee is a member
-        if !self.is_member.get(&(circle_id, assigned_to)).unwrap_or(false) {
-            env.revert(OdraError::user(4)); // Assignee not a member
+        self.scheduling.keeper_cursors.set(&key, (priority, slot));
+        page
+    }
+
+    /// Swap-remove a task from its circle's priority bucket
+    fn unindex_open_task(&mut self, circle_id: u64, priority: u8, task_id: u64) {
+        let bucket = (circle_id, priority.min(MAX_PRIORITY));
+        let count = self.task_index.priority_bucket_count.get(&bucket).unwrap_or(0);
+        let Some(slot) = self.task_index.priority_slot.get(&task_id) else {
+            return;
+        };
+        if count == 0 || self.task_index.priority_buckets.get(&(bucket.0, bucket.1, slot)) != Some(task_id) {
+            return;
+        }
+        let last = count - 1;
+        if slot != last {
+            if let Some(moved) = self.task_index.priority_buckets.get(&(bucket.0, bucket.1, last)) {
+                self.task_index.priority_buckets.set(&(bucket.0, bucket.1, slot), moved);
+                self.task_index.priority_slot.set(&moved, slot);
+            }
         }
+        self.task_index.priority_bucket_count.set(&bucket, last);
+    }
 
-        let id = self.next_task_id.get_or_default();
-        self.next_task_id.set(id + 1);
+    /// Count an open task and its estimate against a member
+    fn add_workload(&mut self, circle_id: u64, member: Address, minutes: u32) {
+        self.members.open_tasks.add(&(circle_id, member), 1);
+        self.members.open_minutes.add(&(circle_id, member), minutes as u64);
+    }
 
-        let task = Task {
-            id,
+    /// Release an open task and its estimate from a member
+    fn release_workload(&mut self, circle_id: u64, member: Address, minutes: u32) {
+        let key = (circle_id, member);
+        let open_tasks = self.members.open_tasks.get(&key).unwrap_or(0);
+        self.members.open_tasks.set(&key, open_tasks.saturating_sub(1));
+        let open_minutes = self.members.open_minutes.get(&key).unwrap_or(0);
+        self.members.open_minutes.set(&key, open_minutes.saturating_sub(minutes as u64));
+    }
+
+    /// Move an open task and its workload to another assignee
+    fn reassign_task(&mut self, mut task: Task, new_assignee: Address) {
+        self.assert_assignment_capacity(task.circle_id, new_assignee);
+        self.workflow.awaiting_acceptance.set(&task.id, false);
+        self.release_workload(task.circle_id, task.assigned_to, task.estimated_minutes);
+        self.add_workload(task.circle_id, new_assignee, task.estimated_minutes);
+
+        task.assigned_to = new_assignee;
+        self.store_task(task);
+    }
+
+    /// Mark a task as awaiting acceptance if the circle requires it and someone else assigned it
+    fn request_acceptance(&mut self, task_id: u64, circle_id: u64, assignee: Address, assigned_by: Address) {
+        if assignee != assigned_by && self.get_settings(circle_id).require_acceptance {
+            self.workflow.awaiting_acceptance.set(&task_id, true);
+        }
+    }
+
+    /// Increment a circle's completion counter
+    fn increment_circle_completions(&mut self, circle_id: u64) {
+        let completions = self.stats.circle_completions.get(&circle_id).unwrap_or(0) + 1;
+        self.stats.circle_completions.set(&circle_id, completions);
+        if COMPLETION_MILESTONES.contains(&completions) {
+            self.emit_milestone(circle_id, MilestoneKind::Completions, completions);
+        }
+    }
+
+    /// Announce a milestone the circle just reached
+    fn emit_milestone(&self, circle_id: u64, kind: MilestoneKind, value: u64) {
+        self.env().emit_event(CircleMilestone {
             circle_id,
-            title: title.clone(),
-            assigned_to,
-            created_by: caller,
-            created_at: timestamp,
-            completed: false,
-            completed_at: 0,
-            priority,
-        };
+            kind,
+            value,
+            routes: self.notification_routes(circle_id),
+        });
+    }
 
-        // Store task
-        self.tasks.set(&id, task);
-        
-        // Update circle task count
-        let task_count = self.circle_task_count.get(&circle_id).unwrap_or(0);
-        self.circle_task_count.set(&circle_id, task_count + 1);
-        
-        // Update circle
-        let mut circle = self.circles.get(&circle_id).expect("Circle not found");
-        circle.task_count += 1;
-        self.circles.set(&circle_id, circle);
-        
-        // Update stats
-        self.total_tasks.set(self.total_tasks.get_or_default() + 1);
+    /// Announce the circle's latest anniversary the first time a weekly digest
+    /// is finalized afterwards
+    fn check_anniversary(&mut self, circle_id: u64, created_at: u64, timestamp: u64) {
+        let years = timestamp.saturating_sub(created_at) / MILLIS_PER_YEAR;
+        if years > self.history.anniversaries.get(&circle_id).unwrap_or(0) {
+            self.history.anniversaries.set(&circle_id, years);
+            self.emit_milestone(circle_id, MilestoneKind::Anniversary, years);
+        }
+    }
 
-        // Emit event
-        self.env().emit_event(TaskCreated {
-            task_id: id,
+    /// Credit the referrer of a recruit whose first verified completion this is
+    fn credit_referral(&mut self, circle_id: u64, recruit: Address, timestamp: u64) {
+        let referrals = &mut self.config.referrals;
+        let Some(mut referral) = referrals.recruits.get(&(circle_id, recruit)) else {
+            return;
+        };
+        if referral.credited_at.is_some() {
+            return;
+        }
+        referral.credited_at = Some(timestamp);
+        let referrer = referral.referrer;
+        referrals.recruits.set(&(circle_id, recruit), referral);
+        let points = referrals.points.get(&(circle_id, referrer)).unwrap_or(0) + REFERRAL_POINTS;
+        referrals.points.set(&(circle_id, referrer), points);
+
+        self.env().emit_event(ReferralCredited {
             circle_id,
-            title,
-            assigned_to,
+            referrer,
+            recruit,
+            points,
+            routes: self.notification_routes(circle_id),
         });
+    }
 
-        id
+    /// Pool a completion into the research aggregates if its circle opted in
+    fn record_research_completion(&mut self, task: &Task, completed_at: u64) {
+        let research = &mut self.stats.research;
+        if !research.opted_in.get(&task.circle_id).unwrap_or(false) {
+            return;
+        }
+        let latency_secs = completed_at.saturating_sub(task.created_at) / MILLIS_PER_SECOND;
+        research.completions.add(&task.priority, 1);
+        research.latency_secs.add(&task.priority, latency_secs);
+        let bucket = RESEARCH_LATENCY_EDGES_SECS.iter()
+            .filter(|edge| latency_secs >= **edge)
+            .count() as u8;
+        research.latency_buckets.add(&bucket, 1);
     }
 
-    /// Complete a task - creates verifiable on-chain proof!
-    pub fn complete_task(&mut self, task_id: u64) {
+    /// Record completion latency and update SLA compliance for the completion week
+    fn record_sla(&mut self, task: &Task, completed_at: u64) {
+        let latency_secs = completed_at.saturating_sub(task.created_at) / MILLIS_PER_SECOND;
+        self.scheduling.sla.task_latency_secs.set(&task.id, latency_secs);
+
+        let target_secs = self.scheduling.sla.targets.get(&task.circle_id)
+            .and_then(|targets| targets.get(task.priority as usize).copied())
+            .unwrap_or(0);
+        if target_secs == 0 {
+            return;
+        }
+
+        let key = (task.circle_id, self.week_of(task.circle_id, completed_at));
+        let mut stats = self.scheduling.sla.period_stats.get(&key).unwrap_or_default();
+        stats.tracked += 1;
+        if latency_secs <= target_secs {
+            stats.met += 1;
+        } else {
+            self.env().emit_event(SlaBreached {
+                task_id: task.id,
+                circle_id: task.circle_id,
+                priority: task.priority,
+                target_secs,
+                latency_secs,
+                routes: self.notification_routes(task.circle_id),
+            });
+        }
+        self.scheduling.sla.period_stats.set(&key, stats);
+    }
+
+    /// Record whether a completion met its due date and update on-time rates
+    fn record_lateness(&mut self, task: &Task, completed_by: Address, completed_at: u64) {
+        if task.due_at == 0 {
+            return;
+        }
+        let settings = self.get_settings(task.circle_id);
+        let late = completed_late(&settings, task, completed_at);
+        let lateness_secs = completed_at.saturating_sub(task.due_at) / MILLIS_PER_SECOND;
+        let sla = &mut self.scheduling.sla;
+        sla.lateness.set(&task.id, (late, lateness_secs));
+
+        let member_key = (task.circle_id, completed_by);
+        let (on_time, tracked) = sla.member_on_time.get(&member_key).unwrap_or((0, 0));
+        sla.member_on_time.set(&member_key, (on_time + u64::from(!late), tracked + 1));
+
+        let key = (task.circle_id, local_week(&settings, completed_at));
+        let mut stats = sla.period_stats.get(&key).unwrap_or_default();
+        stats.due_tracked += 1;
+        if late {
+            stats.late += 1;
+        }
+        sla.period_stats.set(&key, stats);
+    }
+
+    /// Revert unless an episode name and date range are valid
+    fn validate_episode(&self, name: &str, starts_at: u64, ends_at: u64) {
         let env = self.env();
-        let caller = env.caller();
-        let timestamp = env.get_block_time();
+        if name.len() > MAX_TITLE_LEN {
+            env.revert(Error::TitleTooLong);
+        }
+        if ends_at != 0 && ends_at < starts_at {
+            env.revert(Error::InvalidEpisodeRange);
+        }
+    }
 
-        let mut task = self.tasks.get(&task_id)
-            .expect("Task not found");
-        
-        if task.completed {
-            env.revert(OdraError::user(5)); // Already completed
+    /// Save an episode, or tombstone it, and announce the change
+    fn store_episode(&mut self, episode: CareEpisode, updated_by: Address, deleted: bool) {
+        let circle_id = episode.circle_id;
+        let episode_id = episode.id;
+        self.config.episodes.set(&(circle_id, episode_id), (!deleted).then(|| episode.clone()));
+        self.log_activity(circle_id, updated_by, ActivityKind::EpisodeUpdated, circle_id);
+        self.env().emit_event(EpisodeUpdated {
+            circle_id,
+            episode_id,
+            name: episode.name,
+            starts_at: episode.starts_at,
+            ends_at: episode.ends_at,
+            deleted,
+            updated_by,
+            routes: self.notification_routes(circle_id),
+        });
+    }
+
+    /// Add a task to its episode's counts, or take it out
+    fn count_episode_task(&mut self, task: &Task, add: bool) {
+        if task.episode_id == 0 {
+            return;
         }
-        
-        if caller != task.assigned_to {
-            env.revert(OdraError::user(6)); // Not assignee
+        let key = (task.circle_id, task.episode_id);
+        let mut stats = self.config.episode_stats.get(&key).unwrap_or_default();
+        let late = task.completed && self.scheduling.sla.lateness.get(&task.id).is_some_and(|(late, _)| late);
+        let step = |count: u64, applies: bool| match (applies, add) {
+            (false, _) => count,
+            (true, true) => count + 1,
+            (true, false) => count.saturating_sub(1),
+        };
+        stats.completed = step(stats.completed, task.completed);
+        stats.late = step(stats.late, late);
+        stats.tasks = step(stats.tasks, true);
+        self.config.episode_stats.set(&key, stats);
+    }
+
+    /// Count a just-completed task's completion in its episode, and towards the member who completed it
+    fn record_episode_completion(&mut self, task: &Task, completed_by: Address) {
+        if task.episode_id == 0 {
+            return;
         }
+        let key = (task.circle_id, task.episode_id);
+        self.stats.weekly.member_episode_completions.add(&(key.0, key.1, completed_by), 1);
+        let mut stats = self.config.episode_stats.get(&key).unwrap_or_default();
+        stats.completed += 1;
+        if self.scheduling.sla.lateness.get(&task.id).is_some_and(|(late, _)| late) {
+            stats.late += 1;
+        }
+        self.config.episode_stats.set(&key, stats);
+    }
 
-        // Mark as completed
-        task.completed = true;
-        task.completed_at = timestamp;
-        self.tasks.set(&task_id, task.clone());
-        
-        // Update global stats
-        self.total_completions.set(self.total_completions.get_or_default() + 1);
+    /// Save a supply, emitting `SupplyLow` when its stock falls to the threshold from `previous`
+    fn store_supply(&mut self, circle_id: u64, supply: Supply, previous: u64) {
+        let crossed = supply.quantity <= supply.low_threshold && previous > supply.low_threshold;
+        self.config.logistics.supplies.set(&(circle_id, supply.id), supply.clone());
+        if crossed {
+            self.env().emit_event(SupplyLow {
+                circle_id,
+                supply_id: supply.id,
+                name: supply.name,
+                quantity: supply.quantity,
+                low_threshold: supply.low_threshold,
+                routes: self.notification_routes(circle_id),
+            });
+        }
+    }
 
-        // Emit event - THIS IS THE VERIFIABLE PROOF!
-        self.env().emit_event(TaskCompleted {
-            task_id,
-            circle_id: task.circle_id,
-            completed_by: caller,
-            timestamp,
+    /// Queue a hook for the circle's extensions; nothing is stored without any
+    fn queue_hook(&mut self, circle_id: u64, kind: HookKind, subject_id: u64) {
+        let queued_at = self.env().get_block_time();
+        let hooks = &mut self.config.extensions;
+        if hooks.registered.get(&circle_id).unwrap_or_default().is_empty() {
+            return;
+        }
+        let index = hooks.hook_count.get(&circle_id).unwrap_or(0);
+        hooks.hooks.set(&(circle_id, index), HookCall { kind, subject_id, queued_at });
+        hooks.hook_count.set(&circle_id, index + 1);
+    }
+
+    /// Clear the hash of one log entry if `purge` holds for its (logged_at,
+    /// author); None when the entry is missing, else whether it was cleared
+    fn purge_entry(
+        &mut self,
+        circle_id: u64,
+        log: RetainedLog,
+        index: u64,
+        purge: impl Fn(u64, Address) -> bool,
+    ) -> Option<bool> {
+        match log {
+            RetainedLog::Symptoms => {
+                let journal = &mut self.feedback.journal;
+                let mut entry = journal.symptoms.get(&(circle_id, index))?;
+                if !purge(entry.logged_at, entry.logged_by) {
+                    return Some(false);
+                }
+                entry.notes_hash = PURGED_HASH;
+                journal.symptoms.set(&(circle_id, index), entry);
+            }
+            RetainedLog::Meals => {
+                let journal = &mut self.feedback.journal;
+                let mut entry = journal.meals.get(&(circle_id, index))?;
+                if !purge(entry.logged_at, entry.logged_by) {
+                    return Some(false);
+                }
+                entry.notes_hash = PURGED_HASH;
+                journal.meals.set(&(circle_id, index), entry);
+            }
+            RetainedLog::Evidence => {
+                let key = self.config.retention.evidence_log.get(&(circle_id, index))?;
+                let mut item = self.workflow.evidence.get(&key)?;
+                if !purge(item.added_at, item.added_by) {
+                    return Some(false);
+                }
+                item.content_hash = PURGED_HASH;
+                self.workflow.evidence.set(&key, item);
+            }
+            RetainedLog::Trips => {
+                let logistics = &mut self.config.logistics;
+                let mut trip = logistics.trips.get(&(circle_id, index))?;
+                if !purge(trip.logged_at, trip.driver) {
+                    return Some(false);
+                }
+                trip.purpose_hash = PURGED_HASH;
+                logistics.trips.set(&(circle_id, index), trip);
+            }
+            RetainedLog::Incidents => {
+                let journal = &mut self.feedback.journal;
+                let mut report = journal.incidents.get(&(circle_id, index))?;
+                if !purge(report.reported_at, report.reported_by) {
+                    return Some(false);
+                }
+                report.details_hash = PURGED_HASH;
+                journal.incidents.set(&(circle_id, index), report);
+            }
+            RetainedLog::HelpNotes => {
+                let private = &self.config.private;
+                let (task_id, requested_at) = private.help_log.get(&(circle_id, index))?;
+                // A later request on the task holds its requester's note, not this one
+                let Some(requested_by) = private.help_requested_by.get(&task_id) else {
+                    return Some(false);
+                };
+                if !purge(requested_at, requested_by) {
+                    return Some(false);
+                }
+                self.workflow.help_requests.set(&task_id, Some(String::new()));
+            }
+        }
+        Some(true)
+    }
+
+    /// Store an auditor's access expiry, 0 to revoke, and log it publicly
+    fn set_auditor_access(&mut self, circle_id: u64, auditor: Address, scope: ConsentScope, expires_at: u64, changed_by: Address) {
+        self.feedback.auditor_grants.set(&(circle_id, scope, auditor), expires_at);
+        let action = if expires_at != 0 { AuditAction::AuditorGranted } else { AuditAction::AuditorRevoked };
+        self.log_audit(circle_id, changed_by, action, Some(auditor), circle_id);
+        self.env().emit_event(AuditorAccessChanged {
+            circle_id,
+            auditor,
+            scope,
+            expires_at,
+            changed_by,
+            routes: self.notification_routes(circle_id),
         });
     }
 
-    // ==================== View Functions ====================
+    /// Load an envelope the caller stored, or any envelope for the owner
+    fn envelope_for_author(&self, circle_id: u64, envelope_id: u64, caller: Address) -> EncryptedEnvelope {
+        let env = self.env();
+        let Some(envelope) = self.config.private.envelopes.get(&(circle_id, envelope_id)).flatten() else {
+            env.revert(Error::EnvelopeNotFound);
+        };
+        let circle = self.require_circle(circle_id);
+        if caller != envelope.stored_by && caller != circle.owner {
+            env.revert(Error::NotCreatorOrOwner);
+        }
+        self.assert_not_suspended(circle_id, caller);
+        envelope
+    }
 
-    /// Get circle details
-    pub fn get_circle(&self, circle_id: u64) -> Option<Circle> {
-        self.circles.get(&circle_id)
+    /// Save an envelope, or tombstone it, and announce the change
+    fn store_envelope_record(&mut self, circle_id: u64, envelope: EncryptedEnvelope, updated_by: Address, deleted: bool) {
+        let envelope_id = envelope.id;
+        let key_epoch = envelope.key_epoch;
+        self.config.private.envelopes.set(&(circle_id, envelope_id), (!deleted).then_some(envelope));
+        self.log_activity(circle_id, updated_by, ActivityKind::EnvelopeUpdated, circle_id);
+        self.env().emit_event(EnvelopeUpdated {
+            circle_id,
+            envelope_id,
+            key_epoch,
+            deleted,
+            updated_by,
+            routes: self.notification_routes(circle_id),
+        });
     }
 
-    /// Get task details
-    pub fn get_task(&self, task_id: u64) -> Option<Task> {
-        self.tasks.get(&task_id)
+    /// Save a reimbursement request and announce its status
+    fn store_reimbursement(&mut self, circle_id: u64, request: ReimbursementRequest, updated_by: Address) {
+        self.config.logistics.reimbursements.set(&(circle_id, request.id), request.clone());
+        self.log_activity(circle_id, updated_by, ActivityKind::ReimbursementUpdated, circle_id);
+        self.env().emit_event(ReimbursementUpdated {
+            circle_id,
+            request_id: request.id,
+            member: request.member,
+            amount: request.amount,
+            status: request.status,
+            routes: self.notification_routes(circle_id),
+        });
     }
 
-    /// Check if address is a member of a circle
-    pub fn check_is_member(&self, circle_id: u64, addr: Address) -> bool {
-        self.is_member.get(&(circle_id, addr)).unwrap_or(false)
+    /// Add a completed restock task's quantity to its supply
+    fn apply_restock(&mut self, task: &Task, completed_at: u64) {
+        let Some((supply_id, quantity)) = self.get_restock(task.id) else {
+            return;
+        };
+        if let Some(mut supply) = self.get_supply(task.circle_id, supply_id) {
+            let previous = supply.quantity;
+            supply.quantity = previous.saturating_add(quantity);
+            supply.updated_at = completed_at;
+            self.store_supply(task.circle_id, supply, previous);
+        }
     }
 
-    /// Get member count for a circle
-    pub fn get_member_count(&self, circle_id: u64) -> u64 {
-        self.circle_member_count.get(&circle_id).unwrap_or(0)
+    /// Whether a member is available at a timestamp (no declared windows = always)
+    fn is_available_at(&self, circle_id: u64, addr: Address, at: u64) -> bool {
+        if self.members.suspended.get(&(circle_id, addr)).unwrap_or(false) {
+            return false;
+        }
+        match self.members.availability.get(&(circle_id, addr)) {
+            Some(windows) if !windows.is_empty() => windows
+                .iter()
+                .any(|window| window.start <= at && at < window.end),
+            _ => true,
+        }
     }
 
-    /// Get task count for a circle
-    pub fn get_task_count(&self, circle_id: u64) -> u64 {
-        self.circle_task_count.get(&circle_id).unwrap_or(0)
+    /// Open tasks a member may hold in a circle, 0 = unlimited
+    fn assignment_cap(&self, circle_id: u64, member: Address) -> u64 {
+        self.members.open_task_caps.get(&(circle_id, member)).flatten()
+            .unwrap_or_else(|| self.get_settings(circle_id).max_open_per_member)
     }
 
-    /// Get global statistics
-    pub fn get_stats(&self) -> (u64, u64, u64) {
-        (
-            self.total_circles.get_or_default(),
-            self.total_tasks.get_or_default(),
-            self.total_completions.get_or_default(),
-        )
+    /// Whether a member is below their open task cap
+    fn has_assignment_capacity(&self, circle_id: u64, member: Address) -> bool {
+        let cap = self.assignment_cap(circle_id, member);
+        cap == 0 || self.members.open_tasks.get(&(circle_id, member)).unwrap_or(0) < cap
+    }
+
+    /// Revert with `AssignmentLimitReached` if a member is at their open task cap
+    fn assert_assignment_capacity(&self, circle_id: u64, member: Address) {
+        if !self.has_assignment_capacity(circle_id, member) {
+            self.env().revert(Error::AssignmentLimitReached);
+        }
+    }
+
+    /// Whether auto-assignment may hand a member a task now
+    fn can_take_task(&self, circle_id: u64, member: Address, at: u64) -> bool {
+        self.is_available_at(circle_id, member, at) && self.has_assignment_capacity(circle_id, member)
+    }
+
+    /// Pick an available assignee according to the circle's auto-assignment mode
+    fn pick_assignee(&mut self, circle_id: u64, exclude: Option<Address>) -> Option<Address> {
+        let now = self.env().get_block_time();
+        let mode = self.get_settings(circle_id).auto_assign_mode;
+        let member_count = self.members.circle_member_count.get(&circle_id).unwrap_or(0);
+        if member_count == 0 {
+            return None;
+        }
+
+        match mode {
+            AutoAssignMode::Off => None,
+            AutoAssignMode::FewestOpen | AutoAssignMode::LeastWorkload => {
+                let workload = if mode == AutoAssignMode::FewestOpen {
+                    &self.members.open_tasks
+                } else {
+                    &self.members.open_minutes
+                };
+                let mut best: Option<(Address, u64)> = None;
+                for index in 0..member_count {
+                    let addr = match self.members.circle_members.get(&(circle_id, index)) {
+                        Some(addr) => addr,
+                        None => continue,
+                    };
+                    if Some(addr) == exclude || !self.can_take_task(circle_id, addr, now) {
+                        continue;
+                    }
+                    let load = workload.get(&(circle_id, addr)).unwrap_or(0);
+                    if best.is_none_or(|(_, lightest)| load < lightest) {
+                        best = Some((addr, load));
+                    }
+                }
+                best.map(|(addr, _)| addr)
+            }
+            AutoAssignMode::RoundRobin => {
+                let cursor = self.scheduling.auto_assign_cursor.get(&circle_id).unwrap_or(0);
+                // Walk at most one full rotation looking for an available member
+                for step in 0..member_count {
+                    let index = (cursor + step) % member_count;
+                    let addr = match self.members.circle_members.get(&(circle_id, index)) {
+                        Some(addr) => addr,
+                        None => continue,
+                    };
+                    if Some(addr) != exclude && self.can_take_task(circle_id, addr, now) {
+                        self.scheduling.auto_assign_cursor.set(&circle_id, cursor + step + 1);
+                        return Some(addr);
+                    }
+                }
+                None
+            }
+        }
+    }
+}
+
+/// Steps used by circles without a custom discharge template, all critical
+fn default_discharge_steps() -> Vec<DischargeStep> {
+    let step = |title: &str, hours: u64| DischargeStep {
+        title: title.to_string(),
+        priority: MAX_PRIORITY,
+        due_after_secs: hours * MILLIS_PER_HOUR / MILLIS_PER_SECOND,
+    };
+    vec![
+        step("Medication reconciliation", 24),
+        step("Book follow-up appointment", 48),
+        step("Home safety check", 72),
+    ]
+}
+
+/// Report a checked call's outcome by its error code
+fn simulation(outcome: Result<(), OdraError>) -> Simulation {
+    Simulation {
+        ok: outcome.is_ok(),
+        error_code: outcome.err().map_or(0, |error| error.code() as u32),
+    }
+}
+
+/// Catalog code of the event emitted under `name`, 0 if there is none
+pub(crate) fn event_code(name: &str) -> u32 {
+    EVENT_NAMES
+        .iter()
+        .position(|event| *event == name)
+        .map_or(0, |index| FIRST_EVENT_CODE + index as u32)
+}
+
+/// Whether a completion came after the task's due date plus the circle's grace window
+fn completed_late(settings: &CircleSettings, task: &Task, completed_at: u64) -> bool {
+    let grace = settings.completion_grace_secs.saturating_mul(MILLIS_PER_SECOND);
+    completed_at > task.due_at.saturating_add(grace)
+}
+
+/// `value` after `elapsed` ms at a `half_life` in ms: halved per half-life, linear in between
+fn decayed(value: u64, elapsed: u64, half_life: u64) -> u64 {
+    if half_life == 0 {
+        return value;
+    }
+    let halvings = elapsed / half_life;
+    if halvings >= u64::BITS as u64 {
+        return 0;
+    }
+    // 2^-x is close to 1 - x/2 for x in [0, 1], and exact at both ends
+    let into = (elapsed % half_life) as u128;
+    let span = 2 * half_life as u128;
+    ((value >> halvings) as u128 * (span - into) / span) as u64
+}
+
+/// Block time in ms shifted to the circle's local wall clock
+fn local_time(settings: &CircleSettings, timestamp: u64) -> u64 {
+    let standard = settings.utc_offset_mins as i64 * MILLIS_PER_MINUTE as i64;
+    let local = timestamp.saturating_add_signed(standard);
+    if in_daylight_saving(settings.dst_rule, standard, timestamp) {
+        local + MILLIS_PER_HOUR
+    } else {
+        local
     }
 }
+
+/// Week bucket of a block time in the circle's local time
+fn local_week(settings: &CircleSettings, timestamp: u64) -> u64 {
+    week_bucket(local_time(settings, timestamp))
+}
+
+/// Monday-to-Sunday weeks since the one holding the Unix epoch
+fn week_bucket(time: u64) -> u64 {
+    time.saturating_add(EPOCH_AFTER_MONDAY) / MILLIS_PER_WEEK
+}
+
+/// Month bucket (calendar months since January 1970) of a block time in the circle's local time
+fn local_month(settings: &CircleSettings, timestamp: u64) -> u64 {
+    let (year, month) = civil_year_month(local_time(settings, timestamp) / MILLIS_PER_DAY);
+    (year - 1970) * 12 + month - 1
+}
+
+/// Whether `rule` puts clocks forward at `timestamp`, given the standard offset in ms
+fn in_daylight_saving(rule: DstRule, standard: i64, timestamp: u64) -> bool {
+    let year = civil_year(timestamp / MILLIS_PER_DAY);
+    let (start, end) = match rule {
+        DstRule::None => return false,
+        DstRule::Eu => (
+            last_sunday(year, 3) * MILLIS_PER_DAY + MILLIS_PER_HOUR,
+            last_sunday(year, 10) * MILLIS_PER_DAY + MILLIS_PER_HOUR,
+        ),
+        // 02:00 standard time on the way in, 02:00 daylight time on the way out
+        DstRule::Us => (
+            (nth_sunday(year, 3, 2) * MILLIS_PER_DAY + 2 * MILLIS_PER_HOUR).saturating_add_signed(-standard),
+            (nth_sunday(year, 11, 1) * MILLIS_PER_DAY + MILLIS_PER_HOUR).saturating_add_signed(-standard),
+        ),
+    };
+    (start..end).contains(&timestamp)
+}
+
+/// Days since 1970-01-01 of a proleptic Gregorian date
+fn days_from_civil(year: u64, month: u64, day: u64) -> u64 {
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y / 400;
+    let yoe = y - era * 400;
+    let doy = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+/// Gregorian year of a day count since 1970-01-01
+fn civil_year(days: u64) -> u64 {
+    civil_year_month(days).0
+}
+
+/// Gregorian year and month (1-12) of a day count since 1970-01-01
+fn civil_year_month(days: u64) -> (u64, u64) {
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let march_based_month = (5 * doy + 2) / 153;
+    // January and February belong to the next civil year
+    let month = if march_based_month < 10 { march_based_month + 3 } else { march_based_month - 9 };
+    (era * 400 + yoe + u64::from(march_based_month >= 10), month)
+}
+
+/// Day of the `n`th Sunday (1-based) of a month, as days since 1970-01-01
+fn nth_sunday(year: u64, month: u64, n: u64) -> u64 {
+    let first = days_from_civil(year, month, 1);
+    // 1970-01-01 was a Thursday, four days after a Sunday
+    first + (7 - (first + 4) % 7) % 7 + 7 * (n - 1)
+}
+
+/// Day of the last Sunday of a month before December, as days since 1970-01-01
+fn last_sunday(year: u64, month: u64) -> u64 {
+    let last = days_from_civil(year, month + 1, 1) - 1;
+    last - (last + 4) % 7
+}
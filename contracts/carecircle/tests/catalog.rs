@@ -0,0 +1,46 @@
+//! The message catalog against the deployed contract.
+//!
+//! `MESSAGES.md` is the list clients translate from, so every code it gives
+//! must be the one the contract reports.
+
+use carecircle::*;
+use odra::host::{Deployer, NoArgs};
+use odra::OdraError;
+
+const MESSAGES: &str = include_str!("../MESSAGES.md");
+
+/// `(code, name)` rows of the catalog's event table
+fn catalog_events() -> Vec<(u32, &'static str)> {
+    MESSAGES
+        .split("## Events")
+        .nth(1)
+        .expect("MESSAGES.md has an Events section")
+        .lines()
+        .filter_map(|line| {
+            let mut cells = line.trim().trim_matches('|').split('|').map(str::trim);
+            let code = cells.next()?.parse().ok()?;
+            Some((code, cells.nth(1)?.trim_matches('`')))
+        })
+        .collect()
+}
+
+#[test]
+fn event_codes_match_the_catalog() {
+    let env = odra_test::env();
+    let contract = CareCircleHostRef::deploy(&env, NoArgs);
+    let events = catalog_events();
+    assert_eq!(events.len(), 55);
+    for (code, name) in events {
+        assert_eq!(contract.get_event_code(name.to_string()), code, "{name}");
+    }
+    assert_eq!(contract.get_event_code("NotAnEvent".to_string()), 0);
+}
+
+#[test]
+fn error_variants_keep_their_codes() {
+    assert_eq!(OdraError::from(Error::NotOwner), OdraError::user(1));
+    assert_eq!(OdraError::from(Error::ChallengeWindowOpen), OdraError::user(65));
+    assert_eq!(OdraError::from(Error::MemberSuspended), OdraError::user(67));
+    assert_eq!(OdraError::from(Error::CannotSuspendOwner), OdraError::user(68));
+    assert_eq!(OdraError::from(Error::AssignmentLimitReached), OdraError::user(69));
+}
@@ -0,0 +1,230 @@
+//! Property-based invariant suite.
+//!
+//! Generates random sequences of circle, member and task operations from a
+//! handful of host accounts and replays them against a fresh contract on the
+//! Odra test env. Calls are made through the `try_` variants, so operations
+//! the contract rejects are part of the input rather than test failures.
+//! After every sequence the bookkeeping counters must agree with what the
+//! records themselves say:
+//!
+//! - each circle's `member_count` equals its member index entries
+//! - `total_completions` equals the number of completed tasks
+//! - each circle's task count equals the tasks pointing at it, and no task
+//!   points at a circle that does not exist
+//! - `total_circles` and `total_tasks` equal the records created
+//!
+//! The contract holds no funds, so there is no treasury balance to check.
+//!
+//! Two more properties check the compact task storage. A task reads back with
+//! its times to the millisecond, also after moving to a circle created later
+//! in another time zone, where it can still be rescheduled and deferred. A due
+//! date past what the record holds reverts instead of being truncated.
+
+use carecircle::{CareCircleHostRef, CircleSettings, Error};
+use odra::host::{Deployer, HostEnv, NoArgs};
+use odra::Address;
+use proptest::prelude::*;
+use std::collections::BTreeMap;
+
+const ACCOUNTS: usize = 4;
+/// First millisecond a task's u32 seconds cannot hold
+const PAST_TASK_TIMES: u64 = (u32::MAX as u64 + 1) * 1_000;
+
+/// An account taking part in a call: `None` is the one expected to succeed
+/// (the circle owner, or the assignee when completing), `Some` is any account
+type Caller = Option<usize>;
+
+#[derive(Clone, Debug)]
+enum Op {
+    CreateCircle { owner: usize },
+    AddMember { caller: Caller, circle: usize, member: usize },
+    CreateTask { caller: Caller, circle: usize, assignee: Caller, priority: u8 },
+    CreateOpenTask { caller: Caller, circle: usize, priority: u8 },
+    CompleteTask { caller: Caller, task: usize },
+    MoveTask { caller: Caller, task: usize, target: usize },
+}
+
+fn caller() -> impl Strategy<Value = Caller> {
+    prop::option::weighted(0.25, 0..ACCOUNTS)
+}
+
+fn op() -> impl Strategy<Value = Op> {
+    let account = 0..ACCOUNTS;
+    let index = 0..8usize;
+    let priority = 0..5u8;
+    prop_oneof![
+        1 => account.clone().prop_map(|owner| Op::CreateCircle { owner }),
+        3 => (caller(), index.clone(), account.clone())
+            .prop_map(|(caller, circle, member)| Op::AddMember { caller, circle, member }),
+        3 => (caller(), index.clone(), prop::option::weighted(0.5, account), priority.clone())
+            .prop_map(|(caller, circle, assignee, priority)| Op::CreateTask { caller, circle, assignee, priority }),
+        1 => (caller(), index.clone(), priority)
+            .prop_map(|(caller, circle, priority)| Op::CreateOpenTask { caller, circle, priority }),
+        3 => (caller(), index.clone()).prop_map(|(caller, task)| Op::CompleteTask { caller, task }),
+        1 => (caller(), index.clone(), index).prop_map(|(caller, task, target)| Op::MoveTask { caller, task, target }),
+    ]
+}
+
+/// Ids the sequence has created so far; ops pick from these by index
+#[derive(Default)]
+struct Created {
+    circles: Vec<(u64, Address)>,
+    tasks: Vec<u64>,
+}
+
+impl Created {
+    fn circle(&self, index: usize) -> Option<(u64, Address)> {
+        (!self.circles.is_empty()).then(|| self.circles[index % self.circles.len()])
+    }
+
+    fn task(&self, index: usize) -> Option<u64> {
+        (!self.tasks.is_empty()).then(|| self.tasks[index % self.tasks.len()])
+    }
+}
+
+fn apply(env: &HostEnv, contract: &mut CareCircleHostRef, created: &mut Created, op: &Op) {
+    let act_as = |caller: Caller, expected: Address| {
+        env.set_caller(caller.map_or(expected, |index| env.get_account(index)));
+    };
+    match *op {
+        Op::CreateCircle { owner } => {
+            let owner = env.get_account(owner);
+            env.set_caller(owner);
+            if let Ok(circle_id) = contract.try_create_circle("Circle".to_string()) {
+                created.circles.push((circle_id, owner));
+            }
+        }
+        Op::AddMember { caller, circle, member } => {
+            let Some((circle_id, owner)) = created.circle(circle) else { return };
+            act_as(caller, owner);
+            let _ = contract.try_add_member(circle_id, env.get_account(member));
+        }
+        Op::CreateTask { caller, circle, assignee, priority } => {
+            let Some((circle_id, owner)) = created.circle(circle) else { return };
+            act_as(caller, owner);
+            let assignee = assignee.map_or(owner, |index| env.get_account(index));
+            if let Ok(task_id) = contract.try_create_task(circle_id, "Task".to_string(), assignee, priority) {
+                created.tasks.push(task_id);
+            }
+        }
+        Op::CreateOpenTask { caller, circle, priority } => {
+            let Some((circle_id, owner)) = created.circle(circle) else { return };
+            act_as(caller, owner);
+            if let Ok(task_id) = contract.try_create_open_task(circle_id, "Open task".to_string(), priority) {
+                created.tasks.push(task_id);
+            }
+        }
+        Op::CompleteTask { caller, task } => {
+            let Some(task_id) = created.task(task) else { return };
+            let assignee = contract.get_task(task_id).expect("created task is missing").assigned_to;
+            act_as(caller, assignee);
+            let _ = contract.try_complete_task(task_id);
+        }
+        Op::MoveTask { caller, task, target } => {
+            let (Some(task_id), Some((target_id, owner))) = (created.task(task), created.circle(target)) else { return };
+            act_as(caller, owner);
+            let _ = contract.try_move_task(task_id, target_id);
+        }
+    }
+}
+
+fn assert_invariants(contract: &CareCircleHostRef, created: &Created) {
+    let (total_circles, total_tasks, total_completions) = contract.get_stats();
+    assert_eq!(total_circles, created.circles.len() as u64, "total_circles drifted");
+    assert_eq!(total_tasks, created.tasks.len() as u64, "total_tasks drifted");
+
+    for &(circle_id, _) in &created.circles {
+        let circle = contract.get_circle(circle_id).expect("created circle is missing");
+        let members = contract.get_members(circle_id, 0, u64::MAX);
+        assert_eq!(circle.member_count, members.len() as u64, "member_count drifted in circle {circle_id}");
+        assert!(
+            members.iter().all(|member| contract.check_is_member(circle_id, member.address)),
+            "member index of circle {circle_id} lists a non-member"
+        );
+    }
+
+    let mut completed = 0;
+    let mut tasks_per_circle: BTreeMap<u64, u64> = BTreeMap::new();
+    for &task_id in &created.tasks {
+        let task = contract.get_task(task_id).expect("created task is missing");
+        assert!(contract.circle_exists(task.circle_id), "task {task_id} is orphaned");
+        *tasks_per_circle.entry(task.circle_id).or_default() += 1;
+        if task.completed {
+            completed += 1;
+        }
+    }
+    assert_eq!(total_completions, completed, "total_completions drifted");
+
+    for &(circle_id, _) in &created.circles {
+        let expected = tasks_per_circle.get(&circle_id).copied().unwrap_or(0);
+        assert_eq!(contract.get_task_count(circle_id), expected, "task count drifted in circle {circle_id}");
+    }
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(32))]
+
+    #[test]
+    fn counters_match_records(ops in prop::collection::vec(op(), 10..60)) {
+        let env = odra_test::env();
+        let mut contract = CareCircleHostRef::deploy(&env, NoArgs);
+        let mut created = Created::default();
+        for op in &ops {
+            apply(&env, &mut contract, &mut created, op);
+        }
+        assert_invariants(&contract, &created);
+    }
+
+    #[test]
+    fn tasks_keep_their_times(
+        gaps in prop::collection::vec(1..10_000_000u64, 3),
+        due_in in 1_000..2_000_000_000_000u64,
+        priority in 0..=3u8,
+        target_offset_mins in -14 * 60..=14 * 60i32,
+    ) {
+        let env = odra_test::env();
+        let owner = env.get_account(0);
+        env.set_caller(owner);
+        let mut contract = CareCircleHostRef::deploy(&env, NoArgs);
+        let source = contract.create_circle("Source".to_string());
+        env.advance_block_time(gaps[0]);
+        let task_id = contract.create_task(source, "Task".to_string(), owner, priority);
+        let created_at = env.block_time();
+        contract.set_due_date(task_id, created_at + due_in);
+
+        env.advance_block_time(gaps[1]);
+        let target = contract.create_circle("Target".to_string());
+        let settings = CircleSettings { max_defers: 1, utc_offset_mins: target_offset_mins, ..Default::default() };
+        contract.update_settings(target, settings);
+        contract.move_task(task_id, target);
+        let task = contract.get_task(task_id).expect("created task is missing");
+        prop_assert_eq!((task.created_at, task.due_at), (created_at, created_at + due_in));
+
+        // The target counts the moved due date, so it can be replaced and deferred
+        env.advance_block_time(gaps[2]);
+        let rescheduled = env.block_time() + due_in;
+        contract.set_due_date(task_id, rescheduled);
+        contract.defer_task(task_id, rescheduled + due_in, "Later".to_string());
+        contract.complete_task(task_id);
+
+        let task = contract.get_task(task_id).expect("created task is missing");
+        prop_assert_eq!(
+            (task.circle_id, task.priority, task.created_at, task.due_at, task.completed_at),
+            (target, priority, created_at, rescheduled + due_in, env.block_time())
+        );
+    }
+
+    #[test]
+    fn task_times_past_the_record_revert(due_at in PAST_TASK_TIMES..u64::MAX / 2) {
+        let env = odra_test::env();
+        let owner = env.get_account(0);
+        env.set_caller(owner);
+        let mut contract = CareCircleHostRef::deploy(&env, NoArgs);
+        let circle_id = contract.create_circle("Circle".to_string());
+        let task_id = contract.create_task(circle_id, "Task".to_string(), owner, 0);
+
+        prop_assert_eq!(contract.try_set_due_date(task_id, due_at).unwrap_err(), Error::TaskFieldOverflow.into());
+        contract.set_due_date(task_id, PAST_TASK_TIMES - 1);
+        prop_assert_eq!(contract.get_task(task_id).unwrap().due_at, PAST_TASK_TIMES - 1);
+    }
+}
@@ -0,0 +1,284 @@
+//! Keeper entry points.
+//!
+//! Keepers are permissionless and bounded per call: each examines a page of
+//! the circle's open tasks from a stored cursor, whatever the circle's
+//! history, and running one again without new work changes nothing. Hook
+//! delivery runs against a recorder extension deployed next to the contract.
+
+use carecircle::fixtures::CircleFixture;
+use carecircle::*;
+use odra::host::{Deployer, HostEnv, HostRef, NoArgs};
+use odra::prelude::*;
+use odra::{Address, OdraError, Var};
+
+/// Highest task priority
+const CRITICAL: u8 = 3;
+
+struct Circle {
+    env: HostEnv,
+    contract: CareCircleHostRef,
+    owner: Address,
+    member: Address,
+    keeper: Address,
+    circle_id: u64,
+}
+
+impl Circle {
+    /// A circle of two with a third account, outside it, running the keepers
+    fn new() -> Self {
+        let env = odra_test::env();
+        let (owner, member, keeper) = (env.get_account(0), env.get_account(1), env.get_account(2));
+        let mut contract = CareCircleHostRef::deploy(&env, NoArgs);
+        let circle_id = CircleFixture::new("Mom's Care", owner).member(member).build(&env, &mut contract).circle_id;
+        Self { env, contract, owner, member, keeper, circle_id }
+    }
+
+    /// Create tasks assigned to the member, as the owner
+    fn tasks(&mut self, count: usize, priority: u8) -> Vec<u64> {
+        self.env.set_caller(self.owner);
+        (0..count)
+            .map(|index| self.contract.create_task(self.circle_id, format!("Task {index}"), self.member, priority))
+            .collect()
+    }
+
+    fn as_keeper(&mut self) -> &mut CareCircleHostRef {
+        self.env.set_caller(self.keeper);
+        &mut self.contract
+    }
+}
+
+/// Extension that counts completion hooks and reverts on any other kind
+#[odra::module]
+pub struct CompletionRecorder {
+    completions: Var<u64>,
+}
+
+#[odra::module]
+impl CompletionRecorder {
+    pub fn on_care_hook(&mut self, circle_id: u64, kind: HookKind, subject_id: u64) {
+        // Arguments are passed by name, so they keep the interface's names
+        let _ = (circle_id, subject_id);
+        if kind != HookKind::TaskCompleted {
+            self.env().revert(OdraError::user(1));
+        }
+        self.completions.add(1);
+    }
+
+    pub fn completions(&self) -> u64 {
+        self.completions.get_or_default()
+    }
+}
+
+/// Extension that delivers its own hooks again from inside its first one
+#[odra::module]
+pub struct ReentrantRecorder {
+    care_circle: Var<Address>,
+    received: Var<u64>,
+}
+
+#[odra::module]
+impl ReentrantRecorder {
+    pub fn set_care_circle(&mut self, care_circle: Address) {
+        self.care_circle.set(care_circle);
+    }
+
+    pub fn on_care_hook(&mut self, circle_id: u64, kind: HookKind, subject_id: u64) {
+        let _ = (kind, subject_id);
+        self.received.add(1);
+        if self.received.get_or_default() == 1 {
+            let extension = self.env().self_address();
+            CareCircleContractRef::new(self.env(), self.care_circle.get().unwrap())
+                .deliver_hooks(circle_id, extension, 20);
+        }
+    }
+
+    pub fn received(&self) -> u64 {
+        self.received.get_or_default()
+    }
+}
+
+#[test]
+fn due_reminders_page_through_open_tasks() {
+    let mut c = Circle::new();
+    let cid = c.circle_id;
+    let due_at = c.env.block_time() + 60_000;
+    for task_id in c.tasks(5, 1) {
+        c.contract.set_due_date(task_id, due_at);
+    }
+
+    // Two tasks per call; the third call finishes the pass and wraps around
+    assert_eq!(c.as_keeper().emit_due_reminders(cid, 3600, 2), 2);
+    assert_eq!(c.contract.get_keeper_cursor(cid, OpenTaskKeeper::DueReminders), (1, 2));
+    assert_eq!(c.as_keeper().emit_due_reminders(cid, 3600, 2), 2);
+    assert_eq!(c.as_keeper().emit_due_reminders(cid, 3600, 2), 1);
+
+    // Every due date was reminded once: further runs emit nothing
+    assert_eq!(c.as_keeper().emit_due_reminders(cid, 3600, 50), 0);
+    assert_eq!(c.as_keeper().emit_due_reminders(cid, 3600, 50), 0);
+
+    // A new due date is reminded again
+    c.env.set_caller(c.owner);
+    let task_id = c.contract.get_open_tasks(cid, 1)[0].id;
+    c.contract.set_due_date(task_id, due_at + 60_000);
+    assert_eq!(c.as_keeper().emit_due_reminders(cid, 3600, 50), 1);
+}
+
+#[test]
+fn due_reminders_skip_completed_history() {
+    let mut c = Circle::new();
+    let cid = c.circle_id;
+    for task_id in c.tasks(20, 1) {
+        c.env.set_caller(c.member);
+        c.contract.complete_task(task_id);
+    }
+    let open = c.tasks(1, 1)[0];
+    c.contract.set_due_date(open, c.env.block_time() + 60_000);
+
+    // A one-task page reaches the only open task past twenty completed ones
+    assert_eq!(c.as_keeper().emit_due_reminders(cid, 3600, 1), 1);
+    assert_eq!(c.contract.get_keeper_cursor(cid, OpenTaskKeeper::DueReminders), (1, 1));
+}
+
+#[test]
+fn due_reminders_skip_overdue_tasks() {
+    let mut c = Circle::new();
+    let cid = c.circle_id;
+    let tasks = c.tasks(2, 1);
+    let (late, upcoming) = (tasks[0], tasks[1]);
+    c.contract.set_due_date(late, c.env.block_time() + 60_000);
+    c.contract.set_due_date(upcoming, c.env.block_time() + 600_000);
+
+    // The first task's due date passed unreminded; only the second is still ahead
+    c.env.advance_block_time(120_000);
+    assert_eq!(c.as_keeper().emit_due_reminders(cid, 3600, 50), 1);
+}
+
+#[test]
+fn keeper_runs_are_not_member_activity() {
+    let mut c = Circle::new();
+    let cid = c.circle_id;
+    let task_id = c.tasks(1, 1)[0];
+    c.contract.set_due_date(task_id, c.env.block_time() + 60_000);
+    let created_at = c.contract.get_circle(cid).unwrap().created_at;
+    let feed = c.contract.get_activity(cid, 0, 50).len();
+
+    assert_eq!(c.as_keeper().emit_due_reminders(cid, 3600, 10), 1);
+    assert_eq!(c.contract.get_activity(cid, 0, 50).len(), feed);
+    assert_eq!(c.contract.get_last_active(cid, c.keeper), created_at);
+}
+
+#[test]
+fn priority_aging_reaches_every_open_task_once_per_threshold() {
+    let mut c = Circle::new();
+    let cid = c.circle_id;
+    c.contract.update_settings(cid, CircleSettings { priority_aging_secs: 60, ..Default::default() });
+    let aging = c.tasks(3, 0);
+    c.tasks(2, CRITICAL);
+    c.env.advance_block_time(61_000);
+    let feed = c.contract.get_activity(cid, 0, 50).len();
+
+    // Pages of two reach all three aged tasks, each escalated once
+    let escalated: u64 = (0..3).map(|_| c.as_keeper().escalate_priorities(cid, 2)).sum();
+    assert_eq!(escalated, 3);
+    for task_id in &aging {
+        assert_eq!(c.contract.get_task(*task_id).unwrap().priority, 1);
+    }
+    // Top-priority tasks are never examined
+    assert!(c.contract.get_keeper_cursor(cid, OpenTaskKeeper::PriorityAging).0 < CRITICAL);
+
+    // Nothing aged since: repeated runs change nothing
+    for _ in 0..3 {
+        assert_eq!(c.as_keeper().escalate_priorities(cid, 2), 0);
+    }
+    assert_eq!(c.contract.get_activity(cid, 0, 50).len(), feed);
+}
+
+#[test]
+fn inactivity_flags_each_idle_stretch_once() {
+    let mut c = Circle::new();
+    let cid = c.circle_id;
+    c.contract.update_settings(cid, CircleSettings { inactivity_secs: 60, ..Default::default() });
+    let critical = c.tasks(3, CRITICAL);
+    c.tasks(1, 0);
+    c.env.advance_block_time(61_000);
+    let feed = c.contract.get_activity(cid, 0, 50).len();
+
+    // Only the three critical tasks are examined, over two pages
+    assert_eq!(c.as_keeper().flag_inactive_assignees(cid, 2), 2);
+    assert_eq!(c.as_keeper().flag_inactive_assignees(cid, 2), 1);
+    assert_eq!(c.contract.get_keeper_cursor(cid, OpenTaskKeeper::Inactivity).0, CRITICAL);
+    for _ in 0..3 {
+        assert_eq!(c.as_keeper().flag_inactive_assignees(cid, 50), 0);
+    }
+    assert_eq!(c.contract.get_activity(cid, 0, 50).len(), feed);
+
+    // Acting again starts a new stretch, flagged once it is long enough
+    c.env.set_caller(c.member);
+    c.contract.add_task_label(critical[0], "meds".to_string());
+    assert_eq!(c.as_keeper().flag_inactive_assignees(cid, 50), 0);
+    c.env.advance_block_time(61_000);
+    assert_eq!(c.as_keeper().flag_inactive_assignees(cid, 50), 3);
+    assert_eq!(c.as_keeper().flag_inactive_assignees(cid, 50), 0);
+}
+
+#[test]
+fn a_reverting_hook_is_skipped_and_delivery_resumes() {
+    let mut c = Circle::new();
+    let cid = c.circle_id;
+    let recorder = CompletionRecorderHostRef::deploy(&c.env, NoArgs);
+    let other = CompletionRecorderHostRef::deploy(&c.env, NoArgs);
+    let extension = *recorder.address();
+    c.contract.register_extension(cid, extension);
+    c.contract.register_extension(cid, *other.address());
+    let tasks = c.tasks(2, 1);
+    c.env.set_caller(c.member);
+    assert_eq!(c.contract.report_incident(cid, 1, [1; 32]), 0);
+    c.contract.complete_task(tasks[0]);
+
+    // The incident hook reverts every delivery and the cursor stays on it
+    assert!(c.as_keeper().try_deliver_hooks(cid, extension, 20).is_err());
+    assert_eq!(c.contract.get_hook_progress(cid, extension), (0, 2));
+    assert_eq!(
+        c.as_keeper().try_skip_hook(cid, extension).unwrap_err(),
+        Error::NotOwnerOrExtension.into()
+    );
+
+    // Skipping it, as the owner, lets the completion through
+    c.env.set_caller(c.owner);
+    assert_eq!(c.contract.skip_hook(cid, extension), 0);
+    assert_eq!(c.as_keeper().deliver_hooks(cid, extension, 20), 1);
+    assert_eq!(recorder.completions(), 1);
+    c.env.set_caller(c.owner);
+    assert_eq!(c.contract.try_skip_hook(cid, extension).unwrap_err(), Error::NoHookQueued.into());
+    assert_eq!(c.contract.get_hook_progress(cid, *other.address()), (0, 2));
+
+    // Registered again, it resumes with the hooks queued while it was away
+    c.contract.remove_extension(cid, extension);
+    c.env.set_caller(c.member);
+    c.contract.complete_task(tasks[1]);
+    c.env.set_caller(c.owner);
+    c.contract.register_extension(cid, extension);
+    assert_eq!(c.contract.get_hook_progress(cid, extension), (2, 3));
+    assert_eq!(c.as_keeper().deliver_hooks(cid, extension, 20), 1);
+    assert_eq!(recorder.completions(), 2);
+}
+
+#[test]
+fn a_reentrant_extension_receives_each_hook_once() {
+    let mut c = Circle::new();
+    let cid = c.circle_id;
+    let mut recorder = ReentrantRecorderHostRef::deploy(&c.env, NoArgs);
+    recorder.set_care_circle(*c.contract.address());
+    let extension = *recorder.address();
+    c.contract.register_extension(cid, extension);
+    let tasks = c.tasks(3, 1);
+    c.env.set_caller(c.member);
+    for task_id in tasks {
+        c.contract.complete_task(task_id);
+    }
+
+    // The nested call delivers the last two; the outer one stops there
+    assert_eq!(c.as_keeper().deliver_hooks(cid, extension, 20), 1);
+    assert_eq!(recorder.received(), 3);
+    assert_eq!(c.contract.get_hook_progress(cid, extension), (3, 3));
+}
@@ -0,0 +1,178 @@
+//! Local time.
+//!
+//! Circles bucket their activity by local clock: standard offset, then the
+//! daylight saving rule on top. Each test reads the buckets through the views
+//! one millisecond either side of a boundary, so an off-by-one in the
+//! calendar arithmetic shows up as the wrong day, month or next local time.
+//! Counts already made stay in their bucket when the circle changes zone.
+
+use carecircle::fixtures::CircleFixture;
+use carecircle::*;
+use odra::host::{Deployer, HostEnv, NoArgs};
+use odra::Address;
+
+const MINUTE: u64 = 60_000;
+const HOUR: u64 = 60 * MINUTE;
+const DAY: u64 = 24 * HOUR;
+const WEEK: u64 = 7 * DAY;
+/// Monday 1970-01-05, when week bucket 1 starts
+const WEEK_1: u64 = 4 * DAY;
+
+struct Clock {
+    env: HostEnv,
+    contract: CareCircleHostRef,
+    owner: Address,
+    circle_id: u64,
+}
+
+impl Clock {
+    /// A one-member circle keeping `utc_offset_mins` with `dst_rule`
+    fn new(utc_offset_mins: i32, dst_rule: DstRule) -> Self {
+        let env = odra_test::env();
+        let owner = env.get_account(0);
+        let mut contract = CareCircleHostRef::deploy(&env, NoArgs);
+        let circle_id = CircleFixture::new("Mom's Care", owner)
+            .settings(CircleSettings { utc_offset_mins, dst_rule, ..Default::default() })
+            .build(&env, &mut contract)
+            .circle_id;
+        Self { env, contract, owner, circle_id }
+    }
+
+    /// Move the block time forward to `timestamp`
+    fn at(&mut self, timestamp: u64) -> &mut Self {
+        self.env.advance_block_time(timestamp - self.env.block_time());
+        self
+    }
+
+    fn week(&self) -> u64 {
+        self.contract.get_current_period(self.circle_id, LeaderboardPeriod::Week)
+    }
+
+    fn month(&self) -> u64 {
+        self.contract.get_current_period(self.circle_id, LeaderboardPeriod::Month)
+    }
+
+    /// Log a meal now, counted on the current local day
+    fn log_meal(&mut self) {
+        self.contract.log_meal(self.circle_id, None, MealType::Lunch, 100, [1; 32]);
+    }
+
+    /// Meals counted on a local day, as days since 1970-01-01
+    fn meals_on(&self, day: u64) -> u64 {
+        self.contract.get_meal_days_for(self.owner, self.circle_id, day, 1)[0].meals
+    }
+
+    fn next_local_time(&self, minute_of_day: u64) -> u64 {
+        self.contract.get_next_local_time(self.circle_id, minute_of_day)
+    }
+}
+
+/// 2024-03-31 01:00 UTC, the last day of March and its last Sunday
+const EU_START_2024: u64 = 1_711_846_800_000;
+/// 2027-10-31 01:00 UTC, the last day of October and its last Sunday
+const EU_END_2027: u64 = 1_824_944_400_000;
+/// 2026-03-08 07:00 UTC, 02:00 EST on the second Sunday of a March starting on a Sunday
+const US_START_2026: u64 = 1_772_953_200_000;
+/// 2026-11-01 06:00 UTC, 02:00 EDT on the first Sunday of November, its first day
+const US_END_2026: u64 = 1_793_512_800_000;
+
+#[test]
+fn eu_clocks_change_at_one_utc() {
+    // At UTC-1:30, local midnight falls inside the hour the clocks skip or repeat
+    let mut c = Clock::new(-90, DstRule::Eu);
+    let sunday = EU_START_2024 / DAY;
+    c.at(EU_START_2024 - 1).log_meal(); // 23:29:59.999 Saturday, standard time
+    c.at(EU_START_2024).log_meal(); // 00:30 Sunday, summer time
+    assert_eq!((c.meals_on(sunday - 1), c.meals_on(sunday)), (1, 1));
+
+    let sunday = EU_END_2027 / DAY;
+    c.at(EU_END_2027 - 1).log_meal(); // 00:29:59.999 Sunday, summer time
+    c.at(EU_END_2027).log_meal(); // 23:30 Saturday, standard time again
+    c.at(EU_END_2027 + 30 * MINUTE).log_meal(); // Sunday again
+    assert_eq!((c.meals_on(sunday - 1), c.meals_on(sunday)), (1, 2));
+}
+
+#[test]
+fn us_clocks_change_at_two_local() {
+    let mut c = Clock::new(-5 * 60, DstRule::Us);
+
+    // 01:59:59.999 EST: 03:00 comes a millisecond later, when clocks jump to EDT
+    c.at(US_START_2026 - 1);
+    assert_eq!(c.next_local_time(3 * 60), US_START_2026);
+    // 03:00 EDT: the next 03:00 is tomorrow's
+    c.at(US_START_2026);
+    assert_eq!(c.next_local_time(3 * 60), US_START_2026 + DAY);
+
+    // 01:59:59.999 EDT: 01:30 has passed until tomorrow, in standard time
+    c.at(US_END_2026 - 1);
+    assert_eq!(c.next_local_time(90), US_END_2026 + DAY + 30 * MINUTE);
+    // 01:00 EST: the repeated hour brings 01:30 round again
+    c.at(US_END_2026);
+    assert_eq!(c.next_local_time(90), US_END_2026 + 30 * MINUTE);
+}
+
+#[test]
+fn month_buckets_follow_the_local_calendar() {
+    // Months since January 1970
+    const FEB_2024: u64 = 54 * 12 + 1;
+    const DEC_2025: u64 = 55 * 12 + 11;
+    const FEB_2026: u64 = 56 * 12 + 1;
+    const JUN_2026: u64 = 56 * 12 + 5;
+
+    // Leap day into March, in UTC
+    let mut c = Clock::new(0, DstRule::None);
+    let march_2024 = 1_709_251_200_000;
+    assert_eq!(c.at(march_2024 - 1).month(), FEB_2024);
+    assert_eq!(c.at(march_2024).month(), FEB_2024 + 1);
+
+    // New year at 23:00 UTC, one hour ahead
+    let mut c = Clock::new(60, DstRule::None);
+    let new_year_2026 = 1_767_222_000_000;
+    assert_eq!(c.at(new_year_2026 - 1).month(), DEC_2025);
+    assert_eq!(c.at(new_year_2026).month(), DEC_2025 + 1);
+
+    // The end of a non-leap February at 05:00 UTC, five hours behind
+    let mut c = Clock::new(-5 * 60, DstRule::None);
+    let march_2026 = 1_772_341_200_000;
+    assert_eq!(c.at(march_2026 - 1).month(), FEB_2026);
+    assert_eq!(c.at(march_2026).month(), FEB_2026 + 1);
+
+    // July starts at 22:00 UTC in central European summer time
+    let mut c = Clock::new(60, DstRule::Eu);
+    let july_2026 = 1_782_856_800_000;
+    assert_eq!(c.at(july_2026 - 1).month(), JUN_2026);
+    assert_eq!(c.at(july_2026).month(), JUN_2026 + 1);
+}
+
+#[test]
+fn weeks_start_on_local_monday() {
+    // Weeks since the one holding the Unix epoch, Monday 1969-12-29
+    const WEEK_OF_OCT_12_2026: u64 = 2963;
+
+    // Midnight into Monday 2026-10-12 is 22:00 UTC in central European summer time
+    let mut c = Clock::new(60, DstRule::Eu);
+    let monday = 1_791_756_000_000;
+    assert_eq!(c.at(monday - 1).week(), WEEK_OF_OCT_12_2026 - 1);
+    assert_eq!(c.at(monday).week(), WEEK_OF_OCT_12_2026);
+    // Thursday, when the epoch's weekday comes round, stays in the same week
+    assert_eq!(c.at(monday + 3 * DAY).week(), WEEK_OF_OCT_12_2026);
+    assert_eq!(c.at(monday + WEEK - 1).week(), WEEK_OF_OCT_12_2026);
+    assert_eq!(c.at(monday + WEEK).week(), WEEK_OF_OCT_12_2026 + 1);
+}
+
+#[test]
+fn due_dates_stay_in_the_week_they_were_counted_in() {
+    let mut c = Clock::new(0, DstRule::None);
+    let cid = c.circle_id;
+    c.at(HOUR);
+    let task_id = c.contract.create_task(cid, "Pharmacy pickup".to_string(), c.owner, 1);
+    // Week 1 in UTC, but week 2 once the circle moves to UTC+14
+    c.contract.set_due_date(task_id, WEEK_1 + WEEK - HOUR);
+    c.contract.update_settings(cid, CircleSettings { utc_offset_mins: 14 * 60, ..Default::default() });
+
+    // Rescheduling takes the count out of week 1, where it was made
+    c.contract.set_due_date(task_id, WEEK_1 + 2 * WEEK + DAY);
+    c.at(WEEK_1 + 4 * WEEK);
+    let overdue: Vec<u64> = (0..4).map(|_| c.contract.finalize_week(cid).overdue).collect();
+    assert_eq!(overdue, vec![0, 0, 0, 1]);
+}
@@ -0,0 +1,1281 @@
+//! Multi-actor scenarios.
+//!
+//! Each test walks one lifecycle of a single care circle with the same cast,
+//! asserting both the happy path and the calls each persona must not be able
+//! to make:
+//!
+//! - `admin`: deployed the contract and verifies agencies
+//! - `owner`: created the circle, invites members and verifies work
+//! - `mom`: the care recipient, who decides who reads her records
+//! - `daughter`, `son`: family members sharing the tasks
+//! - `agency`: a verified home-care agency, operating `caregiver`
+//! - `volunteer`: a registered volunteer from outside the circle
+//! - `stranger`: an address with no role anywhere
+
+use carecircle::fixtures::CircleFixture;
+use carecircle::*;
+use odra::casper_types::bytesrepr::Bytes;
+use odra::casper_types::crypto::blake2b;
+use odra::host::{Deployer, HostEnv, NoArgs};
+use odra::Address;
+
+const JOIN_CODE: &str = "mom-2026";
+const HOUR: u64 = 3_600_000;
+
+struct Cast {
+    admin: Address,
+    owner: Address,
+    mom: Address,
+    daughter: Address,
+    son: Address,
+    agency: Address,
+    caregiver: Address,
+    volunteer: Address,
+    stranger: Address,
+}
+
+struct Scenario {
+    env: HostEnv,
+    contract: CareCircleHostRef,
+    cast: Cast,
+    circle_id: u64,
+}
+
+impl Scenario {
+    /// Deploy as the admin and create the owner's circle with both family
+    /// members, the daughter added directly, the son through a join code,
+    /// and the owner's mother as care recipient
+    fn new() -> Self {
+        let env = odra_test::env();
+        let cast = Cast {
+            admin: env.get_account(0),
+            owner: env.get_account(1),
+            mom: env.get_account(8),
+            daughter: env.get_account(2),
+            son: env.get_account(3),
+            agency: env.get_account(4),
+            caregiver: env.get_account(5),
+            volunteer: env.get_account(6),
+            stranger: env.get_account(7),
+        };
+        env.set_caller(cast.admin);
+        let mut contract = CareCircleHostRef::deploy(&env, NoArgs);
+
+        let circle_id = CircleFixture::new("Mom's Care", cast.owner)
+            .member(cast.daughter)
+            .build(&env, &mut contract)
+            .circle_id;
+        contract.set_care_recipient(circle_id, cast.mom);
+        contract.create_join_code(circle_id, blake2b(JOIN_CODE), 1, 0);
+        env.set_caller(cast.son);
+        contract.join_with_code(circle_id, JOIN_CODE.to_string());
+
+        Self { env, contract, cast, circle_id }
+    }
+
+    fn as_caller(&mut self, caller: Address) -> &mut CareCircleHostRef {
+        self.env.set_caller(caller);
+        &mut self.contract
+    }
+
+    fn is_suspended(&self, member: Address) -> bool {
+        self.contract
+            .get_members(self.circle_id, 0, 20)
+            .iter()
+            .any(|info| info.address == member && info.is_suspended)
+    }
+}
+
+#[test]
+fn invitations() {
+    let mut s = Scenario::new();
+    let (cid, cast) = (s.circle_id, &s.cast);
+    let (owner, daughter, son, stranger) = (cast.owner, cast.daughter, cast.son, cast.stranger);
+
+    assert!(s.contract.check_is_member(cid, daughter));
+    assert!(s.contract.check_is_member(cid, son));
+    assert_eq!(s.contract.get_member_count(cid), 3);
+
+    // The single-use code is spent, and guessing another code fails
+    assert_eq!(s.as_caller(stranger).try_join_with_code(cid, JOIN_CODE.to_string()).unwrap_err(), Error::JoinCodeExhausted.into());
+    assert_eq!(s.as_caller(stranger).try_join_with_code(cid, "guess".to_string()).unwrap_err(), Error::InvalidJoinCode.into());
+
+    // Only the owner invites
+    assert_eq!(s.as_caller(daughter).try_add_member(cid, stranger).unwrap_err(), Error::NotOwner.into());
+    assert_eq!(
+        s.as_caller(son).try_create_join_code(cid, blake2b("second"), 5, 0).unwrap_err(),
+        Error::NotOwner.into()
+    );
+    s.as_caller(owner).create_join_code(cid, blake2b("second"), 5, 0);
+    s.as_caller(owner).revoke_join_code(cid, blake2b("second"));
+    assert_eq!(s.as_caller(stranger).try_join_with_code(cid, "second".to_string()).unwrap_err(), Error::InvalidJoinCode.into());
+    assert!(!s.contract.check_is_member(cid, stranger));
+
+    // Onboarding tasks count towards the joining member's open task cap
+    let onboarding = ["Read the care plan", "Share your availability"]
+        .map(|title| TaskTemplate { title: title.to_string(), priority: 1 });
+    s.as_caller(owner).set_onboarding_tasks(cid, onboarding.to_vec());
+    s.contract.update_settings(cid, CircleSettings { max_open_per_member: 1, ..Default::default() });
+    s.contract.create_join_code(cid, blake2b("third"), 1, 0);
+    assert_eq!(
+        s.as_caller(stranger).try_join_with_code(cid, "third".to_string()).unwrap_err(),
+        Error::AssignmentLimitReached.into()
+    );
+    s.as_caller(owner).update_settings(cid, CircleSettings { max_open_per_member: 2, ..Default::default() });
+    s.as_caller(stranger).join_with_code(cid, "third".to_string());
+    let open = s.contract.get_open_tasks(cid, 10);
+    assert_eq!(open.iter().filter(|task| task.assigned_to == stranger && task.created_by == owner).count(), 2);
+}
+
+#[test]
+fn family_task_boundaries() {
+    let mut s = Scenario::new();
+    let (cid, cast) = (s.circle_id, &s.cast);
+    let (owner, daughter, son, stranger) = (cast.owner, cast.daughter, cast.son, cast.stranger);
+
+    // Members create tasks for each other; outsiders and non-members cannot be involved
+    let pickup = s.as_caller(daughter).create_task(cid, "Pharmacy pickup".to_string(), son, 2);
+    assert_eq!(
+        s.as_caller(stranger).try_create_task(cid, "Spam".to_string(), son, 1).unwrap_err(),
+        Error::NotAMember.into()
+    );
+    assert_eq!(
+        s.as_caller(daughter).try_create_task(cid, "Errand".to_string(), stranger, 1).unwrap_err(),
+        Error::AssigneeNotAMember.into()
+    );
+
+    // Only the assignee completes, and only once
+    assert_eq!(s.as_caller(daughter).try_complete_task(pickup).unwrap_err(), Error::NotAssignee.into());
+    assert_eq!(s.as_caller(owner).try_complete_task(pickup).unwrap_err(), Error::NotAssignee.into());
+    s.as_caller(son).complete_task(pickup);
+    assert!(s.contract.get_task(pickup).unwrap().completed);
+    assert_eq!(s.as_caller(son).try_complete_task(pickup).unwrap_err(), Error::AlreadyCompleted.into());
+
+    // Settings and suspensions belong to the owner
+    let settings = CircleSettings { require_verification: true, ..Default::default() };
+    assert_eq!(s.as_caller(daughter).try_update_settings(cid, settings.clone()).unwrap_err(), Error::NotOwner.into());
+    assert!(s.as_caller(son).try_suspend_member(cid, daughter).is_err());
+    s.as_caller(owner).suspend_member(cid, son);
+    assert_eq!(
+        s.as_caller(son).try_create_task(cid, "Laundry".to_string(), daughter, 1).unwrap_err(),
+        Error::MemberSuspended.into()
+    );
+    s.as_caller(owner).reinstate_member(cid, son);
+    s.as_caller(son).create_task(cid, "Laundry".to_string(), daughter, 1);
+}
+
+#[test]
+fn private_visibility() {
+    let mut s = Scenario::new();
+    let (cid, cast) = (s.circle_id, &s.cast);
+    let (owner, son, stranger) = (cast.owner, cast.son, cast.stranger);
+
+    s.as_caller(owner).update_settings(cid, CircleSettings { visibility: Visibility::Private, ..Default::default() });
+    let meds = s.as_caller(owner).create_task(cid, "Morning meds".to_string(), son, 1);
+    s.as_caller(son).complete_task(meds);
+
+    // The plain views return what is stored; the `_for` twins redact for the viewer they are given
+    assert_eq!(s.contract.get_task(meds).unwrap().title, "Morning meds");
+    assert_eq!(s.contract.get_task_for(son, meds).unwrap().title, "Morning meds");
+    assert!(s.contract.get_task_for(stranger, meds).unwrap().title.is_empty());
+    assert_eq!(s.contract.get_completion_count(cid), 1);
+    assert_eq!(s.contract.get_completion_count_for(son, cid), 1);
+    assert_eq!(s.contract.get_completion_count_for(stranger, cid), 0);
+}
+
+#[test]
+fn verification_and_dispute() {
+    let mut s = Scenario::new();
+    let (cid, cast) = (s.circle_id, &s.cast);
+    let (owner, daughter, son) = (cast.owner, cast.daughter, cast.son);
+
+    s.as_caller(owner).update_settings(cid, CircleSettings { require_verification: true, ..Default::default() });
+    let meds = s.as_caller(owner).create_task(cid, "Evening medication".to_string(), daughter, 3);
+
+    // A submitted completion waits for the owner and blocks resubmission
+    s.as_caller(daughter).complete_task(meds);
+    assert!(s.contract.get_pending_completion(meds).is_some());
+    assert!(!s.contract.get_task(meds).unwrap().completed);
+    assert_eq!(s.as_caller(daughter).try_complete_task(meds).unwrap_err(), Error::CompletionPendingVerification.into());
+
+    // Other members cannot settle it either way
+    assert_eq!(s.as_caller(son).try_verify_completion(meds, true).unwrap_err(), Error::NotOwner.into());
+    assert_eq!(s.as_caller(daughter).try_verify_completion(meds, true).unwrap_err(), Error::NotOwner.into());
+
+    // The owner disputes it: the task reopens and nothing is counted
+    s.as_caller(owner).verify_completion(meds, false);
+    assert!(s.contract.get_pending_completion(meds).is_none());
+    assert!(!s.contract.get_task(meds).unwrap().completed);
+    assert_eq!(s.contract.get_stats().2, 0);
+    assert_eq!(s.as_caller(owner).try_verify_completion(meds, true).unwrap_err(), Error::NoPendingCompletion.into());
+
+    // Resubmitted and approved, it counts once
+    s.as_caller(daughter).complete_task(meds);
+    s.as_caller(owner).verify_completion(meds, true);
+    assert!(s.contract.get_task(meds).unwrap().completed);
+    assert_eq!(s.contract.get_stats().2, 1);
+    assert_eq!(s.contract.get_completion_count(cid), 1);
+}
+
+#[test]
+fn verification_timeout() {
+    let mut s = Scenario::new();
+    let (cid, cast) = (s.circle_id, &s.cast);
+    let (owner, daughter, stranger) = (cast.owner, cast.daughter, cast.stranger);
+    let timeout = |action| CircleSettings {
+        require_verification: true,
+        verification_timeout_secs: 3_600,
+        timeout_action: action,
+        ..Default::default()
+    };
+
+    // Anyone may settle a completion the owner left waiting, but only once the hour is up
+    s.as_caller(owner).update_settings(cid, timeout(TimeoutAction::Confirm));
+    let meds = s.contract.create_task(cid, "Evening medication".to_string(), daughter, 3);
+    s.as_caller(daughter).complete_task(meds);
+    s.env.advance_block_time(HOUR / 2);
+    assert_eq!(s.as_caller(stranger).try_finalize_unverified(meds).unwrap_err(), Error::VerificationTimeoutNotReached.into());
+    s.env.advance_block_time(HOUR / 2);
+    s.contract.finalize_unverified(meds);
+    assert!(s.contract.get_task(meds).unwrap().completed);
+    assert_eq!(s.contract.get_completion_count(cid), 1);
+    assert_eq!(s.contract.try_finalize_unverified(meds).unwrap_err(), Error::NoPendingCompletion.into());
+
+    // Set to dispute, the timeout reopens the task instead
+    s.as_caller(owner).update_settings(cid, timeout(TimeoutAction::Dispute));
+    let walk = s.contract.create_task(cid, "Afternoon walk".to_string(), daughter, 1);
+    s.as_caller(daughter).complete_task(walk);
+    s.env.advance_block_time(HOUR);
+    s.as_caller(stranger).finalize_unverified(walk);
+    assert!(s.contract.get_pending_completion(walk).is_none());
+    assert!(!s.contract.get_task(walk).unwrap().completed);
+    assert_eq!(s.contract.get_completion_count(cid), 1);
+    let rejected = CompletionRejected { task_id: walk, circle_id: cid, rejected_by: stranger, routes: Vec::new() };
+    assert!(s.env.emitted_event(&s.contract, &rejected));
+
+    // Without a timeout, only the owner settles
+    s.as_caller(owner).update_settings(cid, CircleSettings { require_verification: true, ..Default::default() });
+    s.as_caller(daughter).complete_task(walk);
+    s.env.advance_block_time(1_000 * HOUR);
+    assert_eq!(s.as_caller(stranger).try_finalize_unverified(walk).unwrap_err(), Error::VerificationTimeoutNotReached.into());
+}
+
+#[test]
+fn receipt_chain() {
+    let mut s = Scenario::new();
+    let (cid, cast) = (s.circle_id, &s.cast);
+    let (owner, daughter) = (cast.owner, cast.daughter);
+
+    // An unverified completion leaves no receipt
+    let walk = s.as_caller(owner).create_task(cid, "Morning walk".to_string(), daughter, 1);
+    s.as_caller(daughter).complete_task(walk);
+    assert_eq!((s.contract.get_receipt_head(daughter), s.contract.get_receipt_count(daughter)), ([0; 32], 0));
+
+    // An approved one extends the daughter's chain from the zero head
+    s.as_caller(owner).update_settings(cid, CircleSettings { require_verification: true, ..Default::default() });
+    let meds = s.contract.create_task(cid, "Evening medication".to_string(), daughter, 3);
+    s.env.advance_block_time(HOUR);
+    s.as_caller(daughter).complete_task(meds);
+    s.as_caller(owner).verify_completion(meds, true);
+    let head = s.contract.get_receipt_head(daughter);
+    assert_ne!(head, [0; 32]);
+    assert_eq!(s.contract.get_receipt_count(daughter), 1);
+
+    // Anyone can check the segment against the head, and a doctored one fails
+    let receipt = CompletionReceipt { task_id: meds, circle_id: cid, timestamp: s.contract.get_task(meds).unwrap().completed_at };
+    assert!(s.contract.verify_receipt_chain([0; 32], vec![receipt.clone()], head));
+    let doctored = CompletionReceipt { task_id: walk, ..receipt };
+    assert!(!s.contract.verify_receipt_chain([0; 32], vec![doctored], head));
+}
+
+#[test]
+fn mileage_payout() {
+    let mut s = Scenario::new();
+    let (cid, cast) = (s.circle_id, &s.cast);
+    let (owner, daughter, son, stranger) = (cast.owner, cast.daughter, cast.son, cast.stranger);
+
+    s.as_caller(owner).update_settings(cid, CircleSettings { mileage_rate: 40, ..Default::default() });
+    let visit = s.as_caller(owner).create_task(cid, "Drive to clinic".to_string(), son, 2);
+    let month = s.contract.get_current_period(cid, LeaderboardPeriod::Month);
+
+    // Only members log trips and claim them
+    assert_eq!(s.as_caller(stranger).try_log_trip(cid, visit, 10_000, [1u8; 32]).unwrap_err(), Error::NotAMember.into());
+    s.as_caller(son).log_trip(cid, visit, 20_000, [1u8; 32]);
+    assert_eq!(s.as_caller(stranger).try_request_reimbursement(cid, month).unwrap_err(), Error::NotAMember.into());
+    assert_eq!(
+        s.as_caller(daughter).try_request_reimbursement(cid, month).unwrap_err(),
+        Error::NothingToReimburse.into()
+    );
+    let request = s.as_caller(son).request_reimbursement(cid, month);
+    assert_eq!(s.contract.get_reimbursement(cid, request).unwrap().amount, 800);
+    assert_eq!(s.as_caller(son).try_request_reimbursement(cid, month).unwrap_err(), Error::NothingToReimburse.into());
+
+    // Claimants cannot approve their own payout; the owner settles it once
+    assert_eq!(s.as_caller(son).try_resolve_reimbursement(cid, request, true).unwrap_err(), Error::NotOwner.into());
+    s.as_caller(owner).resolve_reimbursement(cid, request, true);
+    assert_eq!(s.contract.get_reimbursement(cid, request).unwrap().status, ReimbursementStatus::Approved);
+    assert_eq!(
+        s.as_caller(owner).try_resolve_reimbursement(cid, request, false).unwrap_err(),
+        Error::ReimbursementResolved.into()
+    );
+
+    // A disputed claim is rejected and stays claimed
+    s.as_caller(son).log_trip(cid, visit, 5_000, [2u8; 32]);
+    let second = s.as_caller(son).request_reimbursement(cid, month);
+    s.as_caller(owner).resolve_reimbursement(cid, second, false);
+    assert_eq!(s.contract.get_reimbursement(cid, second).unwrap().status, ReimbursementStatus::Rejected);
+    assert_eq!(s.as_caller(son).try_request_reimbursement(cid, month).unwrap_err(), Error::NothingToReimburse.into());
+}
+
+#[test]
+fn agency_caregiver_lifecycle() {
+    let mut s = Scenario::new();
+    let (cid, cast) = (s.circle_id, &s.cast);
+    let (admin, owner, daughter, agency, caregiver) = (cast.admin, cast.owner, cast.daughter, cast.agency, cast.caregiver);
+
+    // The agency must be verified by the admin, not by itself or a circle owner
+    assert_eq!(s.as_caller(agency).try_register_caregiver(caregiver).unwrap_err(), Error::AgencyNotVerified.into());
+    assert_eq!(s.as_caller(agency).try_set_agency_verified(agency, true).unwrap_err(), Error::NotAdmin.into());
+    assert_eq!(s.as_caller(owner).try_set_agency_verified(agency, true).unwrap_err(), Error::NotAdmin.into());
+    s.as_caller(admin).set_agency_verified(agency, true);
+    s.as_caller(agency).register_caregiver(caregiver);
+    assert_eq!(s.contract.get_caregiver_agency(caregiver), Some(agency));
+
+    // Only the owner brings the agency in, which adds its caregivers
+    assert_eq!(s.as_caller(daughter).try_grant_agency(cid, agency).unwrap_err(), Error::NotOwner.into());
+    assert_eq!(s.as_caller(owner).grant_agency(cid, agency), 1);
+    assert!(s.contract.check_is_member(cid, caregiver));
+    assert_eq!(s.contract.get_member_agency(cid, caregiver), Some(agency));
+
+    let bath = s.as_caller(owner).create_task(cid, "Bathing assistance".to_string(), caregiver, 2);
+    s.as_caller(caregiver).complete_task(bath);
+    assert!(s.contract.get_task(bath).unwrap().completed);
+    assert_eq!(s.contract.get_work_history(caregiver, 0, 10).len(), 1);
+
+    // Once the agency lets the caregiver go, the next resync suspends them
+    let lunch = s.as_caller(owner).create_task(cid, "Lunch".to_string(), caregiver, 1);
+    s.as_caller(agency).deregister_caregiver(caregiver);
+    assert_eq!(s.as_caller(owner).grant_agency(cid, agency), 0);
+    assert!(s.is_suspended(caregiver));
+    assert_eq!(s.as_caller(caregiver).try_complete_task(lunch).unwrap_err(), Error::MemberSuspended.into());
+}
+
+#[test]
+fn volunteer_lifecycle() {
+    let mut s = Scenario::new();
+    let (cid, cast) = (s.circle_id, &s.cast);
+    let (owner, daughter, son, volunteer, stranger) = (cast.owner, cast.daughter, cast.son, cast.volunteer, cast.stranger);
+
+    let walk = s.as_caller(owner).create_task(cid, "Afternoon walk".to_string(), daughter, 1);
+
+    // Applying needs a registered volunteer and a listed task
+    assert_eq!(s.as_caller(volunteer).try_apply_for_task(walk).unwrap_err(), Error::NotAVolunteer.into());
+    s.as_caller(volunteer).register_volunteer();
+    assert_eq!(s.as_caller(volunteer).try_apply_for_task(walk).unwrap_err(), Error::NotOpenToVolunteers.into());
+    assert_eq!(s.as_caller(daughter).try_set_open_to_volunteers(walk, true).unwrap_err(), Error::NotOwner.into());
+    s.as_caller(owner).set_open_to_volunteers(walk, true);
+    assert_eq!(s.contract.get_volunteer_board(0, 10), vec![walk]);
+    s.as_caller(volunteer).apply_for_task(walk);
+    assert_eq!(s.as_caller(volunteer).try_apply_for_task(walk).unwrap_err(), Error::AlreadyApplied.into());
+    assert_eq!(s.contract.get_task_applicants(walk), vec![volunteer]);
+
+    // Only the owner accepts, and only actual applicants
+    assert_eq!(s.as_caller(son).try_accept_volunteer(walk, volunteer).unwrap_err(), Error::NotOwner.into());
+    assert_eq!(s.as_caller(owner).try_accept_volunteer(walk, stranger).unwrap_err(), Error::NotAnApplicant.into());
+    s.as_caller(owner).accept_volunteer(walk, volunteer);
+    assert_eq!(s.contract.get_task(walk).unwrap().assigned_to, volunteer);
+
+    // The task now belongs to the volunteer alone
+    assert_eq!(s.as_caller(daughter).try_complete_task(walk).unwrap_err(), Error::NotAssignee.into());
+    s.as_caller(volunteer).complete_task(walk);
+    assert!(s.contract.get_task(walk).unwrap().completed);
+    assert_eq!(s.as_caller(volunteer).try_complete_task(walk).unwrap_err(), Error::AlreadyCompleted.into());
+}
+
+#[test]
+fn volunteer_board_listing() {
+    let mut s = Scenario::new();
+    let (cid, cast) = (s.circle_id, &s.cast);
+    let (owner, daughter) = (cast.owner, cast.daughter);
+
+    let walk = s.as_caller(owner).create_task(cid, "Afternoon walk".to_string(), daughter, 1);
+    let shopping = s.contract.create_task(cid, "Weekly shopping".to_string(), daughter, 1);
+
+    // Unlisting and listing again keeps a single board entry
+    s.contract.set_open_to_volunteers(walk, true);
+    s.contract.set_open_to_volunteers(walk, false);
+    assert_eq!(s.contract.get_volunteer_board(0, 10), Vec::<u64>::new());
+    s.contract.set_open_to_volunteers(walk, true);
+    s.contract.set_open_to_volunteers(shopping, true);
+    assert_eq!(s.contract.get_volunteer_board(0, 10), vec![walk, shopping]);
+
+    // Done by its assignee, a task leaves the board
+    s.as_caller(daughter).complete_task(walk);
+    assert_eq!(s.contract.get_volunteer_board(0, 10), vec![shopping]);
+}
+
+#[test]
+fn member_erasure() {
+    let mut s = Scenario::new();
+    let (cid, cast) = (s.circle_id, &s.cast);
+    let (owner, daughter, son, stranger) = (cast.owner, cast.daughter, cast.son, cast.stranger);
+
+    for _ in 0..30 {
+        s.as_caller(daughter).log_symptom(cid, [7; 32], 4, [1; 32]);
+        s.as_caller(daughter).log_meal(cid, None, MealType::Lunch, 80, [1; 32]);
+    }
+    s.as_caller(son).log_symptom(cid, [7; 32], 2, [2; 32]);
+    s.as_caller(daughter).update_status(cid, 1, [1; 32]);
+    let meds = s.as_caller(owner).create_task(cid, "Morning meds".to_string(), daughter, 1);
+    s.as_caller(daughter).complete_task(meds);
+    let evening = s.as_caller(owner).create_task(cid, "Evening meds".to_string(), daughter, 1);
+    s.as_caller(daughter).add_evidence(evening, [3; 32], EvidenceKind::Photo);
+    s.as_caller(daughter).request_help(evening, "Running late from work".to_string());
+
+    // Only the member or the admin erases, and a keeper has nothing to purge yet
+    assert_eq!(s.as_caller(stranger).try_erase_member_data(cid, daughter).unwrap_err(), Error::NotAdmin.into());
+    assert_eq!(s.as_caller(stranger).purge_erased_notes(cid, daughter, 50), 0);
+    let pseudonym = s.as_caller(daughter).erase_member_data(cid, daughter);
+
+    // The erasure examines a first page of 50: all 31 symptoms, then 19 meals
+    assert_eq!(s.contract.get_erasure_cursor(cid, daughter, RetainedLog::Symptoms), 31);
+    assert_eq!(s.contract.get_erasure_cursor(cid, daughter, RetainedLog::Meals), 19);
+    // The keeper finishes the meals, her evidence and her help request
+    assert_eq!(s.as_caller(stranger).purge_erased_notes(cid, daughter, 50), 13);
+    assert_eq!(s.as_caller(stranger).purge_erased_notes(cid, daughter, 50), 0);
+    assert_eq!(s.as_caller(stranger).purge_erased_notes(cid, son, 50), 0);
+
+    // Her notes are gone, the son's are kept
+    s.env.set_caller(owner);
+    let symptoms = s.contract.get_symptom_log_for(owner, cid, 0, 50);
+    assert!(symptoms[..30].iter().all(|entry| entry.notes_hash == [0; 32] && entry.logged_by == pseudonym));
+    assert_eq!((symptoms[30].notes_hash, symptoms[30].logged_by), ([2; 32], son));
+    assert_eq!(s.contract.get_status(cid).unwrap().note_hash, [0; 32]);
+    assert_eq!(s.contract.get_evidence(evening, 0, 1)[0].content_hash, [0; 32]);
+    assert_eq!(s.contract.get_help_request(evening), Some(String::new()));
+    // The `_for` views answer for the viewer they are given, not for whoever calls them
+    assert!(s.contract.get_symptom_log_for(stranger, cid, 0, 50).is_empty());
+    assert!(s.contract.get_status_for(stranger, cid).is_none());
+
+    // Views returning an address show the pseudonym
+    let week = s.contract.get_current_period(cid, LeaderboardPeriod::Week);
+    assert_eq!(s.contract.get_leaderboard(cid, LeaderboardPeriod::Week, week, 5)[0].member, pseudonym);
+    let workload = s.contract.get_workload(cid);
+    assert!(workload.iter().any(|entry| entry.member == pseudonym && entry.open_tasks == 1));
+    assert!(workload.iter().all(|entry| entry.member != daughter));
+}
+
+#[test]
+fn suspension_lifecycle() {
+    let mut s = Scenario::new();
+    let (cid, cast) = (s.circle_id, &s.cast);
+    let (owner, daughter, son, volunteer) = (cast.owner, cast.daughter, cast.son, cast.volunteer);
+
+    s.as_caller(owner).update_settings(cid, CircleSettings { mileage_rate: 40, ..Default::default() });
+    s.as_caller(owner).appoint_guardian(cid, daughter);
+    let errand = s.as_caller(daughter).create_task(cid, "Pharmacy run".to_string(), son, 1);
+    s.as_caller(son).log_trip(cid, errand, 20_000, [1u8; 32]);
+    let month = s.contract.get_current_period(cid, LeaderboardPeriod::Month);
+    let (now, day) = (s.env.block_time(), 86_400_000);
+
+    // Suspended members cannot claim, edit the tasks they created or act as guardian
+    s.as_caller(owner).suspend_member(cid, son);
+    s.as_caller(owner).suspend_member(cid, daughter);
+    assert_eq!(s.as_caller(son).try_request_reimbursement(cid, month).unwrap_err(), Error::MemberSuspended.into());
+    assert_eq!(s.as_caller(daughter).try_set_due_date(errand, now + day).unwrap_err(), Error::MemberSuspended.into());
+    assert_eq!(
+        s.as_caller(daughter).try_create_episode(cid, "Rehab".to_string(), now, now + day).unwrap_err(),
+        Error::MemberSuspended.into()
+    );
+    s.as_caller(owner).reinstate_member(cid, son);
+    s.as_caller(son).request_reimbursement(cid, month);
+
+    // A suspension follows the member into the circle theirs is merged into
+    let respite = s.as_caller(owner).create_circle("Respite care".to_string());
+    s.contract.add_member(respite, volunteer);
+    s.contract.suspend_member(respite, volunteer);
+    s.contract.merge_circles(respite, cid);
+    assert!(s.is_suspended(volunteer));
+    assert!(s.is_suspended(daughter));
+}
+
+#[test]
+fn circle_merge() {
+    let mut s = Scenario::new();
+    let (cid, cast) = (s.circle_id, &s.cast);
+    let (owner, daughter, son, volunteer) = (cast.owner, cast.daughter, cast.son, cast.volunteer);
+
+    // The daughter runs a second circle for her father with a volunteer
+    let dad = s.as_caller(daughter).create_circle("Dad's Care".to_string());
+    s.contract.add_member(dad, volunteer);
+    s.contract.add_member(dad, son);
+    let shopping = s.contract.create_task(dad, "Weekly shopping".to_string(), volunteer, 1);
+    let done = s.contract.create_task(dad, "Book eye test".to_string(), daughter, 1);
+    s.contract.complete_task(done);
+    let errand = s.contract.create_task(dad, "Pick up prescription".to_string(), daughter, 1);
+    s.contract.propose_task_link(errand, cid, false);
+
+    // Only an owner of either circle approves, and both must before anything moves
+    assert_eq!(s.as_caller(son).try_merge_circles(dad, cid).unwrap_err(), Error::NotOwner.into());
+    s.as_caller(owner).merge_circles(dad, cid);
+    assert_eq!(s.contract.get_merge_approval(dad, cid), (false, true));
+    assert!(s.contract.is_circle_active(dad));
+    assert!(!s.contract.check_is_member(cid, volunteer));
+    s.as_caller(daughter).merge_circles(dad, cid);
+
+    // The volunteer and the open task come over, renumbered; the completed task and the source stay behind
+    assert!(s.contract.check_is_member(cid, volunteer));
+    assert_eq!(s.contract.get_member_count(cid), 4);
+    let moved = s.contract.get_task(shopping).unwrap();
+    assert_eq!((moved.circle_id, moved.circle_task_seq), (cid, 1));
+    assert_eq!(s.contract.get_task(done).unwrap().circle_id, dad);
+    assert!(!s.contract.is_circle_active(dad));
+    assert_eq!(s.contract.get_merge_approval(dad, cid), (false, false));
+    assert_eq!(s.as_caller(owner).try_merge_circles(dad, cid).unwrap_err(), Error::CircleArchived.into());
+
+    // The task with a pending link stays behind, and the archived source takes no more changes
+    assert_eq!(s.contract.get_task(errand).unwrap().circle_id, dad);
+    assert_eq!(s.as_caller(owner).try_accept_task_link(errand).unwrap_err(), Error::CircleArchived.into());
+    assert_eq!(
+        s.as_caller(daughter).try_update_settings(dad, CircleSettings::default()).unwrap_err(),
+        Error::CircleArchived.into()
+    );
+    assert_eq!(s.contract.try_set_open_to_volunteers(errand, true).unwrap_err(), Error::CircleArchived.into());
+    assert_eq!(s.contract.try_add_supply(dad, "Gloves".to_string(), 10, 2).unwrap_err(), Error::CircleArchived.into());
+    assert_eq!(s.contract.try_finalize_week(dad).unwrap_err(), Error::CircleArchived.into());
+    assert_eq!(s.contract.emit_due_reminders(dad, 3_600, 10), 0);
+}
+
+#[test]
+fn moving_tasks() {
+    let mut s = Scenario::new();
+    let (cid, cast) = (s.circle_id, &s.cast);
+    let (owner, daughter, son) = (cast.owner, cast.daughter, cast.son);
+
+    let respite = s.as_caller(owner).create_circle("Respite care".to_string());
+    s.contract.add_member(respite, daughter);
+    s.contract.create_task(respite, "Arrange cover".to_string(), owner, 1);
+    let meds = s.contract.create_task(cid, "Morning meds".to_string(), daughter, 1);
+    let laundry = s.contract.create_task(cid, "Laundry".to_string(), son, 1);
+    let done = s.contract.create_task(cid, "Refill pillbox".to_string(), daughter, 1);
+    s.as_caller(daughter).complete_task(done);
+
+    // Only the owner of both circles moves a task, and only somewhere its assignee belongs
+    assert_eq!(s.as_caller(daughter).try_move_task(meds, respite).unwrap_err(), Error::NotOwner.into());
+    assert_eq!(s.as_caller(owner).try_move_task(laundry, respite).unwrap_err(), Error::AssigneeNotAMember.into());
+    assert_eq!(s.as_caller(owner).try_move_task(meds, cid).unwrap_err(), Error::InvalidTargetCircle.into());
+    assert_eq!(s.as_caller(owner).try_move_task(done, respite).unwrap_err(), Error::AlreadyCompleted.into());
+
+    // The moved task takes the next number in its new circle
+    s.as_caller(owner).move_task(meds, respite);
+    let moved = s.contract.get_task(meds).unwrap();
+    assert_eq!((moved.circle_id, moved.circle_task_seq), (respite, 2));
+    assert_eq!((s.contract.get_task_count(cid), s.contract.get_task_count(respite)), (2, 2));
+    s.as_caller(daughter).complete_task(meds);
+    assert_eq!(s.contract.get_completion_count(respite), 1);
+}
+
+#[test]
+fn discharge_plan() {
+    let mut s = Scenario::new();
+    let (cid, cast) = (s.circle_id, &s.cast);
+    let (owner, daughter, son) = (cast.owner, cast.daughter, cast.son);
+    let discharge_at = s.env.block_time() + HOUR;
+
+    // A guardian starts the plan, but only while allowed to create tasks
+    s.as_caller(owner).appoint_guardian(cid, daughter);
+    s.contract.set_task_creator_override(cid, daughter, Some(false));
+    assert_eq!(
+        s.as_caller(daughter).try_start_discharge_plan(cid, discharge_at).unwrap_err(),
+        Error::TaskCreationNotAllowed.into()
+    );
+    s.as_caller(owner).set_task_creator_override(cid, daughter, None);
+
+    // Without auto-assignment all three steps fall back to the owner, who has room for two
+    s.contract.set_member_task_cap(cid, owner, Some(2));
+    assert_eq!(
+        s.as_caller(daughter).try_start_discharge_plan(cid, discharge_at).unwrap_err(),
+        Error::AssignmentLimitReached.into()
+    );
+    assert_eq!(s.contract.get_discharge_plan(cid), None);
+
+    // Auto-assignment passes over the suspended son and spreads the steps
+    s.as_caller(owner).update_settings(cid, CircleSettings { auto_assign_mode: AutoAssignMode::FewestOpen, ..Default::default() });
+    s.contract.suspend_member(cid, son);
+    let steps = s.as_caller(daughter).start_discharge_plan(cid, discharge_at);
+    let assignees: Vec<Address> = steps.iter().map(|id| s.contract.get_task(*id).unwrap().assigned_to).collect();
+    assert_eq!(assignees, vec![owner, daughter, owner]);
+    assert_eq!(s.contract.get_task(steps[0]).unwrap().due_at, discharge_at + 24 * HOUR);
+}
+
+#[test]
+fn shared_task() {
+    let mut s = Scenario::new();
+    let (cid, cast) = (s.circle_id, &s.cast);
+    let (owner, daughter, son, volunteer) = (cast.owner, cast.daughter, cast.son, cast.volunteer);
+
+    // The son runs a respite circle with the volunteer
+    let respite = s.as_caller(son).create_circle("Respite care".to_string());
+    s.contract.add_member(respite, volunteer);
+    let visit = s.as_caller(owner).create_task(cid, "Weekend visit".to_string(), daughter, 2);
+
+    // The task's owner proposes the link and the respite owner accepts it
+    assert_eq!(s.as_caller(daughter).try_propose_task_link(visit, respite, true).unwrap_err(), Error::NotOwner.into());
+    s.as_caller(owner).propose_task_link(visit, respite, true);
+    assert_eq!(s.as_caller(owner).try_accept_task_link(visit).unwrap_err(), Error::NotOwner.into());
+    s.as_caller(son).accept_task_link(visit);
+    assert_eq!(s.contract.get_task_secondary_circle(visit), Some(respite));
+
+    // Shared completion reaches the respite circle, not the rest of the task's own circle
+    assert_eq!(s.as_caller(owner).try_complete_task(visit).unwrap_err(), Error::NotAssignee.into());
+    s.as_caller(volunteer).complete_task(visit);
+    assert!(s.contract.get_task(visit).unwrap().completed);
+    assert_eq!(s.contract.get_completion_count(respite), 1);
+}
+
+#[test]
+fn ownership_succession() {
+    let mut s = Scenario::new();
+    let (cid, cast) = (s.circle_id, &s.cast);
+    let (owner, daughter, son, stranger) = (cast.owner, cast.daughter, cast.son, cast.stranger);
+
+    // The daughter may claim after an hour of owner silence, with ten minutes to object
+    assert_eq!(s.as_caller(son).try_designate_successor(cid, son, 3_600, 600).unwrap_err(), Error::NotOwner.into());
+    s.as_caller(owner).designate_successor(cid, daughter, 3_600, 600);
+    assert_eq!(s.as_caller(son).try_claim_ownership_by_inactivity(cid).unwrap_err(), Error::NotSuccessor.into());
+    assert_eq!(s.as_caller(daughter).try_claim_ownership_by_inactivity(cid).unwrap_err(), Error::OwnerStillActive.into());
+
+    // A suspended successor cannot claim
+    s.as_caller(owner).suspend_member(cid, daughter);
+    s.env.advance_block_time(HOUR);
+    assert_eq!(s.as_caller(daughter).try_claim_ownership_by_inactivity(cid).unwrap_err(), Error::MemberSuspended.into());
+    s.as_caller(owner).reinstate_member(cid, daughter);
+
+    // Any owner activity during the challenge window voids the claim
+    s.env.advance_block_time(HOUR);
+    s.as_caller(daughter).claim_ownership_by_inactivity(cid);
+    assert_eq!(s.as_caller(daughter).try_claim_ownership_by_inactivity(cid).unwrap_err(), Error::ChallengeWindowOpen.into());
+    s.as_caller(owner).create_task(cid, "Pharmacy pickup".to_string(), son, 1);
+    s.env.advance_block_time(HOUR / 6);
+    assert_eq!(s.as_caller(daughter).try_claim_ownership_by_inactivity(cid).unwrap_err(), Error::OwnerStillActive.into());
+
+    // Unchallenged, the claim hands the circle over
+    s.env.advance_block_time(HOUR);
+    s.as_caller(daughter).claim_ownership_by_inactivity(cid);
+    s.env.advance_block_time(HOUR / 6);
+    s.as_caller(daughter).claim_ownership_by_inactivity(cid);
+    assert_eq!(s.contract.get_circle(cid).unwrap().owner, daughter);
+    assert_eq!(s.as_caller(owner).try_add_member(cid, stranger).unwrap_err(), Error::NotOwner.into());
+    assert_eq!(s.as_caller(daughter).try_claim_ownership_by_inactivity(cid).unwrap_err(), Error::NotSuccessor.into());
+}
+
+#[test]
+fn meals_are_member_activity() {
+    let mut s = Scenario::new();
+    let (cid, daughter) = (s.circle_id, s.cast.daughter);
+
+    s.env.advance_block_time(HOUR);
+    s.as_caller(daughter).log_meal(cid, None, MealType::Lunch, 90, [1; 32]);
+    let latest = s.contract.get_activity(cid, 0, 1)[0].clone();
+    assert_eq!((latest.actor, latest.kind, latest.subject_id), (daughter, ActivityKind::MealLogged, cid));
+    assert_eq!(s.contract.get_last_active(cid, daughter), s.env.block_time());
+}
+
+#[test]
+fn consent_gating() {
+    let mut s = Scenario::new();
+    let (cid, cast) = (s.circle_id, &s.cast);
+    let (owner, mom, daughter, son, stranger) = (cast.owner, cast.mom, cast.daughter, cast.son, cast.stranger);
+
+    s.as_caller(daughter).log_symptom(cid, [7; 32], 3, [1; 32]);
+    s.as_caller(daughter).log_meal(cid, None, MealType::Lunch, 90, [1; 32]);
+
+    // The owner and the care recipient always read; members need her consent, even for their own entries
+    assert!(s.contract.has_consent(cid, ConsentScope::Symptoms, owner));
+    assert!(s.contract.has_consent(cid, ConsentScope::Symptoms, mom));
+    assert!(!s.contract.has_consent(cid, ConsentScope::Symptoms, daughter));
+    assert!(s.contract.get_symptom_log_for(daughter, cid, 0, 10).is_empty());
+    assert_eq!(s.contract.get_symptom_log_for(owner, cid, 0, 10).len(), 1);
+
+    // Only she or a guardian grants it, one address or every member at a time
+    assert_eq!(
+        s.as_caller(owner).try_set_consent(cid, ConsentScope::Symptoms, son, true).unwrap_err(),
+        Error::NotCareRecipientOrGuardian.into()
+    );
+    s.as_caller(mom).set_consent(cid, ConsentScope::Symptoms, son, true);
+    assert_eq!(s.contract.get_symptom_log_for(son, cid, 0, 10).len(), 1);
+    assert!(s.contract.get_symptom_log_for(daughter, cid, 0, 10).is_empty());
+    s.as_caller(mom).set_member_consent(cid, ConsentScope::Nutrition, true);
+    assert!(s.contract.has_consent(cid, ConsentScope::Nutrition, daughter));
+    assert!(!s.contract.has_consent(cid, ConsentScope::Nutrition, stranger));
+    assert!(!s.contract.has_consent(cid, ConsentScope::Symptoms, daughter));
+
+    // Auditor access covers one scope and lapses on its own
+    let until = s.env.block_time() + HOUR;
+    s.as_caller(mom).grant_auditor_access(cid, stranger, ConsentScope::Symptoms, until);
+    assert_eq!(s.contract.get_symptom_log_for(stranger, cid, 0, 10).len(), 1);
+    assert!(!s.contract.has_consent(cid, ConsentScope::Nutrition, stranger));
+    s.env.advance_block_time(HOUR);
+    assert!(s.contract.get_symptom_log_for(stranger, cid, 0, 10).is_empty());
+
+    // Revoking takes effect at once
+    s.as_caller(mom).set_consent(cid, ConsentScope::Symptoms, son, false);
+    assert!(s.contract.get_symptom_log_for(son, cid, 0, 10).is_empty());
+}
+
+#[test]
+fn care_surveys() {
+    let mut s = Scenario::new();
+    let (cid, cast) = (s.circle_id, &s.cast);
+    let (owner, mom, daughter, son) = (cast.owner, cast.mom, cast.daughter, cast.son);
+
+    // The daughter's physio falls under the hip recovery; the son's errand does not
+    let recovery = s.as_caller(owner).create_episode(cid, "Hip recovery".to_string(), 0, 0);
+    let physio = s.contract.create_task(cid, "Physio exercises".to_string(), daughter, 2);
+    s.contract.set_task_episode(physio, recovery);
+    let errand = s.contract.create_task(cid, "Pick up prescription".to_string(), son, 1);
+    s.as_caller(daughter).complete_task(physio);
+    s.as_caller(son).complete_task(errand);
+
+    // Neither the ongoing episode nor the current month can be rated yet
+    let scores = Bytes::from(vec![5, 4]);
+    assert_eq!(
+        s.as_caller(mom).try_submit_survey(cid, SurveyPeriod::Episode, recovery, scores.clone(), [0; 32]).unwrap_err(),
+        Error::PeriodNotOver.into()
+    );
+    let month = s.contract.get_current_period(cid, LeaderboardPeriod::Month);
+    assert_eq!(
+        s.contract.try_submit_survey(cid, SurveyPeriod::Month, month, scores.clone(), [0; 32]).unwrap_err(),
+        Error::PeriodNotOver.into()
+    );
+
+    // Once the episode ends, only the work done under it is credited
+    s.env.advance_block_time(HOUR);
+    let ended_at = s.env.block_time();
+    s.as_caller(owner).update_episode(cid, recovery, "Hip recovery".to_string(), 0, ended_at);
+    assert_eq!(
+        s.as_caller(daughter).try_submit_survey(cid, SurveyPeriod::Episode, recovery, scores.clone(), [0; 32]).unwrap_err(),
+        Error::NotCareRecipientOrGuardian.into()
+    );
+    s.as_caller(mom).submit_survey(cid, SurveyPeriod::Episode, recovery, scores.clone(), [0; 32]);
+    assert_eq!(s.contract.get_caregiver_survey_average(daughter), 450);
+    assert_eq!(s.contract.get_caregiver_survey_average(son), 0);
+    assert_eq!(s.contract.get_survey(cid, SurveyPeriod::Episode, recovery).unwrap().scores, scores);
+    assert_eq!(s.contract.get_survey(cid, SurveyPeriod::Week, recovery), None);
+
+    // Every member was examined in the same call, so the keeper has nothing left
+    assert_eq!(s.contract.credit_survey(cid, SurveyPeriod::Episode, recovery, 50), 0);
+    assert_eq!(
+        s.contract.try_submit_survey(cid, SurveyPeriod::Episode, recovery, scores, [0; 32]).unwrap_err(),
+        Error::SurveyAlreadySubmitted.into()
+    );
+}
+
+#[test]
+fn reputation_decay() {
+    let mut s = Scenario::new();
+    let (cid, cast) = (s.circle_id, &s.cast);
+    let (admin, owner, mom, daughter) = (cast.admin, cast.owner, cast.mom, cast.daughter);
+
+    // Only the admin sets how quickly scores fade: a 30 day half-life
+    let half_life = 30 * 24 * HOUR;
+    assert_eq!(s.as_caller(owner).try_set_reputation_half_life(half_life / 1_000).unwrap_err(), Error::NotAdmin.into());
+    s.as_caller(admin).set_reputation_half_life(half_life / 1_000);
+    assert_eq!(s.contract.get_reputation_half_life(), half_life / 1_000);
+
+    // A poor score, then a good one exactly one half-life later
+    for (title, score) in [("Hip recovery", 2), ("Flu recovery", 5)] {
+        let episode = s.as_caller(owner).create_episode(cid, title.to_string(), 0, 0);
+        let task = s.contract.create_task(cid, "Physio exercises".to_string(), daughter, 2);
+        s.contract.set_task_episode(task, episode);
+        s.as_caller(daughter).complete_task(task);
+        s.env.advance_block_time(HOUR);
+        let ended_at = s.env.block_time();
+        s.as_caller(owner).update_episode(cid, episode, title.to_string(), 0, ended_at);
+        s.as_caller(mom).submit_survey(cid, SurveyPeriod::Episode, episode, Bytes::from(vec![score]), [0; 32]);
+        s.env.advance_block_time(half_life - HOUR);
+    }
+
+    // The plain average weighs both alike; reputation counts the older score half
+    assert_eq!(s.contract.get_caregiver_survey_average(daughter), 350);
+    assert_eq!(s.contract.get_caregiver_reputation(daughter), 400);
+
+    // Two half-lives on, both earlier scores weigh a quarter of what they did
+    s.env.advance_block_time(half_life);
+    let episode = s.as_caller(owner).create_episode(cid, "Fall recovery".to_string(), 0, 0);
+    let task = s.contract.create_task(cid, "Physio exercises".to_string(), daughter, 2);
+    s.contract.set_task_episode(task, episode);
+    s.as_caller(daughter).complete_task(task);
+    s.env.advance_block_time(HOUR);
+    let ended_at = s.env.block_time();
+    s.as_caller(owner).update_episode(cid, episode, "Fall recovery".to_string(), 0, ended_at);
+    s.as_caller(mom).submit_survey(cid, SurveyPeriod::Episode, episode, Bytes::from(vec![2]), [0; 32]);
+    assert_eq!(s.contract.get_caregiver_survey_average(daughter), 300);
+    // (2/8 + 5/4 + 2) / (1/8 + 1/4 + 1)
+    assert_eq!(s.contract.get_caregiver_reputation(daughter), 254);
+}
+
+#[test]
+fn availability_windows() {
+    let mut s = Scenario::new();
+    let (cid, cast) = (s.circle_id, &s.cast);
+    let (owner, daughter, son, stranger) = (cast.owner, cast.daughter, cast.son, cast.stranger);
+    let now = s.env.block_time();
+    let window = |from: u64, to: u64| AvailabilityWindow { start: now + from * HOUR, end: now + to * HOUR };
+
+    // Windows must be non-empty, at most 16, and declared by members
+    assert_eq!(s.as_caller(daughter).try_set_availability(cid, vec![window(2, 1)]).unwrap_err(), Error::InvalidWindow.into());
+    assert_eq!(s.contract.try_set_availability(cid, vec![window(1, 2); 17]).unwrap_err(), Error::TooManyWindows.into());
+    assert_eq!(s.as_caller(stranger).try_set_availability(cid, vec![window(0, 1)]).unwrap_err(), Error::NotAMember.into());
+
+    // The son is free this hour, the daughter later on, the owner later still
+    s.as_caller(son).set_availability(cid, vec![window(0, 1)]);
+    s.as_caller(daughter).set_availability(cid, vec![window(1, 3)]);
+    assert_eq!(s.contract.get_availability(cid, daughter), vec![window(1, 3)]);
+    assert_eq!(s.contract.get_available_members(cid, now), vec![owner, son]);
+    s.as_caller(owner).set_availability(cid, vec![window(3, 4)]);
+    assert_eq!(s.contract.get_available_members(cid, now), vec![son]);
+    assert_eq!(s.contract.get_available_members(cid, now + 2 * HOUR), vec![daughter]);
+
+    // Auto-assignment only picks whoever is available when the task is created
+    let settings = CircleSettings { allow_open_tasks: true, auto_assign_mode: AutoAssignMode::FewestOpen, ..Default::default() };
+    s.as_caller(owner).update_settings(cid, settings);
+    let groceries = s.contract.create_open_task(cid, "Groceries".to_string(), 1);
+    assert_eq!(s.contract.get_task(groceries).unwrap().assigned_to, son);
+    s.env.advance_block_time(2 * HOUR);
+    let laundry = s.contract.create_open_task(cid, "Laundry".to_string(), 1);
+    assert_eq!(s.contract.get_task(laundry).unwrap().assigned_to, daughter);
+    s.env.advance_block_time(3 * HOUR);
+    assert_eq!(
+        s.contract.try_create_open_task(cid, "Dishes".to_string(), 1).unwrap_err(),
+        Error::AutoAssignmentDisabled.into()
+    );
+}
+
+#[test]
+fn sla_breaches() {
+    let mut s = Scenario::new();
+    let (cid, cast) = (s.circle_id, &s.cast);
+    let (owner, daughter) = (cast.owner, cast.daughter);
+
+    // Medium priority tasks are due within the hour; low priority ones have no target
+    assert_eq!(s.as_caller(daughter).try_set_sla_targets(cid, vec![0, 3_600]).unwrap_err(), Error::NotOwner.into());
+    assert_eq!(s.as_caller(owner).try_set_sla_targets(cid, vec![0; 5]).unwrap_err(), Error::TooManySlaTargets.into());
+    s.contract.set_sla_targets(cid, vec![0, 3_600]);
+    assert_eq!(s.contract.get_sla_targets(cid), vec![0, 3_600]);
+    let week = s.contract.get_current_period(cid, LeaderboardPeriod::Week);
+
+    let prompt = s.contract.create_task(cid, "Refill pill box".to_string(), daughter, 1);
+    let slow = s.contract.create_task(cid, "Call the pharmacy".to_string(), daughter, 1);
+    let untracked = s.contract.create_task(cid, "Water the plants".to_string(), daughter, 0);
+    s.env.advance_block_time(HOUR / 2);
+    s.as_caller(daughter).complete_task(prompt);
+    s.env.advance_block_time(HOUR * 3 / 2);
+    s.contract.complete_task(slow);
+    s.contract.complete_task(untracked);
+
+    // Only the slow task breached, and only tracked priorities count
+    let breach = |task_id, latency_secs| SlaBreached {
+        task_id,
+        circle_id: cid,
+        priority: 1,
+        target_secs: 3_600,
+        latency_secs,
+        routes: Vec::new(),
+    };
+    assert!(s.env.emitted_event(&s.contract, &breach(slow, 7_200)));
+    assert!(!s.env.emitted_event(&s.contract, &breach(prompt, 1_800)));
+    assert_eq!(s.contract.get_task_latency(slow), Some(7_200));
+    let stats = s.contract.get_sla_stats(cid, week);
+    assert_eq!((stats.tracked, stats.met), (2, 1));
+    assert_eq!(s.contract.get_sla_compliance_bps(cid, week), 5_000);
+}
+
+#[test]
+fn cosigned_completion() {
+    let mut s = Scenario::new();
+    let (cid, cast) = (s.circle_id, &s.cast);
+    let (owner, daughter, son, stranger) = (cast.owner, cast.daughter, cast.son, cast.stranger);
+    let title = || "Wound dressing".to_string();
+
+    s.as_caller(owner);
+    for count in [0, 11] {
+        assert_eq!(
+            s.contract.try_create_cosigned_task(cid, title(), daughter, count).unwrap_err(),
+            Error::InvalidCoSignerCount.into()
+        );
+    }
+    let dressing = s.contract.create_cosigned_task(cid, title(), daughter, 2);
+    assert_eq!(s.as_caller(son).try_cosign_completion(dressing).unwrap_err(), Error::NoCoSignaturesPending.into());
+
+    // The assignee's completion waits for two other members
+    s.as_caller(daughter).complete_task(dressing);
+    let request = s.contract.get_cosign_request(dressing).unwrap();
+    assert_eq!((request.completed_by, request.signatures), (daughter, 0));
+    assert!(!s.contract.get_task(dressing).unwrap().completed);
+    let completed = TaskCompleted {
+        task_id: dressing,
+        circle_id: cid,
+        assigned_to: daughter,
+        completed_by: daughter,
+        timestamp: request.submitted_at,
+        agency: None,
+        evidence_count: 0,
+        routes: Vec::new(),
+    };
+
+    s.env.advance_block_time(HOUR);
+    s.as_caller(son).cosign_completion(dressing);
+    assert_eq!(s.contract.get_cosign_request(dressing).unwrap().signatures, 1);
+    assert!(!s.contract.get_task(dressing).unwrap().completed);
+    assert!(!s.env.emitted_event(&s.contract, &completed));
+    assert_eq!(s.contract.try_cosign_completion(dressing).unwrap_err(), Error::AlreadyCoSigned.into());
+    assert_eq!(s.as_caller(daughter).try_cosign_completion(dressing).unwrap_err(), Error::CannotCoSignOwnCompletion.into());
+    assert_eq!(s.as_caller(stranger).try_cosign_completion(dressing).unwrap_err(), Error::NotAMember.into());
+
+    // The second distinct signature completes the task as of its submission
+    s.as_caller(owner).cosign_completion(dressing);
+    assert!(s.contract.get_cosign_request(dressing).is_none());
+    assert!(s.contract.get_task(dressing).unwrap().completed);
+    assert!(s.env.emitted_event(&s.contract, &completed));
+}
+
+#[test]
+fn allergy_registry() {
+    let mut s = Scenario::new();
+    let (cid, cast) = (s.circle_id, &s.cast);
+    let (owner, mom, daughter) = (cast.owner, cast.mom, cast.daughter);
+    let (penicillin, diabetes, metformin) = (blake2b("penicillin"), blake2b("diabetes"), blake2b("metformin"));
+    let notes = blake2b("rash and swelling");
+
+    assert_eq!(
+        s.as_caller(daughter).try_set_health_record(cid, penicillin, HealthRecordKind::Allergy, Severity::Severe, notes).unwrap_err(),
+        Error::NotOwnerOrGuardian.into()
+    );
+    s.as_caller(owner).set_health_record(cid, penicillin, HealthRecordKind::Allergy, Severity::Moderate, notes);
+    s.contract.set_health_record(cid, diabetes, HealthRecordKind::Condition, Severity::Moderate, notes);
+    s.contract.set_health_record(cid, penicillin, HealthRecordKind::Allergy, Severity::Severe, notes);
+    let records = s.contract.get_health_records_for(owner, cid, 0, 10);
+    assert_eq!(records.iter().map(|record| record.substance_hash).collect::<Vec<_>>(), vec![penicillin, diabetes]);
+    assert_eq!(s.contract.get_health_record_for(owner, cid, penicillin).unwrap().severity, Severity::Severe);
+    assert!(s.contract.get_health_records_for(daughter, cid, 0, 10).is_empty());
+
+    // A medication on the allergy list is recorded, with a warning
+    let conflict = |medication_id| AllergyConflict {
+        circle_id: cid,
+        medication_id,
+        substance_hash: penicillin,
+        severity: Severity::Severe,
+        added_by: owner,
+        routes: Vec::new(),
+    };
+    assert_eq!(s.contract.add_medication(cid, metformin, notes), 0);
+    assert_eq!(s.contract.add_medication(cid, penicillin, notes), 1);
+    assert!(s.env.emitted_event(&s.contract, &conflict(1)));
+    assert_eq!(s.contract.get_medication_count(cid), 2);
+
+    // Conditions do not conflict, and removed allergies no longer warn
+    s.contract.add_medication(cid, diabetes, notes);
+    s.contract.remove_health_record(cid, penicillin);
+    assert_eq!(s.contract.try_remove_health_record(cid, penicillin).unwrap_err(), Error::HealthRecordNotFound.into());
+    assert_eq!(s.contract.get_health_records_for(owner, cid, 0, 10).len(), 1);
+    s.contract.add_medication(cid, penicillin, notes);
+    assert!(!s.env.emitted_event(&s.contract, &conflict(3)));
+
+    s.env.advance_block_time(HOUR);
+    s.contract.stop_medication(cid, 1);
+    assert_eq!(s.contract.try_stop_medication(cid, 1).unwrap_err(), Error::MedicationStopped.into());
+    assert_eq!(s.contract.try_stop_medication(cid, 4).unwrap_err(), Error::MedicationNotFound.into());
+    assert!(s.contract.get_medications_for(daughter, cid, 0, 10).is_empty());
+    s.as_caller(mom).set_consent(cid, ConsentScope::Medications, daughter, true);
+    let medications = s.contract.get_medications_for(daughter, cid, 0, 10);
+    assert_eq!(medications.iter().map(|medication| medication.stopped_at != 0).collect::<Vec<_>>(), vec![false, true, false, false]);
+}
+
+#[test]
+fn sealed_titles() {
+    let mut s = Scenario::new();
+    let (cid, cast) = (s.circle_id, &s.cast);
+    let (owner, daughter, son) = (cast.owner, cast.daughter, cast.son);
+    let title = "Psychiatrist appointment".to_string();
+    let (salt, wrong_salt) = ([7u8; 32], [8u8; 32]);
+    let seal = |title: &str, salt: [u8; 32]| blake2b([title.as_bytes(), &salt[..]].concat());
+    let commitment = seal(&title, salt);
+
+    // Until revealed, views carry only the commitment
+    let task_id = s.as_caller(daughter).create_sealed_task(cid, commitment, son, 2);
+    assert_eq!(s.contract.get_task(task_id).unwrap().title, "");
+    assert_eq!(s.contract.get_title_commitment(task_id), Some(commitment));
+    let sealed = TitleSealed { task_id, circle_id: cid, commitment, routes: Vec::new() };
+    assert!(s.env.emitted_event(&s.contract, &sealed));
+
+    assert_eq!(
+        s.as_caller(son).try_reveal_title(task_id, title.clone(), salt).unwrap_err(),
+        Error::NotCreatorOrOwner.into()
+    );
+    s.as_caller(daughter);
+    assert_eq!(s.contract.try_reveal_title(task_id, title.clone(), wrong_salt).unwrap_err(), Error::TitleMismatch.into());
+    assert_eq!(s.contract.try_reveal_title(task_id, "Dentist".to_string(), salt).unwrap_err(), Error::TitleMismatch.into());
+    assert_eq!(s.contract.get_task(task_id).unwrap().title, "");
+
+    s.contract.reveal_title(task_id, title.clone(), salt);
+    assert_eq!(s.contract.get_task(task_id).unwrap().title, title);
+    assert_eq!(s.contract.get_title_commitment(task_id), None);
+    let revealed = TitleRevealed { task_id, circle_id: cid, title: title.clone(), revealed_by: daughter, routes: Vec::new() };
+    assert!(s.env.emitted_event(&s.contract, &revealed));
+    assert_eq!(s.contract.try_reveal_title(task_id, title, salt).unwrap_err(), Error::TitleNotSealed.into());
+
+    let plain = s.as_caller(owner).create_task(cid, "Groceries".to_string(), son, 1);
+    assert_eq!(s.contract.try_reveal_title(plain, "Groceries".to_string(), salt).unwrap_err(), Error::TitleNotSealed.into());
+}
+
+#[test]
+fn retention_purge() {
+    const DAY: u64 = 24 * HOUR;
+    let mut s = Scenario::new();
+    let (cid, cast) = (s.circle_id, &s.cast);
+    let (owner, daughter) = (cast.owner, cast.daughter);
+    let (confusion, notes) = (blake2b("confusion"), blake2b("worse after dinner"));
+
+    s.as_caller(daughter);
+    for severity in [3, 5, 4] {
+        s.contract.log_symptom(cid, confusion, severity, notes);
+    }
+    s.contract.log_meal(cid, None, MealType::Dinner, 60, notes);
+    s.env.advance_block_time(10 * DAY);
+    s.contract.log_symptom(cid, confusion, 2, notes);
+    s.env.advance_block_time(25 * DAY);
+
+    // Nothing expires without a retention period
+    assert_eq!(s.contract.purge_expired_records(cid, 10), 0);
+    s.as_caller(owner).update_settings(cid, CircleSettings { retention_days: 30, ..Default::default() });
+    let trend = s.contract.get_symptom_trend_for(owner, cid, confusion, 0, 40);
+    let meal_days = s.contract.get_meal_days_for(owner, cid, 0, 40);
+
+    // The keeper resumes from its cursor and stops at entries still in retention
+    let purged = |purged| RecordsPurged { circle_id: cid, cutoff: 5 * DAY, purged, routes: Vec::new() };
+    assert_eq!(s.contract.purge_expired_records(cid, 2), 2);
+    assert!(s.env.emitted_event(&s.contract, &purged(2)));
+    assert_eq!(s.contract.purge_expired_records(cid, 10), 2);
+    assert_eq!(s.contract.purge_expired_records(cid, 10), 0);
+    let symptoms = s.contract.get_symptom_log_for(owner, cid, 0, 10);
+    let cleared = symptoms.iter().map(|entry| entry.notes_hash == [0u8; 32]).collect::<Vec<_>>();
+    assert_eq!(cleared, vec![true, true, true, false]);
+    assert_eq!(s.contract.get_meal_log_for(owner, cid, 0, 10)[0].notes_hash, [0u8; 32]);
+
+    // Counts and daily aggregates survive the purge
+    assert_eq!(s.contract.get_symptom_count(cid), 4);
+    assert_eq!(s.contract.get_symptom_trend_for(owner, cid, confusion, 0, 40), trend);
+    assert_eq!(s.contract.get_meal_days_for(owner, cid, 0, 40), meal_days);
+}
+
+#[test]
+fn feature_flags() {
+    let mut s = Scenario::new();
+    let (cid, cast) = (s.circle_id, &s.cast);
+    let (admin, owner, daughter, stranger) = (cast.admin, cast.owner, cast.daughter, cast.stranger);
+
+    assert!(s.contract.get_feature_flags().iter().all(|(_, enabled)| *enabled));
+    assert_eq!(
+        s.as_caller(owner).try_set_feature_enabled(Feature::Extensions, false).unwrap_err(),
+        Error::NotAdmin.into()
+    );
+    s.contract.set_proof_verifier(cid, Some(stranger));
+    let task_id = s.contract.create_task(cid, "Blood glucose check".to_string(), daughter, 2);
+    assert_eq!(s.as_caller(daughter).try_complete_task(task_id).unwrap_err(), Error::ProofRequired.into());
+
+    s.as_caller(admin);
+    for feature in [Feature::Extensions, Feature::ProofVerifiers, Feature::Reimbursements] {
+        s.contract.set_feature_enabled(feature, false);
+        assert!(!s.contract.is_feature_enabled(feature));
+        assert!(s.env.emitted_event(&s.contract, &FeatureToggled { feature, enabled: false }));
+    }
+    assert!(s.contract.is_feature_enabled(Feature::CircleMerges));
+
+    // Gated entry points revert while their feature is off
+    s.as_caller(owner);
+    assert_eq!(s.contract.try_register_extension(cid, stranger).unwrap_err(), Error::FeatureDisabled.into());
+    assert_eq!(s.contract.try_set_proof_verifier(cid, None).unwrap_err(), Error::FeatureDisabled.into());
+    assert_eq!(s.contract.try_deliver_hooks(cid, stranger, 10).unwrap_err(), Error::FeatureDisabled.into());
+    s.as_caller(daughter);
+    assert_eq!(s.contract.try_request_reimbursement(cid, 0).unwrap_err(), Error::FeatureDisabled.into());
+    assert_eq!(
+        s.contract.try_complete_task_with_proof(task_id, Bytes::from(vec![1u8])).unwrap_err(),
+        Error::FeatureDisabled.into()
+    );
+
+    // A stored verifier is ignored rather than consulted
+    s.contract.complete_task(task_id);
+    assert!(s.contract.get_task(task_id).unwrap().completed);
+
+    s.as_caller(admin).set_feature_enabled(Feature::ProofVerifiers, true);
+    s.as_caller(owner).set_proof_verifier(cid, None);
+    assert!(s.contract.is_feature_enabled(Feature::ProofVerifiers));
+}
+
+#[test]
+fn completion_delegation() {
+    let mut s = Scenario::new();
+    let (cid, cast) = (s.circle_id, &s.cast);
+    let (owner, daughter, son, stranger) = (cast.owner, cast.daughter, cast.son, cast.stranger);
+    let task_id = s.as_caller(owner).create_task(cid, "Collect prescription".to_string(), son, 2);
+
+    assert_eq!(
+        s.as_caller(daughter).try_delegate_completion_rights(task_id, daughter).unwrap_err(),
+        Error::NotAssigneeOrOwner.into()
+    );
+    s.as_caller(son).delegate_completion_rights(task_id, daughter);
+    assert_eq!(s.contract.get_completion_delegate(task_id), Some(daughter));
+    s.contract.revoke_completion_rights(task_id);
+    assert_eq!(s.contract.get_completion_delegate(task_id), None);
+    assert_eq!(s.as_caller(daughter).try_complete_task(task_id).unwrap_err(), Error::NotAssignee.into());
+
+    // The owner can delegate too; the event names both the assignee and the delegate
+    s.as_caller(owner).delegate_completion_rights(task_id, daughter);
+    s.env.advance_block_time(HOUR);
+    s.as_caller(daughter).complete_task(task_id);
+    let task = s.contract.get_task(task_id).unwrap();
+    assert!(task.completed);
+    let completed = TaskCompleted {
+        task_id,
+        circle_id: cid,
+        assigned_to: son,
+        completed_by: daughter,
+        timestamp: task.completed_at,
+        agency: None,
+        evidence_count: 0,
+        routes: Vec::new(),
+    };
+    assert!(s.env.emitted_event(&s.contract, &completed));
+    assert_eq!(
+        s.as_caller(son).try_delegate_completion_rights(task_id, stranger).unwrap_err(),
+        Error::AlreadyCompleted.into()
+    );
+}
+
+#[test]
+fn circle_cloning() {
+    let mut s = Scenario::new();
+    let (cid, cast) = (s.circle_id, &s.cast);
+    let (owner, daughter, son) = (cast.owner, cast.daughter, cast.son);
+    let onboarding = vec![TaskTemplate { title: "Read the care plan".to_string(), priority: 1 }];
+    let settings = CircleSettings { max_open_per_member: 4, require_verification: true, ..Default::default() };
+
+    s.as_caller(owner).set_onboarding_tasks(cid, onboarding.clone());
+    s.contract.update_settings(cid, settings.clone());
+    s.contract.create_task(cid, "Pharmacy pickup".to_string(), son, 2);
+    assert_eq!(
+        s.as_caller(daughter).try_clone_circle(cid, "Dad's Care".to_string(), true, true).unwrap_err(),
+        Error::NotOwner.into()
+    );
+
+    // Settings always carry over; members and onboarding templates on request
+    let bare = s.as_caller(owner).clone_circle(cid, "Dad's Care".to_string(), false, false);
+    assert_eq!(s.contract.get_settings(bare), settings);
+    assert_eq!(s.contract.get_member_count(bare), 1);
+    assert!(!s.contract.check_is_member(bare, daughter));
+    assert!(s.contract.get_onboarding_tasks(bare).is_empty());
+
+    let full = s.contract.clone_circle(cid, "Aunt's Care".to_string(), true, true);
+    let circle = s.contract.get_circle(full).unwrap();
+    assert_eq!((circle.owner, circle.member_count, circle.task_count), (owner, 3, 0));
+    assert!(s.contract.check_is_member(full, daughter) && s.contract.check_is_member(full, son));
+    assert_eq!(s.contract.get_onboarding_tasks(full), onboarding);
+    assert_eq!(s.contract.get_care_recipient(full), None);
+}
+
+#[test]
+fn join_code_referrals() {
+    let mut s = Scenario::new();
+    let (cid, cast) = (s.circle_id, &s.cast);
+    let (owner, daughter, volunteer, stranger) = (cast.owner, cast.daughter, cast.volunteer, cast.stranger);
+    let code = blake2b("from-daughter");
+
+    s.as_caller(owner).create_join_code(cid, code, 2, 0);
+    assert_eq!(s.contract.try_set_join_code_referrer(cid, code, Some(stranger)).unwrap_err(), Error::NotAMember.into());
+    assert_eq!(
+        s.contract.try_set_join_code_referrer(cid, blake2b("unknown"), Some(daughter)).unwrap_err(),
+        Error::InvalidJoinCode.into()
+    );
+    assert_eq!(
+        s.as_caller(daughter).try_set_join_code_referrer(cid, code, Some(daughter)).unwrap_err(),
+        Error::NotOwner.into()
+    );
+    s.as_caller(owner).set_join_code_referrer(cid, code, Some(daughter));
+    assert_eq!(s.contract.get_join_code_referrer(cid, code), Some(daughter));
+
+    s.env.advance_block_time(HOUR);
+    s.as_caller(volunteer).join_with_code(cid, "from-daughter".to_string());
+    let referral = Referral { referrer: daughter, recruit: volunteer, joined_at: HOUR, credited_at: None };
+    assert_eq!(s.contract.get_referral(cid, volunteer), Some(referral.clone()));
+    assert_eq!(s.contract.get_referrals(cid, daughter, 0, 10), vec![referral]);
+    assert_eq!(s.contract.get_referral_count(cid, daughter), 1);
+
+    // An unverified completion earns nothing
+    let first = s.as_caller(owner).create_task(cid, "Fold laundry".to_string(), volunteer, 1);
+    s.as_caller(volunteer).complete_task(first);
+    assert_eq!(s.contract.get_referral_points(cid, daughter), 0);
+
+    // The first verified completion credits the referrer, once
+    s.as_caller(owner).update_settings(cid, CircleSettings { require_verification: true, ..Default::default() });
+    for title in ["Water the plants", "Take out recycling"] {
+        s.env.advance_block_time(HOUR);
+        let task_id = s.as_caller(owner).create_task(cid, title.to_string(), volunteer, 1);
+        s.as_caller(volunteer).complete_task(task_id);
+        s.as_caller(owner).verify_completion(task_id, true);
+    }
+    assert_eq!(s.contract.get_referral_points(cid, daughter), 10);
+    assert_eq!(s.contract.get_referral(cid, volunteer).unwrap().credited_at, Some(2 * HOUR));
+    let credited = ReferralCredited { circle_id: cid, referrer: daughter, recruit: volunteer, points: 10, routes: Vec::new() };
+    assert!(s.env.emitted_event(&s.contract, &credited));
+}
+
+#[test]
+fn encrypted_envelopes() {
+    let mut s = Scenario::new();
+    let (cid, cast) = (s.circle_id, &s.cast);
+    let (owner, daughter, son, stranger) = (cast.owner, cast.daughter, cast.son, cast.stranger);
+    let ciphertext = |len: usize| Bytes::from(vec![0xa5u8; len]);
+
+    let task_id = s.as_caller(owner).create_task(cid, "Insurance paperwork".to_string(), daughter, 2);
+    let other_circle = s.as_caller(stranger).create_circle("Elsewhere".to_string());
+    let other_task = s.contract.create_task(other_circle, "Unrelated".to_string(), stranger, 1);
+    assert_eq!(s.contract.try_store_envelope(cid, None, ciphertext(16)).unwrap_err(), Error::NotAMember.into());
+
+    // Ciphertexts up to 1024 bytes, attached to the circle or one of its tasks
+    s.as_caller(daughter);
+    assert_eq!(s.contract.try_store_envelope(cid, None, ciphertext(1025)).unwrap_err(), Error::CiphertextTooLarge.into());
+    assert_eq!(s.contract.try_store_envelope(cid, Some(other_task), ciphertext(16)).unwrap_err(), Error::TaskNotInCircle.into());
+    let policy = s.contract.store_envelope(cid, Some(task_id), ciphertext(1024));
+    let notes = s.contract.store_envelope(cid, None, ciphertext(16));
+    assert_eq!(s.contract.get_envelope(cid, policy).unwrap().key_epoch, 0);
+
+    // Rotating the key leaves stored envelopes on their epoch until re-encrypted
+    assert_eq!(s.as_caller(son).try_rotate_circle_key_epoch(cid).unwrap_err(), Error::NotOwnerOrGuardian.into());
+    assert_eq!(s.as_caller(owner).rotate_circle_key_epoch(cid), 1);
+    let rotated = KeyEpochRotated { circle_id: cid, key_epoch: 1, rotated_by: owner, routes: Vec::new() };
+    assert!(s.env.emitted_event(&s.contract, &rotated));
+    assert_eq!(s.contract.get_envelope(cid, policy).unwrap().key_epoch, 0);
+    assert_eq!(
+        s.as_caller(son).try_replace_envelope(cid, policy, ciphertext(32)).unwrap_err(),
+        Error::NotCreatorOrOwner.into()
+    );
+    s.as_caller(daughter);
+    assert_eq!(s.contract.try_replace_envelope(cid, policy, ciphertext(1025)).unwrap_err(), Error::CiphertextTooLarge.into());
+    s.contract.replace_envelope(cid, policy, ciphertext(32));
+    let envelope = s.contract.get_envelope(cid, policy).unwrap();
+    assert_eq!((envelope.key_epoch, envelope.ciphertext, envelope.task_id), (1, ciphertext(32), Some(task_id)));
+
+    s.contract.delete_envelope(cid, notes);
+    assert_eq!(s.contract.get_envelope(cid, notes), None);
+    assert_eq!(s.contract.get_envelopes(cid, 0, 10).iter().map(|envelope| envelope.id).collect::<Vec<_>>(), vec![policy]);
+    assert_eq!(s.contract.try_delete_envelope(cid, notes).unwrap_err(), Error::EnvelopeNotFound.into());
+
+    // At most 100 live envelopes per circle; deleting one frees its slot
+    for _ in 1..100 {
+        s.contract.store_envelope(cid, None, ciphertext(1));
+    }
+    assert_eq!(s.contract.try_store_envelope(cid, None, ciphertext(1)).unwrap_err(), Error::TooManyEnvelopes.into());
+    s.as_caller(owner).delete_envelope(cid, policy);
+    s.as_caller(daughter).store_envelope(cid, None, ciphertext(1));
+}
@@ -0,0 +1,119 @@
+//! Simulation views.
+//!
+//! `simulate_create_task` and `simulate_complete_task` promise the code the
+//! real call would revert with. Each test sets up one class of failure,
+//! asks the simulation, then makes the real call as the same caller, and
+//! both must name the same error, or both succeed.
+
+use carecircle::fixtures::CircleFixture;
+use carecircle::*;
+use odra::host::{Deployer, HostEnv, NoArgs};
+use odra::{Address, OdraError, OdraResult};
+
+struct Circle {
+    env: HostEnv,
+    contract: CareCircleHostRef,
+    owner: Address,
+    member: Address,
+    stranger: Address,
+    circle_id: u64,
+}
+
+impl Circle {
+    /// A circle of two, and an account outside it
+    fn new() -> Self {
+        let env = odra_test::env();
+        let (owner, member, stranger) = (env.get_account(0), env.get_account(1), env.get_account(2));
+        let mut contract = CareCircleHostRef::deploy(&env, NoArgs);
+        let circle_id = CircleFixture::new("Mom's Care", owner).member(member).build(&env, &mut contract).circle_id;
+        Self { env, contract, owner, member, stranger, circle_id }
+    }
+
+    fn settings(&mut self, settings: CircleSettings) {
+        self.env.set_caller(self.owner);
+        self.contract.update_settings(self.circle_id, settings);
+    }
+
+    /// Simulate `create_task` as `caller`, then make the call; both must end in `expected`
+    fn create(&mut self, caller: Address, title: &str, assigned_to: Address, priority: u8, expected: Option<Error>) -> Option<u64> {
+        let simulated = self.contract.simulate_create_task(caller, self.circle_id, title.to_string(), assigned_to, priority);
+        self.env.set_caller(caller);
+        let real = self.contract.try_create_task(self.circle_id, title.to_string(), assigned_to, priority);
+        agree(simulated, &real, expected);
+        real.ok()
+    }
+
+    /// Simulate `complete_task` as `caller`, then make the call; both must end in `expected`
+    fn complete(&mut self, caller: Address, task_id: u64, expected: Option<Error>) {
+        let simulated = self.contract.simulate_complete_task(caller, task_id);
+        self.env.set_caller(caller);
+        let real = self.contract.try_complete_task(task_id);
+        agree(simulated, &real, expected);
+    }
+}
+
+fn agree<T>(simulated: Simulation, real: &OdraResult<T>, expected: Option<Error>) {
+    let expected = expected.map(OdraError::from);
+    assert_eq!(real.as_ref().err(), expected.as_ref());
+    let predicted = (!simulated.ok).then(|| OdraError::user(simulated.error_code as u16));
+    assert_eq!(predicted, expected);
+}
+
+#[test]
+fn membership() {
+    let mut c = Circle::new();
+    let (owner, member, stranger) = (c.owner, c.member, c.stranger);
+
+    c.create(stranger, "Groceries", member, 1, Some(Error::NotAMember));
+    c.create(owner, "Groceries", stranger, 1, Some(Error::AssigneeNotAMember));
+    let task_id = c.create(owner, "Groceries", member, 1, None).unwrap();
+
+    c.complete(stranger, task_id, Some(Error::NotAssignee));
+    c.complete(owner, task_id, Some(Error::NotAssignee));
+    c.complete(member, task_id + 1, Some(Error::TaskNotFound));
+    c.complete(member, task_id, None);
+}
+
+#[test]
+fn status() {
+    let mut c = Circle::new();
+    let (owner, member) = (c.owner, c.member);
+
+    c.settings(CircleSettings { sealed_titles: true, ..Default::default() });
+    c.create(owner, "Groceries", member, 1, Some(Error::SealedTitleRequired));
+
+    // Waiting on the assignee, then on the owner, then done
+    c.settings(CircleSettings { require_acceptance: true, require_verification: true, ..Default::default() });
+    let task_id = c.create(owner, "Groceries", member, 1, None).unwrap();
+    c.complete(member, task_id, Some(Error::AssignmentNotAccepted));
+    c.contract.accept_assignment(task_id);
+    c.complete(member, task_id, None);
+    c.complete(member, task_id, Some(Error::CompletionPendingVerification));
+    c.env.set_caller(owner);
+    c.contract.verify_completion(task_id, true);
+    c.complete(member, task_id, Some(Error::AlreadyCompleted));
+
+    // A suspended member can neither create, be assigned, nor complete
+    c.settings(CircleSettings::default());
+    let task_id = c.create(owner, "Laundry", member, 1, None).unwrap();
+    c.contract.suspend_member(c.circle_id, member);
+    c.create(member, "Laundry", owner, 1, Some(Error::MemberSuspended));
+    c.create(owner, "Laundry", member, 1, Some(Error::MemberSuspended));
+    c.complete(member, task_id, Some(Error::MemberSuspended));
+}
+
+#[test]
+fn limits() {
+    let mut c = Circle::new();
+    let (owner, member) = (c.owner, c.member);
+
+    c.create(owner, &"x".repeat(129), member, 1, Some(Error::TitleTooLong));
+    c.create(owner, "Groceries", member, 4, Some(Error::InvalidPriority));
+
+    // The member's cap of one is reached, and completing the task frees it again
+    c.contract.set_member_task_cap(c.circle_id, member, Some(1));
+    let task_id = c.create(owner, "Groceries", member, 1, None).unwrap();
+    c.create(owner, "Laundry", member, 1, Some(Error::AssignmentLimitReached));
+    c.complete(member, task_id, None);
+    c.create(owner, "Laundry", member, 1, None);
+}